@@ -17,6 +17,9 @@ fn main() {
     println!("cargo:rerun-if-env-changed=DEP_LV_CONF_PATH");
     println!("cargo:rerun-if-env-changed=ESP_TOOLCHAIN_VERSION");
 
+    // Features that switch LVGL modules on in lv_conf.h
+    let use_log = if env::var_os("CARGO_FEATURE_LOG").is_some() { "1" } else { "0" };
+
     // Collect LVGL source files
     let src_dir = lvgl_dir.join("src");
     let mut sources: Vec<PathBuf> = Vec::new();
@@ -29,6 +32,7 @@ fn main() {
         .include(&lvgl_dir)
         .include(&lv_conf_include)
         .define("LV_CONF_INCLUDE_SIMPLE", None)
+        .define("LV_USE_LOG", use_log)
         // Suppress warnings from LVGL code
         .warnings(false)
         .extra_warnings(false);
@@ -95,6 +99,7 @@ fn main() {
         .clang_arg(format!("-I{}", lvgl_dir.display()))
         .clang_arg(format!("-I{}", lv_conf_include.display()))
         .clang_arg("-DLV_CONF_INCLUDE_SIMPLE")
+        .clang_arg(format!("-DLV_USE_LOG={}", use_log))
         // no_std compatibility
         .use_core()
         .ctypes_prefix("cty")
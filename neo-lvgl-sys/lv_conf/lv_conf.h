@@ -65,6 +65,9 @@
 #define LV_DRAW_LAYER_MAX_MEMORY                0
 #define LV_DRAW_THREAD_STACK_SIZE               (8 * 1024)
 
+/** Maximum number of color stops in a gradient */
+#define LV_GRADIENT_MAX_STOPS                   4
+
 #define LV_USE_DRAW_SW 1
 #if LV_USE_DRAW_SW == 1
     #define LV_DRAW_SW_SUPPORT_RGB565           1
@@ -99,7 +102,26 @@
  * FEATURE CONFIGURATION
  *=======================*/
 
-#define LV_USE_LOG 0
+/* Set to 1 by the `log` feature; the log module costs flash otherwise */
+#ifndef LV_USE_LOG
+    #define LV_USE_LOG 0
+#endif
+#if LV_USE_LOG
+    #define LV_LOG_LEVEL LV_LOG_LEVEL_WARN
+    /* Logs only go to a callback registered from Rust (e.g. `RingLog`) */
+    #define LV_LOG_PRINTF 0
+    #define LV_LOG_USE_TIMESTAMP 1
+    #define LV_LOG_USE_FILE_LINE 0
+    #define LV_LOG_TRACE_MEM        0
+    #define LV_LOG_TRACE_TIMER      0
+    #define LV_LOG_TRACE_INDEV      0
+    #define LV_LOG_TRACE_DISP_REFR  0
+    #define LV_LOG_TRACE_EVENT      0
+    #define LV_LOG_TRACE_OBJ_CREATE 0
+    #define LV_LOG_TRACE_LAYOUT     0
+    #define LV_LOG_TRACE_ANIM       0
+    #define LV_LOG_TRACE_CACHE      0
+#endif
 
 #define LV_USE_ASSERT_NULL          1
 #define LV_USE_ASSERT_MALLOC        1
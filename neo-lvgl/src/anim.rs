@@ -4,6 +4,7 @@
 //!
 //! - `Anim` - Animation builder with fluent API
 //! - `AnimHandle` - Handle to a running animation
+//! - `MoveHandle` - Handle to a `Widget::move_to` animation
 //! - `AnimTimeline` - Sequencing multiple animations
 //! - `AnimPath` - Easing functions
 //!
@@ -76,10 +77,30 @@ pub enum AnimPath {
         x2: i16,
         y2: i16,
     },
+    /// Easing function written in Rust
+    ///
+    /// Maps the elapsed fraction of the duration to the fraction of the way
+    /// from the start to the end value. Since [`Progress`] is clamped to
+    /// 0.0-1.0, the animation can't go past its end values; use
+    /// [`Overshoot`](Self::Overshoot) or [`Bounce`](Self::Bounce) for that.
+    ///
+    /// ```ignore
+    /// fn ease_out_quad(t: Progress) -> Progress {
+    ///     let t = t.as_f32();
+    ///     Progress::new(t * (2.0 - t))
+    /// }
+    ///
+    /// anim.set_path(AnimPath::Custom(ease_out_quad));
+    /// ```
+    Custom(fn(Progress) -> Progress),
 }
 
 impl AnimPath {
-    fn to_raw(self) -> neo_lvgl_sys::lv_anim_path_cb_t {
+    /// Path callback for paths that need nothing stored in the `lv_anim_t`
+    ///
+    /// `CustomBezier` and `Custom` keep their parameters in the anim and fall
+    /// back to `EaseInOut` here; [`Anim::set_path`] installs them itself.
+    pub(crate) fn to_raw(self) -> neo_lvgl_sys::lv_anim_path_cb_t {
         match self {
             AnimPath::Linear => Some(neo_lvgl_sys::lv_anim_path_linear),
             AnimPath::EaseIn => Some(neo_lvgl_sys::lv_anim_path_ease_in),
@@ -88,11 +109,35 @@ impl AnimPath {
             AnimPath::Overshoot => Some(neo_lvgl_sys::lv_anim_path_overshoot),
             AnimPath::Bounce => Some(neo_lvgl_sys::lv_anim_path_bounce),
             AnimPath::Step => Some(neo_lvgl_sys::lv_anim_path_step),
-            AnimPath::CustomBezier { .. } => Some(neo_lvgl_sys::lv_anim_path_custom_bezier3),
+            AnimPath::CustomBezier { .. } | AnimPath::Custom(_) => {
+                Some(neo_lvgl_sys::lv_anim_path_ease_in_out)
+            }
         }
     }
 }
 
+type EasingFn = fn(Progress) -> Progress;
+
+// The easing function is kept in the anim's bezier parameters, which are
+// otherwise unused with a custom path, so `var` and `user_data` stay free
+const _: () = assert!(
+    core::mem::size_of::<EasingFn>() <= core::mem::size_of::<neo_lvgl_sys::lv_anim_bezier3_para_t>()
+);
+
+/// Path callback running the easing function stored by `Anim::set_path`
+unsafe extern "C" fn custom_path_trampoline(anim: *const neo_lvgl_sys::lv_anim_t) -> i32 {
+    let anim = &*anim;
+    let ease: EasingFn = core::ptr::read_unaligned(core::ptr::addr_of!(anim.parameter).cast());
+    let t = if anim.duration == 0 {
+        1.0
+    } else {
+        anim.act_time as f32 / anim.duration as f32
+    };
+    let eased = ease(Progress::new(t)).as_f32();
+    let range = (anim.end_value as i64 - anim.start_value as i64) as f32;
+    anim.start_value + (range * eased) as i32
+}
+
 /// Progress value from 0.0 to 1.0
 ///
 /// Internally scaled to LVGL's 0-65535 range.
@@ -187,16 +232,25 @@ impl Anim {
 
     /// Set the animation path (easing function)
     pub fn set_path(&mut self, path: AnimPath) -> &mut Self {
-        // Store bezier params if using custom bezier
-        if let AnimPath::CustomBezier { x1, y1, x2, y2 } = path {
-            self.bezier_params = Some((x1, y1, x2, y2));
-            unsafe {
-                neo_lvgl_sys::lv_anim_set_bezier3_param(&mut self.raw, x1, y1, x2, y2);
+        let cb: neo_lvgl_sys::lv_anim_path_cb_t = match path {
+            // Store bezier params if using custom bezier
+            AnimPath::CustomBezier { x1, y1, x2, y2 } => {
+                self.bezier_params = Some((x1, y1, x2, y2));
+                unsafe {
+                    neo_lvgl_sys::lv_anim_set_bezier3_param(&mut self.raw, x1, y1, x2, y2);
+                }
+                Some(neo_lvgl_sys::lv_anim_path_custom_bezier3)
             }
-        }
+            AnimPath::Custom(ease) => {
+                let param = core::ptr::addr_of_mut!(self.raw.parameter).cast::<EasingFn>();
+                unsafe { core::ptr::write_unaligned(param, ease) };
+                Some(custom_path_trampoline)
+            }
+            path => path.to_raw(),
+        };
 
         unsafe {
-            neo_lvgl_sys::lv_anim_set_path_cb(&mut self.raw, path.to_raw());
+            neo_lvgl_sys::lv_anim_set_path_cb(&mut self.raw, cb);
         }
         self
     }
@@ -269,6 +323,22 @@ impl Anim {
         self
     }
 
+    /// Make the start and end values relative to a value read at start
+    ///
+    /// When the animation starts (after its delay), `cb` is called and its
+    /// result is added to both values, e.g. with `set_values(0, 40)` a
+    /// widget slides 40 px from wherever it is at that moment. Pass `None`
+    /// for absolute values.
+    pub fn set_get_value_cb(
+        &mut self,
+        cb: Option<unsafe extern "C" fn(*mut neo_lvgl_sys::lv_anim_t) -> i32>,
+    ) -> &mut Self {
+        unsafe {
+            neo_lvgl_sys::lv_anim_set_get_value_cb(&mut self.raw, cb);
+        }
+        self
+    }
+
     /// Set start callback (called when animation actually starts after delay)
     pub fn set_start_cb(
         &mut self,
@@ -569,6 +639,90 @@ impl AnimHandle {
     }
 }
 
+/// Handle to a [`Widget::move_to`](crate::widgets::Widget::move_to) animation
+///
+/// The x and y coordinates are animated separately, with the same duration
+/// and path; this handle controls both.
+pub struct MoveHandle {
+    obj: *mut neo_lvgl_sys::lv_obj_t,
+    x: AnimHandle,
+    y: AnimHandle,
+}
+
+impl MoveHandle {
+    /// Start moving `obj` from its current position to (`x`, `y`)
+    ///
+    /// Replaces a move already running on the same widget.
+    pub(crate) fn start(
+        obj: *mut neo_lvgl_sys::lv_obj_t,
+        x: i32,
+        y: i32,
+        duration_ms: u32,
+        path: AnimPath,
+    ) -> Option<Self> {
+        let (start_x, start_y) = unsafe {
+            neo_lvgl_sys::lv_anim_delete(obj.cast(), Some(move_x_exec));
+            neo_lvgl_sys::lv_anim_delete(obj.cast(), Some(move_y_exec));
+            (neo_lvgl_sys::lv_obj_get_x(obj), neo_lvgl_sys::lv_obj_get_y(obj))
+        };
+
+        let mut anim = Anim::new();
+        anim.set_var(obj.cast())
+            .set_values(start_x, x)
+            .set_duration(duration_ms)
+            .set_path(path)
+            .set_exec_cb(Some(move_x_exec));
+        let x_handle = anim.start()?;
+        anim.set_values(start_y, y).set_exec_cb(Some(move_y_exec));
+        let Some(y_handle) = anim.start() else {
+            unsafe { neo_lvgl_sys::lv_anim_delete(obj.cast(), Some(move_x_exec)) };
+            return None;
+        };
+        Some(Self {
+            obj,
+            x: x_handle,
+            y: y_handle,
+        })
+    }
+
+    /// Pause the move
+    ///
+    /// Must not be called once the move has completed.
+    pub fn pause(&self) {
+        self.x.pause();
+        self.y.pause();
+    }
+
+    /// Resume a paused move
+    pub fn resume(&self) {
+        self.x.resume();
+        self.y.resume();
+    }
+
+    /// Check if the move is paused
+    pub fn is_paused(&self) -> bool {
+        self.x.is_paused()
+    }
+
+    /// Stop the move, leaving the widget where it is
+    ///
+    /// Safe to call after the move has completed.
+    pub fn delete(self) {
+        unsafe {
+            neo_lvgl_sys::lv_anim_delete(self.obj.cast(), Some(move_x_exec));
+            neo_lvgl_sys::lv_anim_delete(self.obj.cast(), Some(move_y_exec));
+        }
+    }
+}
+
+unsafe extern "C" fn move_x_exec(var: *mut c_void, value: i32) {
+    neo_lvgl_sys::lv_obj_set_x(var.cast(), value);
+}
+
+unsafe extern "C" fn move_y_exec(var: *mut c_void, value: i32) {
+    neo_lvgl_sys::lv_obj_set_y(var.cast(), value);
+}
+
 /// Animation timeline for sequencing multiple animations
 ///
 /// Timelines allow you to coordinate multiple animations with precise timing.
@@ -583,6 +737,35 @@ impl AnimTimeline {
         NonNull::new(ptr).map(|raw| Self { raw })
     }
 
+    /// Create a timeline from `(start_time, anim)` pairs
+    ///
+    /// The animations are copied, so they don't need to outlive the call.
+    ///
+    /// ```ignore
+    /// let intro = AnimTimeline::from_segments(&[(0, &fade_title), (300, &slide_menu)])?;
+    /// intro.start();
+    /// ```
+    pub fn from_segments(segments: &[(u32, &Anim)]) -> Option<Self> {
+        let mut timeline = Self::new()?;
+        for (start_time, anim) in segments {
+            timeline.add(*start_time, anim);
+        }
+        Some(timeline)
+    }
+
+    /// Start building a timeline fluently
+    ///
+    /// ```ignore
+    /// let (intro, playtime) = AnimTimeline::builder()
+    ///     .at(0, &fade_title)
+    ///     .at(300, &slide_menu)
+    ///     .delay(100)
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> AnimTimelineBuilder {
+        AnimTimelineBuilder { timeline: Self::new() }
+    }
+
     /// Add an animation to the timeline
     ///
     /// # Arguments
@@ -699,6 +882,56 @@ impl AnimTimeline {
     }
 }
 
+/// Fluent builder for an [`AnimTimeline`]
+///
+/// Created with [`AnimTimeline::builder`].
+pub struct AnimTimelineBuilder {
+    timeline: Option<AnimTimeline>,
+}
+
+impl AnimTimelineBuilder {
+    /// Add a copy of `anim` starting `start_time` ms after the timeline starts
+    pub fn at(mut self, start_time: u32, anim: &Anim) -> Self {
+        if let Some(timeline) = self.timeline.as_mut() {
+            timeline.add(start_time, anim);
+        }
+        self
+    }
+
+    /// Set the delay before the timeline starts playing
+    pub fn delay(self, ms: u32) -> Self {
+        if let Some(timeline) = &self.timeline {
+            timeline.set_delay(ms);
+        }
+        self
+    }
+
+    /// Set how many times the whole timeline plays
+    pub fn repeat(self, count: RepeatCount) -> Self {
+        if let Some(timeline) = &self.timeline {
+            timeline.set_repeat(count);
+        }
+        self
+    }
+
+    /// Play the timeline backwards
+    pub fn reverse(self, reverse: bool) -> Self {
+        if let Some(timeline) = &self.timeline {
+            timeline.set_reverse(reverse);
+        }
+        self
+    }
+
+    /// Finish the timeline and get its playtime in milliseconds
+    ///
+    /// Returns `None` if LVGL could not allocate the timeline.
+    pub fn build(self) -> Option<(AnimTimeline, u32)> {
+        let timeline = self.timeline?;
+        let playtime = timeline.playtime();
+        Some((timeline, playtime))
+    }
+}
+
 impl Default for AnimTimeline {
     fn default() -> Self {
         Self::new().expect("Failed to create animation timeline")
@@ -751,3 +984,258 @@ pub fn anim_refr_now() {
 pub fn anim_speed_to_time(speed: u32, start: i32, end: i32) -> u32 {
     unsafe { neo_lvgl_sys::lv_anim_speed_to_time(speed, start, end) }
 }
+
+/// Animate a numeric style property of `widget` as a local style
+///
+/// The property is set with `lv_obj_set_local_style_prop` on every frame,
+/// so it overrides styles added to the widget for `selector`, e.g. to grow
+/// the border when a widget gets focus:
+///
+/// ```ignore
+/// let focused = StyleSelector::MAIN | StyleSelector::FOCUSED;
+/// animate_style_prop(&button, StyleProp::BorderWidth, 0, 4, 150, focused);
+/// ```
+///
+/// Numeric properties can be animated: sizes, positions, paddings,
+/// margins, radius, border/outline/shadow/line/arc widths, offsets,
+/// translations and transforms, letter and line spacing, and the
+/// opacities (clamped to 0..=255). Colors, fonts, image sources and
+/// [`StyleProp::Align`] are not numbers; for them `None` is returned and
+/// nothing is started.
+///
+/// Starting another animation on the same property doesn't stop this one;
+/// delete the returned handle first.
+pub fn animate_style_prop<'a>(
+    widget: &impl crate::widgets::Widget<'a>,
+    prop: crate::style::StyleProp,
+    from: i32,
+    to: i32,
+    duration_ms: u32,
+    selector: crate::style::StyleSelector,
+) -> Option<AnimHandle> {
+    use crate::style::{StyleProp::Align, StyleValue};
+
+    let (from, to) = match prop.value_from_raw(neo_lvgl_sys::lv_style_value_t { num: 0 }) {
+        StyleValue::Color(_) | StyleValue::Ptr(_) => return None,
+        StyleValue::Opa(_) => (from.clamp(0, 255), to.clamp(0, 255)),
+        StyleValue::Int(_) if prop == Align => return None,
+        StyleValue::Int(_) => (from, to),
+    };
+    // The property id fits in 8 bits, the part and state in the lower 24
+    let packed = ((prop.raw() as usize) << 24) | (selector.bits() as usize & 0xFF_FFFF);
+
+    let mut anim = Anim::new();
+    anim.set_var(widget.raw().cast())
+        .set_values(from, to)
+        .set_duration(duration_ms)
+        .set_user_data(packed as *mut c_void)
+        .set_custom_exec_cb(Some(style_prop_exec));
+    anim.start()
+}
+
+/// Animate the uniform scale of `widget` (256 = 100%)
+///
+/// Sets `transform_scale_x` and `transform_scale_y` as local styles of the
+/// main part on every frame, e.g. for a zoom-in when a card appears:
+///
+/// ```ignore
+/// animate_scale(&card, 128, 256, 200);
+/// ```
+///
+/// The widget is scaled around its transform pivot, the top-left corner by
+/// default. Replaces a running `animate_scale` on the same widget.
+pub fn animate_scale<'a>(
+    widget: &impl crate::widgets::Widget<'a>,
+    from: i32,
+    to: i32,
+    duration_ms: u32,
+) -> Option<AnimHandle> {
+    unsafe {
+        neo_lvgl_sys::lv_anim_delete(widget.raw().cast(), Some(scale_exec));
+    }
+    let mut anim = Anim::new();
+    anim.set_var(widget.raw().cast())
+        .set_values(from, to)
+        .set_duration(duration_ms)
+        .set_exec_cb(Some(scale_exec));
+    anim.start()
+}
+
+unsafe extern "C" fn scale_exec(var: *mut c_void, value: i32) {
+    let main = neo_lvgl_sys::lv_part_t_LV_PART_MAIN;
+    neo_lvgl_sys::lv_obj_set_style_transform_scale_x(var.cast(), value, main);
+    neo_lvgl_sys::lv_obj_set_style_transform_scale_y(var.cast(), value, main);
+}
+
+unsafe extern "C" fn style_prop_exec(anim: *mut neo_lvgl_sys::lv_anim_t, value: i32) {
+    let packed = neo_lvgl_sys::lv_anim_get_user_data(anim) as usize;
+    neo_lvgl_sys::lv_obj_set_local_style_prop(
+        (*anim).var.cast(),
+        (packed >> 24) as neo_lvgl_sys::lv_style_prop_t,
+        neo_lvgl_sys::lv_style_value_t { num: value },
+        (packed & 0xFF_FFFF) as neo_lvgl_sys::lv_style_selector_t,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeline_playtime() {
+        let _lvgl = crate::test_lvgl();
+
+        let mut first = Anim::new();
+        first.set_values(0, 100).set_duration(300);
+        let mut second = Anim::new();
+        second.set_values(0, 100).set_duration(200);
+
+        let timeline = AnimTimeline::from_segments(&[(0, &first), (400, &second)]).unwrap();
+        assert_eq!(timeline.playtime(), 600);
+
+        let (_timeline, playtime) =
+            AnimTimeline::builder().at(0, &first).at(100, &second).build().unwrap();
+        assert_eq!(playtime, 300);
+    }
+
+    #[test]
+    fn test_animate_style_prop() {
+        use crate::style::{StyleProp, StyleSelector};
+        use crate::widgets::{Container, Widget};
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::display::Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        let radius = || panel.style_prop(StyleProp::Radius, StyleSelector::MAIN).as_int();
+        let step = |ms| {
+            crate::tick_inc(ms);
+            crate::task_handler();
+        };
+
+        let main = StyleSelector::MAIN;
+        assert!(animate_style_prop(&panel, StyleProp::Radius, 0, 20, 100, main).is_some());
+        step(50);
+        assert!(matches!(radius(), Some(5..=15)), "{:?}", radius());
+        step(60);
+        assert_eq!(radius(), Some(20));
+
+        assert!(animate_style_prop(&panel, StyleProp::BgColor, 0, 1, 100, main).is_none());
+    }
+
+    #[test]
+    fn test_move_to() {
+        use crate::widgets::{Container, Widget};
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::display::Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        panel.set_pos(0, 0);
+        let step = |ms| {
+            crate::tick_inc(ms);
+            crate::task_handler();
+        };
+
+        let handle = panel.move_to(100, 50, 200, AnimPath::Linear).unwrap();
+        step(100);
+        assert!(matches!(panel.x(), 30..=70), "{}", panel.x());
+        assert!(matches!(panel.y(), 15..=35), "{}", panel.y());
+
+        handle.pause();
+        let paused_at = (panel.x(), panel.y());
+        step(50);
+        assert_eq!((panel.x(), panel.y()), paused_at);
+        handle.resume();
+
+        step(250);
+        assert_eq!((panel.x(), panel.y()), (100, 50));
+        handle.delete();
+
+        // A new move starts from where the widget is
+        let handle = panel.move_to(0, 0, 100, AnimPath::EaseOut).unwrap();
+        step(120);
+        assert_eq!((panel.x(), panel.y()), (0, 0));
+        handle.delete();
+    }
+
+    #[test]
+    fn test_animate_scale() {
+        use crate::style::StyleSelector;
+        use crate::widgets::{Button, Widget};
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::display::Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let button = Button::new(&screen).unwrap();
+        button.set_size(60, 30);
+        crate::layout::LayoutExt::update_layout(&button);
+
+        button.set_style_transform_scale(512, StyleSelector::MAIN);
+        assert_eq!((button.transformed_width(), button.transformed_height()), (120, 60));
+        assert_eq!(button.width(), 60);
+
+        animate_scale(&button, 256, 128, 100).unwrap();
+        crate::tick_inc(150);
+        crate::task_handler();
+        assert_eq!((button.transformed_width(), button.transformed_height()), (30, 15));
+    }
+
+    static VALUE: core::sync::atomic::AtomicI32 = core::sync::atomic::AtomicI32::new(0);
+
+    unsafe extern "C" fn store_value(_var: *mut c_void, value: i32) {
+        VALUE.store(value, core::sync::atomic::Ordering::Relaxed);
+    }
+
+    unsafe extern "C" fn current_value(_anim: *mut neo_lvgl_sys::lv_anim_t) -> i32 {
+        VALUE.load(core::sync::atomic::Ordering::Relaxed)
+    }
+
+    fn inverse(t: Progress) -> Progress {
+        Progress::new(1.0 - t.as_f32())
+    }
+
+    #[test]
+    fn test_custom_inverse_path() {
+        let _lvgl = crate::test_lvgl();
+
+        let value = || VALUE.load(core::sync::atomic::Ordering::Relaxed);
+        let step = |ms| {
+            crate::tick_inc(ms);
+            crate::task_handler();
+        };
+
+        let mut anim = Anim::new();
+        anim.set_var(core::ptr::addr_of!(VALUE) as *mut c_void)
+            .set_values(0, 100)
+            .set_duration(100)
+            .set_path(AnimPath::Custom(inverse))
+            .set_exec_cb(Some(store_value));
+        anim.start().unwrap();
+        step(25);
+        assert!(matches!(value(), 65..=85), "{}", value());
+        step(100);
+        assert_eq!(value(), 0);
+    }
+
+    #[test]
+    fn test_relative_values() {
+        let _lvgl = crate::test_lvgl();
+
+        // Relative to the value at start: 40 + (0..10)
+        VALUE.store(40, core::sync::atomic::Ordering::Relaxed);
+        let mut anim = Anim::new();
+        anim.set_var(core::ptr::addr_of!(VALUE) as *mut c_void)
+            .set_values(0, 10)
+            .set_duration(50)
+            .set_get_value_cb(Some(current_value))
+            .set_exec_cb(Some(store_value));
+        anim.start().unwrap();
+        crate::tick_inc(60);
+        crate::task_handler();
+        assert_eq!(VALUE.load(core::sync::atomic::Ordering::Relaxed), 50);
+    }
+}
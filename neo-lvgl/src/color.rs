@@ -34,6 +34,72 @@ impl Color {
         Self(neo_lvgl_sys::lv_color_t { blue: 255, green: 255, red: 255 })
     }
 
+    /// Create a color from 8-bit RGB channels.
+    ///
+    /// `lv_color_t` always stores 8 bits per channel, independent of the
+    /// `LV_COLOR_DEPTH` configured in `lv_conf.h`, so this round-trips
+    /// exactly with [`Color::to_rgb888`].
+    #[inline]
+    pub const fn from_rgb888(r: u8, g: u8, b: u8) -> Self {
+        Self(neo_lvgl_sys::lv_color_t { blue: b, green: g, red: r })
+    }
+
+    /// Get the 8-bit RGB channels of this color as `(r, g, b)`.
+    #[inline]
+    pub fn to_rgb888(self) -> (u8, u8, u8) {
+        let v = unsafe { neo_lvgl_sys::lv_color_to_u32(self.0) };
+        ((v >> 16) as u8, (v >> 8) as u8, v as u8)
+    }
+
+    /// Create a color from a packed RGB565 value.
+    ///
+    /// The low bits of each channel are filled by bit replication so that
+    /// `0xFFFF` maps to pure white.
+    #[inline]
+    pub const fn from_rgb565(v: u16) -> Self {
+        let r5 = ((v >> 11) & 0x1F) as u8;
+        let g6 = ((v >> 5) & 0x3F) as u8;
+        let b5 = (v & 0x1F) as u8;
+        Self::from_rgb888((r5 << 3) | (r5 >> 2), (g6 << 2) | (g6 >> 4), (b5 << 3) | (b5 >> 2))
+    }
+
+    /// Convert this color to a packed RGB565 value.
+    ///
+    /// This is what LVGL renders with `LV_COLOR_DEPTH == 16`. The lower 3
+    /// (red, blue) or 2 (green) bits of each channel are lost, so
+    /// `Color::from_rgb565(c.to_rgb565())` is only approximately `c`.
+    #[inline]
+    pub fn to_rgb565(self) -> u16 {
+        unsafe { neo_lvgl_sys::lv_color_to_16(self.0) }
+    }
+
+    /// Get the perceived brightness (0 = black, 255 = white)
+    ///
+    /// Uses the ITU-R BT.601 luma weights, the same as embedded-graphics'
+    /// `Rgb888` to `Gray8` conversion.
+    #[inline]
+    pub fn luminance(self) -> u8 {
+        let (r, g, b) = (self.0.red as u16, self.0.green as u16, self.0.blue as u16);
+        ((r * 77 + g * 150 + b * 29) >> 8) as u8
+    }
+
+    /// Pick black or white, whichever is more readable on top of this color
+    pub fn contrasting_text(self) -> Self {
+        if self.luminance() < 128 {
+            Self::white()
+        } else {
+            Self::black()
+        }
+    }
+
+    /// Pick the candidate whose luminance differs most from this color
+    ///
+    /// Returns `None` if `candidates` is empty.
+    pub fn best_contrast(self, candidates: &[Color]) -> Option<Self> {
+        let own = self.luminance();
+        candidates.iter().copied().max_by_key(|c| c.luminance().abs_diff(own))
+    }
+
     /// Get the raw LVGL color value
     #[inline]
     pub(crate) fn raw(self) -> neo_lvgl_sys::lv_color_t {
@@ -114,3 +180,34 @@ impl Default for Opacity {
         Self::OPAQUE
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luminance() {
+        assert_eq!(Color::black().luminance(), 0);
+        assert_eq!(Color::white().luminance(), 255);
+        assert_eq!(Color::from_rgb888(255, 0, 0).luminance(), 76);
+        assert_eq!(Color::from_rgb888(0, 255, 0).luminance(), 149);
+        assert_eq!(Color::from_rgb888(0, 0, 255).luminance(), 28);
+    }
+
+    #[test]
+    fn test_contrasting_text() {
+        let (r, g, b) = Color::from_rgb888(0, 0, 255).contrasting_text().to_rgb888();
+        assert_eq!((r, g, b), (255, 255, 255));
+        let (r, g, b) = Color::from_rgb888(255, 235, 59).contrasting_text().to_rgb888();
+        assert_eq!((r, g, b), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_best_contrast() {
+        let gray = Color::from_rgb888(200, 200, 200);
+        let candidates = [Color::from_rgb888(180, 180, 180), Color::from_rgb888(20, 20, 60)];
+        let best = gray.best_contrast(&candidates).unwrap();
+        assert_eq!(best.to_rgb888(), (20, 20, 60));
+        assert!(gray.best_contrast(&[]).is_none());
+    }
+}
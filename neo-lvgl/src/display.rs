@@ -1,5 +1,6 @@
 //! Display management
 
+use core::cell::Cell;
 use core::ptr::NonNull;
 
 #[cfg(feature = "alloc")]
@@ -12,6 +13,9 @@ use alloc::vec::Vec;
 /// LVGL display
 pub struct Display {
     raw: NonNull<neo_lvgl_sys::lv_display_t>,
+    /// Render mode of the buffers, once set through this wrapper; LVGL
+    /// doesn't expose it
+    render_mode: Cell<Option<RenderMode>>,
 }
 
 impl Display {
@@ -24,10 +28,28 @@ impl Display {
     pub fn new(width: i32, height: i32) -> Option<Self> {
         unsafe {
             let ptr = neo_lvgl_sys::lv_display_create(width, height);
-            NonNull::new(ptr).map(|raw| Self { raw })
+            NonNull::new(ptr).map(|raw| Self {
+                raw,
+                render_mode: Cell::new(None),
+            })
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(width: i32, height: i32) -> Result<Self, crate::LvError> {
+        Self::new(width, height).ok_or(crate::LvError::OutOfMemory)
+    }
+
+    /// Create a display and make `tick_cb` LVGL's time source
+    ///
+    /// Shorthand for [`set_tick_cb`](crate::set_tick_cb) followed by
+    /// [`new`](Self::new). The tick source is global, so it also applies to
+    /// every other display.
+    pub fn new_with_tick_cb(width: i32, height: i32, tick_cb: crate::TickGetCb) -> Option<Self> {
+        crate::set_tick_cb(Some(tick_cb));
+        Self::new(width, height)
+    }
+
     /// Get the raw pointer to the display
     #[inline]
     pub fn raw(&self) -> *mut neo_lvgl_sys::lv_display_t {
@@ -41,6 +63,11 @@ impl Display {
         }
     }
 
+    /// Get a handle for signalling flush completion from an interrupt
+    pub fn handle(&self) -> DisplayHandle {
+        DisplayHandle { raw: self.raw }
+    }
+
     /// Get the currently active screen for this display
     pub fn active_screen(&self) -> crate::widgets::Screen<'_> {
         unsafe {
@@ -65,13 +92,79 @@ impl Display {
             None => core::ptr::null_mut(),
         };
 
+        self.set_buffers_raw(buf1.as_mut_ptr(), buf2_ptr, buf1.len(), render_mode);
+    }
+
+    /// Hand the buffers to LVGL and remember their render mode
+    ///
+    /// Every path that sets buffers goes through here, so that
+    /// [`set_color_format_checked`](Self::set_color_format_checked) knows
+    /// the mode.
+    unsafe fn set_buffers_raw(
+        &self,
+        buf1: *mut u8,
+        buf2: *mut u8,
+        len: usize,
+        render_mode: RenderMode,
+    ) {
         neo_lvgl_sys::lv_display_set_buffers(
             self.raw.as_ptr(),
-            buf1.as_mut_ptr() as *mut _,
-            buf2_ptr,
-            buf1.len() as u32,
+            buf1.cast(),
+            buf2.cast(),
+            len as u32,
             render_mode.to_raw(),
         );
+        self.render_mode.set(Some(render_mode));
+    }
+
+    /// Set the color format and display buffers after checking they match
+    ///
+    /// A buffer sized for the wrong format is a common cause of garbled
+    /// output, so this checks that:
+    ///
+    /// - each buffer holds a whole number of pixels in `format`
+    /// - each buffer holds at least one full line
+    /// - in [`RenderMode::Full`] and [`RenderMode::Direct`], each buffer
+    ///   covers the whole screen
+    /// - `buf2`, if given, is the same size as `buf1`
+    ///
+    /// Nothing is changed if a check fails.
+    pub fn set_buffers_checked(
+        &self,
+        buf1: &'static mut [u8],
+        buf2: Option<&'static mut [u8]>,
+        render_mode: RenderMode,
+        format: ColorFormat,
+    ) -> Result<(), BufferError> {
+        if buf2.as_ref().is_some_and(|b| b.len() != buf1.len()) {
+            return Err(BufferError::SizeMismatch);
+        }
+        self.check_buffer(buf1.len(), render_mode, format)?;
+
+        self.set_color_format(format);
+        unsafe { self.set_buffers(buf1, buf2, render_mode) };
+        Ok(())
+    }
+
+    fn check_buffer(
+        &self,
+        len: usize,
+        render_mode: RenderMode,
+        format: ColorFormat,
+    ) -> Result<(), BufferError> {
+        let (width, height) = (self.width() as u32, self.height() as usize);
+        let bytes_per_pixel = (format.bits_per_pixel() as usize).div_ceil(8);
+        if len % bytes_per_pixel != 0 {
+            return Err(BufferError::NotWholePixels);
+        }
+        if len < format.stride(width) + format.palette_size() {
+            return Err(BufferError::SmallerThanLine);
+        }
+        let screen = format.stride(width) * height + format.palette_size();
+        if render_mode != RenderMode::Partial && len < screen {
+            return Err(BufferError::SmallerThanScreen);
+        }
+        Ok(())
     }
 
     /// Set the flush callback for the display.
@@ -83,6 +176,25 @@ impl Display {
         }
     }
 
+    /// Set a callback LVGL calls to wait for a flush to complete.
+    ///
+    /// Without one, LVGL busy-waits until [`flush_ready`](Self::flush_ready)
+    /// is called whenever it needs a buffer that is still being sent. With
+    /// one, LVGL calls `cb` instead and treats the flush as complete once it
+    /// returns, so `cb` must block until the transfer is done. This lets an
+    /// RTOS task sleep on a semaphore given by the DMA interrupt, or poll an
+    /// SPI peripheral while yielding, instead of spinning.
+    ///
+    /// It pairs with a flush callback that only starts the transfer and
+    /// returns; calling `flush_ready` is then optional. LVGL doesn't call
+    /// `cb` if the flush already finished. Pass `None` to go back to
+    /// busy-waiting.
+    pub fn set_flush_wait_cb(&self, cb: Option<FlushWaitCb>) {
+        unsafe {
+            neo_lvgl_sys::lv_display_set_flush_wait_cb(self.raw.as_ptr(), cb);
+        }
+    }
+
     /// Signal that the flush operation is complete.
     ///
     /// Call this from your flush callback when the transfer is done.
@@ -102,16 +214,118 @@ impl Display {
         unsafe { neo_lvgl_sys::lv_display_get_vertical_resolution(self.raw.as_ptr()) }
     }
 
+    /// Enable or disable anti-aliasing for everything drawn on this display.
+    ///
+    /// Anti-aliasing is on by default. Turning it off gives crisp,
+    /// pixel-exact edges and saves CPU time, e.g. in low-power modes.
+    ///
+    /// This is the only global runtime toggle. The other render quality
+    /// settings are:
+    /// - compile-time: `LV_DRAW_SW_COMPLEX` in `lv_conf.h` enables
+    ///   anti-aliased arcs, rounded corners, shadows and transformations;
+    ///   without it those are not drawn at all
+    /// - runtime, per widget: `Image::set_antialias` for transformed images,
+    ///   and `Style::set_arc_rounded`/`set_line_rounded` for the shape of
+    ///   arc and line ends
+    pub fn set_antialiasing(&self, enable: bool) {
+        unsafe {
+            neo_lvgl_sys::lv_display_set_antialiasing(self.raw.as_ptr(), enable);
+        }
+    }
+
+    /// Check if anti-aliasing is enabled for this display
+    pub fn antialiasing(&self) -> bool {
+        unsafe { neo_lvgl_sys::lv_display_get_antialiasing(self.raw.as_ptr()) }
+    }
+
+    /// Set the display rotation.
+    ///
+    /// LVGL swaps the reported width and height for 90 and 270 degrees and
+    /// sends `EventCode::ResolutionChanged`.
+    pub fn set_rotation(&self, rotation: DisplayRotation) {
+        unsafe {
+            neo_lvgl_sys::lv_display_set_rotation(self.raw.as_ptr(), rotation.to_raw());
+        }
+    }
+
+    /// Get the display rotation
+    pub fn rotation(&self) -> DisplayRotation {
+        let raw = unsafe { neo_lvgl_sys::lv_display_get_rotation(self.raw.as_ptr()) };
+        DisplayRotation::from_raw(raw)
+    }
+
+    /// Redraw all invalidated areas of this display immediately.
+    ///
+    /// Normally rendering happens in [`crate::task_handler`]. Use this to
+    /// force a synchronous refresh, e.g. before taking a snapshot.
+    pub fn refresh_now(&self) {
+        unsafe {
+            neo_lvgl_sys::lv_refr_now(self.raw.as_ptr());
+        }
+    }
+
+    /// Get the milliseconds since the last user input on this display
+    ///
+    /// See [`crate::inactive_time_ms`] for the time across all displays.
+    pub fn inactive_time_ms(&self) -> u32 {
+        unsafe { neo_lvgl_sys::lv_display_get_inactive_time(self.raw.as_ptr()) }
+    }
+
+    /// Reset the inactivity time of this display to zero
+    ///
+    /// Use this for input LVGL doesn't see, e.g. a wake-up button handled
+    /// by the application.
+    pub fn trigger_activity(&self) {
+        unsafe {
+            neo_lvgl_sys::lv_display_trigger_activity(self.raw.as_ptr());
+        }
+    }
+
     /// Set the color format for this display.
     ///
     /// This determines how pixels are encoded in the display buffers.
     /// The default is ARGB8888.
+    ///
+    /// Set the format before the buffers, and size the buffers for it.
+    /// Switching to a format with more bytes per pixel afterwards makes
+    /// LVGL render past the end of a full-screen buffer. Use
+    /// [`set_color_format_checked`](Self::set_color_format_checked) if the
+    /// format has to change later, or [`set_buffers_checked`](Self::set_buffers_checked)
+    /// to set both at once.
     pub fn set_color_format(&self, format: ColorFormat) {
         unsafe {
             neo_lvgl_sys::lv_display_set_color_format(self.raw.as_ptr(), format.to_raw());
         }
     }
 
+    /// Change the color format after checking the buffers still fit it
+    ///
+    /// Applies the checks of [`set_buffers_checked`](Self::set_buffers_checked)
+    /// to the buffers set earlier, with their render mode. The buffer size
+    /// is read back from LVGL. The render mode isn't, so buffers handed to
+    /// LVGL without this wrapper give [`BufferError::UnknownRenderMode`].
+    /// Nothing is changed if a check fails. Without buffers, this is the
+    /// same as [`set_color_format`](Self::set_color_format).
+    pub fn set_color_format_checked(&self, format: ColorFormat) -> Result<(), BufferError> {
+        if let Some(len) = self.buffer_size() {
+            let render_mode = self.render_mode.get().ok_or(BufferError::UnknownRenderMode)?;
+            self.check_buffer(len, render_mode, format)?;
+        }
+        self.set_color_format(format);
+        Ok(())
+    }
+
+    /// Size in bytes of LVGL's active draw buffer, if buffers are set
+    fn buffer_size(&self) -> Option<usize> {
+        unsafe {
+            let buf = neo_lvgl_sys::lv_display_get_buf_active(self.raw.as_ptr());
+            if buf.is_null() || (*buf).data.is_null() {
+                return None;
+            }
+            Some((*buf).data_size as usize)
+        }
+    }
+
     /// Get the current color format for this display.
     pub fn color_format(&self) -> ColorFormat {
         let raw = unsafe { neo_lvgl_sys::lv_display_get_color_format(self.raw.as_ptr()) };
@@ -127,6 +341,49 @@ impl Drop for Display {
     }
 }
 
+/// A copyable handle for signalling flush completion from outside the flush callback
+///
+/// Obtain one with [`Display::handle`] or `ManagedDisplay::handle` and move it
+/// into a DMA-complete interrupt handler. It does not own the display and must
+/// not be used after the display is dropped.
+#[derive(Clone, Copy)]
+pub struct DisplayHandle {
+    raw: NonNull<neo_lvgl_sys::lv_display_t>,
+}
+
+// SAFETY: lv_display_flush_ready only sets a flag on the display and is
+// intended to be called from interrupt context.
+unsafe impl Send for DisplayHandle {}
+unsafe impl Sync for DisplayHandle {}
+
+impl DisplayHandle {
+    /// Signal LVGL that the current flush has finished
+    ///
+    /// Safe to call from an interrupt handler.
+    pub fn flush_ready(&self) {
+        unsafe {
+            neo_lvgl_sys::lv_display_flush_ready(self.raw.as_ptr());
+        }
+    }
+}
+
+/// Error returned by [`Display::set_buffers_checked`] and
+/// [`Display::set_color_format_checked`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BufferError {
+    /// The buffer length is not a multiple of the format's pixel size
+    NotWholePixels,
+    /// The buffer can't hold a single line of the display
+    SmallerThanLine,
+    /// Full and direct render modes need a buffer for the whole screen
+    SmallerThanScreen,
+    /// The two buffers have different lengths
+    SizeMismatch,
+    /// The buffers were set without this wrapper, so their render mode
+    /// is unknown
+    UnknownRenderMode,
+}
+
 /// Display render mode
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum RenderMode {
@@ -153,6 +410,40 @@ impl RenderMode {
     }
 }
 
+/// Display rotation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DisplayRotation {
+    /// No rotation
+    #[default]
+    Deg0,
+    /// Rotated 90 degrees
+    Deg90,
+    /// Rotated 180 degrees
+    Deg180,
+    /// Rotated 270 degrees
+    Deg270,
+}
+
+impl DisplayRotation {
+    fn to_raw(self) -> neo_lvgl_sys::lv_display_rotation_t {
+        match self {
+            DisplayRotation::Deg0 => neo_lvgl_sys::lv_display_rotation_t_LV_DISPLAY_ROTATION_0,
+            DisplayRotation::Deg90 => neo_lvgl_sys::lv_display_rotation_t_LV_DISPLAY_ROTATION_90,
+            DisplayRotation::Deg180 => neo_lvgl_sys::lv_display_rotation_t_LV_DISPLAY_ROTATION_180,
+            DisplayRotation::Deg270 => neo_lvgl_sys::lv_display_rotation_t_LV_DISPLAY_ROTATION_270,
+        }
+    }
+
+    fn from_raw(raw: neo_lvgl_sys::lv_display_rotation_t) -> Self {
+        match raw {
+            neo_lvgl_sys::lv_display_rotation_t_LV_DISPLAY_ROTATION_90 => DisplayRotation::Deg90,
+            neo_lvgl_sys::lv_display_rotation_t_LV_DISPLAY_ROTATION_180 => DisplayRotation::Deg180,
+            neo_lvgl_sys::lv_display_rotation_t_LV_DISPLAY_ROTATION_270 => DisplayRotation::Deg270,
+            _ => DisplayRotation::Deg0,
+        }
+    }
+}
+
 /// Display color format
 ///
 /// Determines how pixels are encoded in display buffers.
@@ -190,7 +481,7 @@ pub enum ColorFormat {
 }
 
 impl ColorFormat {
-    fn to_raw(self) -> neo_lvgl_sys::lv_color_format_t {
+    pub(crate) fn to_raw(self) -> neo_lvgl_sys::lv_color_format_t {
         match self {
             ColorFormat::L8 => neo_lvgl_sys::lv_color_format_t_LV_COLOR_FORMAT_L8,
             ColorFormat::A1 => neo_lvgl_sys::lv_color_format_t_LV_COLOR_FORMAT_A1,
@@ -236,6 +527,8 @@ impl ColorFormat {
     ///
     /// Note: For sub-byte formats (A1, A2, A4, I1, I2, I4), this returns 1
     /// as the minimum addressable unit, though actual bits per pixel is less.
+    /// Use [`bits_per_pixel`](Self::bits_per_pixel) or
+    /// [`stride`](Self::stride) for buffer math.
     pub fn bytes_per_pixel(&self) -> usize {
         match self {
             ColorFormat::A1 | ColorFormat::I1 => 1,
@@ -247,6 +540,102 @@ impl ColorFormat {
             ColorFormat::Argb8888 | ColorFormat::Xrgb8888 => 4,
         }
     }
+
+    /// Get the number of bits per pixel for this format (1, 2, 4, 8, 16, 24 or 32)
+    pub fn bits_per_pixel(&self) -> u8 {
+        match self {
+            ColorFormat::A1 | ColorFormat::I1 => 1,
+            ColorFormat::A2 | ColorFormat::I2 => 2,
+            ColorFormat::A4 | ColorFormat::I4 => 4,
+            ColorFormat::L8 | ColorFormat::A8 | ColorFormat::I8 => 8,
+            ColorFormat::Rgb565 | ColorFormat::Rgb565Swapped => 16,
+            ColorFormat::Rgb888 => 24,
+            ColorFormat::Argb8888 | ColorFormat::Xrgb8888 => 32,
+        }
+    }
+
+    /// Get the number of bytes in one row of `width` pixels
+    ///
+    /// Follows LVGL's rules, including the `LV_DRAW_BUF_STRIDE_ALIGN` padding
+    /// configured in `lv_conf.h`, so it matches what the renderer expects.
+    /// Requires LVGL to be initialized.
+    pub fn stride(&self, width: u32) -> usize {
+        unsafe { neo_lvgl_sys::lv_draw_buf_width_to_stride(width, self.to_raw()) as usize }
+    }
+
+    /// Size in bytes of the palette that precedes pixel data in indexed formats
+    pub(crate) fn palette_size(&self) -> usize {
+        match self {
+            ColorFormat::I1 | ColorFormat::I2 | ColorFormat::I4 | ColorFormat::I8 => {
+                4 << self.bits_per_pixel()
+            }
+            _ => 0,
+        }
+    }
+}
+
+// Closure support (requires alloc feature)
+#[cfg(feature = "alloc")]
+mod closure_support {
+    use super::*;
+    use crate::event::EventCode;
+    use core::ffi::c_void;
+
+    /// Container for resolution-changed closure
+    struct ResolutionClosure {
+        callback: Box<dyn FnMut(i32, i32)>,
+    }
+
+    /// Trampoline for display resolution-changed events
+    unsafe extern "C" fn resolution_trampoline(e: *mut neo_lvgl_sys::lv_event_t) {
+        let user_data = neo_lvgl_sys::lv_event_get_user_data(e);
+        let disp = neo_lvgl_sys::lv_event_get_target(e) as *mut neo_lvgl_sys::lv_display_t;
+        if !user_data.is_null() && !disp.is_null() {
+            let closure = &mut *(user_data as *mut ResolutionClosure);
+            (closure.callback)(
+                neo_lvgl_sys::lv_display_get_horizontal_resolution(disp),
+                neo_lvgl_sys::lv_display_get_vertical_resolution(disp),
+            );
+        }
+    }
+
+    /// Free the resolution-changed closure when the display is deleted
+    unsafe extern "C" fn free_resolution_closure(e: *mut neo_lvgl_sys::lv_event_t) {
+        let user_data = neo_lvgl_sys::lv_event_get_user_data(e);
+        drop(Box::from_raw(user_data as *mut ResolutionClosure));
+    }
+
+    impl Display {
+        /// Call `callback` with the new `(width, height)` whenever the
+        /// resolution changes, e.g. after [`Display::set_rotation`].
+        ///
+        /// Use this to re-align widgets for responsive layouts. The closure
+        /// is freed when the display is deleted.
+        pub fn on_resolution_changed<F>(&self, callback: F)
+        where
+            F: FnMut(i32, i32) + 'static,
+        {
+            let closure = Box::new(ResolutionClosure {
+                callback: Box::new(callback),
+            });
+            let raw_closure = Box::into_raw(closure);
+
+            unsafe {
+                neo_lvgl_sys::lv_display_add_event_cb(
+                    self.raw.as_ptr(),
+                    Some(resolution_trampoline),
+                    EventCode::ResolutionChanged.to_raw(),
+                    raw_closure as *mut c_void,
+                );
+                neo_lvgl_sys::lv_display_add_event_cb(
+                    self.raw.as_ptr(),
+                    Some(free_resolution_closure),
+                    EventCode::Delete.to_raw(),
+                    raw_closure as *mut c_void,
+                );
+            }
+        }
+    }
 }
 
 /// Flush callback type
@@ -256,6 +645,9 @@ pub type FlushCb = unsafe extern "C" fn(
     px_map: *mut u8,
 );
 
+/// Flush wait callback type, see [`Display::set_flush_wait_cb`]
+pub type FlushWaitCb = unsafe extern "C" fn(disp: *mut neo_lvgl_sys::lv_display_t);
+
 /// Display driver trait for custom display implementations
 pub trait DisplayDriver {
     /// Get display dimensions (width, height)
@@ -266,11 +658,40 @@ pub trait DisplayDriver {
     /// # Arguments
     ///
     /// * `area` - The rectangular area to update
-    /// * `pixels` - The pixel data in the display's color format
+    /// * `pixels` - The pixel data in the display's color format. In
+    ///   [`RenderMode::Partial`] it holds just `area`, one
+    ///   [`ColorFormat::stride`] of `area.width()` per row. In
+    ///   [`RenderMode::Full`] and [`RenderMode::Direct`] it is the whole
+    ///   screen buffer and `area` is the part that changed.
     fn flush(&mut self, area: &Area, pixels: &[u8]);
 
     /// Called when flush is complete (optional)
     fn flush_ready(&mut self) {}
+
+    /// Whether [`flush`](Self::flush) completes asynchronously (optional)
+    ///
+    /// By default `ManagedDisplay` signals LVGL that the flush is done as soon
+    /// as `flush` returns. Return `true` for drivers that only start a
+    /// transfer in `flush` (e.g. non-blocking SPI DMA); the driver must then
+    /// call [`DisplayHandle::flush_ready`] itself once the transfer completes.
+    ///
+    /// `pixels` stays valid and untouched by LVGL until that call, so the
+    /// driver may keep its pointer for the transfer.
+    ///
+    /// **Forgetting to signal completion stalls rendering forever**: LVGL
+    /// waits for the flush to finish before it reuses the buffer.
+    fn flush_is_async(&self) -> bool {
+        false
+    }
+
+    /// Block until the transfer started by [`flush`](Self::flush) is done (optional)
+    ///
+    /// Only called after [`ManagedDisplay::enable_flush_wait`], and only
+    /// while an asynchronous flush is still in progress. LVGL considers the
+    /// flush complete when this returns, so the driver doesn't need to
+    /// signal completion; it can instead wait here on an RTOS semaphore or
+    /// poll the peripheral while yielding to other tasks.
+    fn flush_wait(&mut self) {}
 }
 
 /// Rectangular area
@@ -283,6 +704,21 @@ pub struct Area {
 }
 
 impl Area {
+    /// Create an area from its corner coordinates (both inclusive)
+    pub const fn new(x1: i16, y1: i16, x2: i16, y2: i16) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Convert to a raw LVGL area
+    pub fn to_raw(&self) -> neo_lvgl_sys::lv_area_t {
+        neo_lvgl_sys::lv_area_t {
+            x1: self.x1 as i32,
+            y1: self.y1 as i32,
+            x2: self.x2 as i32,
+            y2: self.y2 as i32,
+        }
+    }
+
     /// Create from raw LVGL area
     pub fn from_raw(raw: &neo_lvgl_sys::lv_area_t) -> Self {
         Self {
@@ -392,7 +828,8 @@ impl<D: DisplayDriver> ManagedDisplay<D> {
         let display = Display::new(width, height)?;
 
         // Calculate buffer size
-        let buf_size = (width as usize) * (height as usize) * color_format.bytes_per_pixel();
+        let buf_size = color_format.stride(width as u32) * (height as usize)
+            + color_format.palette_size();
 
         // Allocate buffers
         let mut buf1 = alloc::vec![0u8; buf_size];
@@ -417,13 +854,7 @@ impl<D: DisplayDriver> ManagedDisplay<D> {
             .map(|b| b.as_mut_ptr())
             .unwrap_or(core::ptr::null_mut());
         unsafe {
-            neo_lvgl_sys::lv_display_set_buffers(
-                display.raw(),
-                buf1.as_mut_ptr() as *mut _,
-                buf2_ptr as *mut _,
-                buf_size as u32,
-                render_mode.to_raw(),
-            );
+            display.set_buffers_raw(buf1.as_mut_ptr(), buf2_ptr, buf_size, render_mode);
         }
 
         // Set color format
@@ -529,6 +960,24 @@ impl<D: DisplayDriver> ManagedDisplay<D> {
         self.display.active_screen()
     }
 
+    /// Get a handle for signalling flush completion from an interrupt.
+    ///
+    /// Required when the driver's [`DisplayDriver::flush_is_async`] returns `true`.
+    pub fn handle(&self) -> DisplayHandle {
+        self.display.handle()
+    }
+
+    /// Let LVGL wait for asynchronous flushes with [`DisplayDriver::flush_wait`].
+    ///
+    /// Instead of busy-waiting for [`DisplayHandle::flush_ready`], LVGL then
+    /// calls the driver's `flush_wait` whenever it needs a buffer that is
+    /// still being sent. Only useful with
+    /// [`flush_is_async`](DisplayDriver::flush_is_async) drivers; see
+    /// [`Display::set_flush_wait_cb`].
+    pub fn enable_flush_wait(&self) {
+        self.display.set_flush_wait_cb(Some(Self::flush_wait_trampoline));
+    }
+
     /// Get display width.
     pub fn width(&self) -> i32 {
         self.display.width()
@@ -565,17 +1014,288 @@ impl<D: DisplayDriver> ManagedDisplay<D> {
         let area_ref = &*area;
         let rust_area = Area::from_raw(area_ref);
 
-        // Calculate buffer size based on area and color format
-        let color_format = ColorFormat::from_raw(neo_lvgl_sys::lv_display_get_color_format(disp));
-        let pixel_count = rust_area.pixel_count();
-        let buf_size = pixel_count * color_format.bytes_per_pixel();
+        // In Partial mode LVGL reshapes the active buffer to the area, in
+        // Full and Direct mode it stays screen sized and `px_map` is its
+        // start; either way its header describes what was rendered
+        let buf = neo_lvgl_sys::lv_display_get_buf_active(disp);
+        let buf_size = if !buf.is_null() && (*buf).data == px_map {
+            let header = &(*buf).header;
+            (header.stride as usize * header.h as usize).min((*buf).data_size as usize)
+        } else {
+            let color_format =
+                ColorFormat::from_raw(neo_lvgl_sys::lv_display_get_color_format(disp));
+            color_format.stride(rust_area.width() as u32) * (rust_area.height() as usize)
+        };
 
         let pixels = core::slice::from_raw_parts(px_map, buf_size);
 
         // Call the Rust driver
         driver.flush(&rust_area, pixels);
 
-        // Signal flush complete
-        neo_lvgl_sys::lv_display_flush_ready(disp);
+        // Signal flush complete, unless the driver does it from its DMA interrupt
+        if !driver.flush_is_async() {
+            neo_lvgl_sys::lv_display_flush_ready(disp);
+        }
+    }
+
+    /// The C trampoline that lets the driver wait for a flush
+    unsafe extern "C" fn flush_wait_trampoline(disp: *mut neo_lvgl_sys::lv_display_t) {
+        let driver_ptr = neo_lvgl_sys::lv_display_get_user_data(disp) as *mut D;
+        if !driver_ptr.is_null() {
+            (*driver_ptr).flush_wait();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_FORMATS: [ColorFormat; 14] = [
+        ColorFormat::L8,
+        ColorFormat::A1,
+        ColorFormat::A2,
+        ColorFormat::A4,
+        ColorFormat::A8,
+        ColorFormat::I1,
+        ColorFormat::I2,
+        ColorFormat::I4,
+        ColorFormat::I8,
+        ColorFormat::Rgb565,
+        ColorFormat::Rgb565Swapped,
+        ColorFormat::Rgb888,
+        ColorFormat::Argb8888,
+        ColorFormat::Xrgb8888,
+    ];
+
+    #[test]
+    fn test_bits_per_pixel() {
+        let expected = [8, 1, 2, 4, 8, 1, 2, 4, 8, 16, 16, 24, 32, 32];
+        for (format, bits) in ALL_FORMATS.iter().zip(expected) {
+            assert_eq!(format.bits_per_pixel(), bits, "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn test_set_buffers_checked() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(10, 4).unwrap();
+        let before = display.color_format();
+        let check = |len, buf2: Option<usize>, mode| {
+            let buf2 = buf2.map(crate::test_buffer);
+            display.set_buffers_checked(crate::test_buffer(len), buf2, mode, ColorFormat::Rgb565)
+        };
+
+        // A line of 10 RGB565 pixels is 20 bytes, the screen 80
+        assert_eq!(check(21, None, RenderMode::Partial), Err(BufferError::NotWholePixels));
+        assert_eq!(check(18, None, RenderMode::Partial), Err(BufferError::SmallerThanLine));
+        assert_eq!(check(40, None, RenderMode::Full), Err(BufferError::SmallerThanScreen));
+        assert_eq!(check(40, Some(20), RenderMode::Partial), Err(BufferError::SizeMismatch));
+        assert_eq!(display.color_format(), before);
+
+        assert_eq!(check(80, None, RenderMode::Full), Ok(()));
+        assert_eq!(display.color_format(), ColorFormat::Rgb565);
+    }
+
+    #[test]
+    fn test_set_color_format_checked() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(10, 4).unwrap();
+        assert_eq!(display.set_color_format_checked(ColorFormat::Rgb565), Ok(()));
+
+        // Full screen of RGB565, too small for ARGB8888
+        unsafe { display.set_buffers(crate::test_buffer(80), None, RenderMode::Full) };
+        assert_eq!(
+            display.set_color_format_checked(ColorFormat::Argb8888),
+            Err(BufferError::SmallerThanScreen)
+        );
+        assert_eq!(display.color_format(), ColorFormat::Rgb565);
+        assert_eq!(display.set_color_format_checked(ColorFormat::L8), Ok(()));
+        assert_eq!(display.color_format(), ColorFormat::L8);
+    }
+
+    #[test]
+    fn test_set_color_format_checked_raw_buffers() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(10, 4).unwrap();
+        display.set_color_format(ColorFormat::Rgb565);
+        let buf = crate::test_buffer(80);
+        unsafe {
+            neo_lvgl_sys::lv_display_set_buffers(
+                display.raw(),
+                buf.as_mut_ptr().cast(),
+                core::ptr::null_mut(),
+                buf.len() as u32,
+                RenderMode::Full.to_raw(),
+            );
+        }
+
+        // LVGL has the buffer, but not how it is used
+        assert_eq!(
+            display.set_color_format_checked(ColorFormat::Argb8888),
+            Err(BufferError::UnknownRenderMode)
+        );
+        assert_eq!(display.color_format(), ColorFormat::Rgb565);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_set_color_format_checked_managed() {
+        struct NullDriver;
+
+        impl DisplayDriver for NullDriver {
+            fn size(&self) -> (i32, i32) {
+                (10, 4)
+            }
+
+            fn flush(&mut self, _area: &Area, _pixels: &[u8]) {}
+        }
+
+        let _lvgl = crate::test_lvgl();
+
+        let managed =
+            ManagedDisplay::with_buffers(NullDriver, ColorFormat::Rgb565, RenderMode::Full, false)
+                .unwrap();
+        let display = managed.display();
+        assert_eq!(
+            display.set_color_format_checked(ColorFormat::Argb8888),
+            Err(BufferError::SmallerThanScreen)
+        );
+        assert_eq!(display.color_format(), ColorFormat::Rgb565);
+        assert_eq!(display.set_color_format_checked(ColorFormat::L8), Ok(()));
+    }
+
+    #[test]
+    fn test_stride() {
+        let _lvgl = crate::test_lvgl();
+
+        // 10 pixels wide; matches the default LV_DRAW_BUF_STRIDE_ALIGN of 1
+        let expected = [10, 2, 3, 5, 10, 2, 3, 5, 10, 20, 20, 30, 40, 40];
+        for (format, stride) in ALL_FORMATS.iter().zip(expected) {
+            assert_eq!(format.stride(10), stride, "{:?}", format);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_resolution_changed_on_rotation() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let seen = Rc::new(Cell::new(None));
+        let seen_cb = seen.clone();
+        display.on_resolution_changed(move |w, h| seen_cb.set(Some((w, h))));
+
+        display.set_rotation(DisplayRotation::Deg90);
+        assert_eq!(seen.get(), Some((240, 320)));
+        assert_eq!(display.rotation(), DisplayRotation::Deg90);
+
+        display.set_rotation(DisplayRotation::Deg0);
+        assert_eq!(seen.get(), Some((320, 240)));
+
+        // Deleting the display frees the closure
+        drop(display);
+        assert_eq!(Rc::strong_count(&seen), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_flush_wait_completes_async_flush() {
+        use crate::widgets::Widget;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        struct DmaDriver {
+            flushes: Rc<Cell<u32>>,
+            waits: Rc<Cell<u32>>,
+        }
+
+        impl DisplayDriver for DmaDriver {
+            fn size(&self) -> (i32, i32) {
+                (32, 32)
+            }
+
+            fn flush(&mut self, _area: &Area, _pixels: &[u8]) {
+                self.flushes.set(self.flushes.get() + 1);
+            }
+
+            fn flush_is_async(&self) -> bool {
+                true
+            }
+
+            fn flush_wait(&mut self) {
+                self.waits.set(self.waits.get() + 1);
+            }
+        }
+
+        let _lvgl = crate::test_lvgl();
+
+        let flushes = Rc::new(Cell::new(0));
+        let waits = Rc::new(Cell::new(0));
+        let driver = DmaDriver {
+            flushes: flushes.clone(),
+            waits: waits.clone(),
+        };
+        let display =
+            ManagedDisplay::with_buffers(driver, ColorFormat::Argb8888, RenderMode::Full, false)
+                .unwrap();
+        display.enable_flush_wait();
+
+        // flush_ready is never called; without the wait callback LVGL
+        // would spin forever on the unfinished flush
+        display.display().refresh_now();
+        display.active_screen().invalidate();
+        display.display().refresh_now();
+        assert!(flushes.get() >= 2);
+        assert!(waits.get() >= 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_direct_mode_flushes_whole_buffer() {
+        use crate::widgets::{Container, Widget};
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        struct LenDriver {
+            flushed: Rc<Cell<Option<(i16, usize)>>>,
+        }
+
+        impl DisplayDriver for LenDriver {
+            fn size(&self) -> (i32, i32) {
+                (32, 32)
+            }
+
+            fn flush(&mut self, area: &Area, pixels: &[u8]) {
+                self.flushed.set(Some((area.width(), pixels.len())));
+            }
+        }
+
+        let _lvgl = crate::test_lvgl();
+
+        let flushed = Rc::new(Cell::new(None));
+        let driver = LenDriver {
+            flushed: flushed.clone(),
+        };
+        let display =
+            ManagedDisplay::with_buffers(driver, ColorFormat::Rgb565, RenderMode::Direct, false)
+                .unwrap();
+        let screen = display.active_screen();
+        display.display().refresh_now();
+
+        let panel = Container::new(&screen).unwrap();
+        panel.set_size(4, 4);
+        display.display().refresh_now();
+
+        // Only the panel changed, but the slice covers the whole screen
+        let (width, len) = flushed.get().unwrap();
+        assert!(width < 32);
+        assert_eq!(len, ColorFormat::Rgb565.stride(32) * 32);
     }
 }
@@ -0,0 +1,146 @@
+//! Draw buffers
+//!
+//! A [`DrawBuf`] is a pixel buffer allocated by LVGL with the alignment and
+//! stride its renderer expects. Use it as the backing store of a
+//! [`Canvas`](crate::widgets::Canvas) or as the source of an
+//! [`Image`](crate::widgets::Image) instead of sizing raw byte slices by hand.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use lvgl::draw_buf::DrawBuf;
+//! use lvgl::display::ColorFormat;
+//!
+//! let buf = DrawBuf::new(100, 100, ColorFormat::Rgb565).unwrap();
+//! let canvas = Canvas::new(&screen).unwrap();
+//! canvas.set_draw_buf(&buf);
+//! canvas.fill_bg(Color::white(), Opacity::COVER);
+//! ```
+
+use crate::display::ColorFormat;
+use core::ptr::NonNull;
+
+/// An LVGL-allocated, stride-aligned pixel buffer
+///
+/// The buffer is freed when dropped. Widgets that draw from it borrow it for
+/// their own lifetime, so it can't be dropped while they use it.
+pub struct DrawBuf {
+    raw: NonNull<neo_lvgl_sys::lv_draw_buf_t>,
+    format: ColorFormat,
+}
+
+impl DrawBuf {
+    /// Allocate a buffer of `width` x `height` pixels
+    ///
+    /// The stride is chosen by LVGL (see [`ColorFormat::stride`]). For
+    /// indexed formats the palette is stored in front of the pixel data.
+    /// Returns `None` if LVGL is out of memory or a dimension is zero.
+    pub fn new(width: u32, height: u32, format: ColorFormat) -> Option<Self> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let ptr = unsafe { neo_lvgl_sys::lv_draw_buf_create(width, height, format.to_raw(), 0) };
+        NonNull::new(ptr).map(|raw| Self { raw, format })
+    }
+
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(width: u32, height: u32, format: ColorFormat) -> Result<Self, crate::LvError> {
+        Self::new(width, height, format).ok_or(crate::LvError::OutOfMemory)
+    }
+
+    /// Get the width in pixels
+    pub fn width(&self) -> u32 {
+        unsafe { self.raw.as_ref().header.w() }
+    }
+
+    /// Get the height in pixels
+    pub fn height(&self) -> u32 {
+        unsafe { self.raw.as_ref().header.h() }
+    }
+
+    /// Get the color format
+    pub fn color_format(&self) -> ColorFormat {
+        self.format
+    }
+
+    /// Get the number of bytes per row, including padding
+    pub fn stride(&self) -> usize {
+        unsafe { self.raw.as_ref().header.stride() as usize }
+    }
+
+    /// Get the whole buffer (palette, if any, followed by the rows)
+    ///
+    /// # Safety
+    ///
+    /// A [`Canvas`](crate::widgets::Canvas) using this buffer keeps only a
+    /// shared borrow of it and LVGL draws into it behind that borrow. Don't
+    /// draw on such a canvas while the returned slice is alive.
+    /// [`data_mut`](Self::data_mut) needs no such care.
+    pub unsafe fn data(&self) -> &[u8] {
+        unsafe {
+            let raw = self.raw.as_ref();
+            core::slice::from_raw_parts(raw.data, raw.data_size as usize)
+        }
+    }
+
+    /// Get the whole buffer mutably
+    ///
+    /// No widget can be using the buffer while it is borrowed mutably.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            let raw = self.raw.as_mut();
+            core::slice::from_raw_parts_mut(raw.data, raw.data_size as usize)
+        }
+    }
+
+    /// Get the bytes of row `y`, without the stride padding
+    ///
+    /// Returns `None` if `y` is out of range.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`data`](Self::data): LVGL must not draw into the buffer
+    /// while the returned slice is alive.
+    pub unsafe fn row(&self, y: u32) -> Option<&[u8]> {
+        let range = self.row_range(y)?;
+        Some(&self.data()[range])
+    }
+
+    /// Get the bytes of row `y` mutably, without the stride padding
+    ///
+    /// Returns `None` if `y` is out of range.
+    pub fn row_mut(&mut self, y: u32) -> Option<&mut [u8]> {
+        let range = self.row_range(y)?;
+        Some(&mut self.data_mut()[range])
+    }
+
+    /// Set every byte of the pixel data to zero
+    pub fn clear(&mut self) {
+        unsafe {
+            neo_lvgl_sys::lv_draw_buf_clear(self.raw.as_ptr(), core::ptr::null());
+        }
+    }
+
+    /// Get the raw draw buffer pointer
+    #[inline]
+    pub fn raw(&self) -> *mut neo_lvgl_sys::lv_draw_buf_t {
+        self.raw.as_ptr()
+    }
+
+    fn row_range(&self, y: u32) -> Option<core::ops::Range<usize>> {
+        if y >= self.height() {
+            return None;
+        }
+        let row_bytes = (self.width() as usize * self.format.bits_per_pixel() as usize).div_ceil(8);
+        let start = self.format.palette_size() + y as usize * self.stride();
+        Some(start..start + row_bytes)
+    }
+}
+
+impl Drop for DrawBuf {
+    fn drop(&mut self) {
+        unsafe {
+            neo_lvgl_sys::lv_draw_buf_destroy(self.raw.as_ptr());
+        }
+    }
+}
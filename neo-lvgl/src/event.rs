@@ -7,6 +7,8 @@
 //! - Static function callbacks (always available)
 //! - Closure callbacks (requires `alloc` feature)
 
+use crate::indev::GestureDir;
+use crate::widgets::Obj;
 use core::ffi::c_void;
 
 #[cfg(feature = "alloc")]
@@ -58,10 +60,25 @@ pub enum EventCode {
     ScreenUnloadStart = neo_lvgl_sys::lv_event_code_t_LV_EVENT_SCREEN_UNLOAD_START,
     /// Screen unloaded
     ScreenUnloaded = neo_lvgl_sys::lv_event_code_t_LV_EVENT_SCREEN_UNLOADED,
+    /// Object scrolled (sent on every scroll position change)
+    Scroll = neo_lvgl_sys::lv_event_code_t_LV_EVENT_SCROLL,
     /// Object size changed
     SizeChanged = neo_lvgl_sys::lv_event_code_t_LV_EVENT_SIZE_CHANGED,
     /// Object style changed
     StyleChanged = neo_lvgl_sys::lv_event_code_t_LV_EVENT_STYLE_CHANGED,
+    /// A swipe gesture was detected (see `Event::gesture_dir`)
+    Gesture = neo_lvgl_sys::lv_event_code_t_LV_EVENT_GESTURE,
+    /// Text is about to be inserted (sent by TextArea, param is the text)
+    Insert = neo_lvgl_sys::lv_event_code_t_LV_EVENT_INSERT,
+    /// Display resolution changed (sent to the display, e.g. on rotation)
+    ResolutionChanged = neo_lvgl_sys::lv_event_code_t_LV_EVENT_RESOLUTION_CHANGED,
+    /// LVGL asks how far the widget draws outside its area
+    ///
+    /// Sent whenever the extra draw size has to be recalculated, e.g. after a
+    /// style change or an explicit `Widget::refresh_ext_draw_size`. Each
+    /// handler reports its need with `Event::set_ext_draw_size`; the largest
+    /// value wins.
+    RefreshExtDrawSize = neo_lvgl_sys::lv_event_code_t_LV_EVENT_REFR_EXT_DRAW_SIZE,
     /// All events (for filtering)
     All = neo_lvgl_sys::lv_event_code_t_LV_EVENT_ALL,
 }
@@ -91,8 +108,15 @@ impl EventCode {
             neo_lvgl_sys::lv_event_code_t_LV_EVENT_SCREEN_LOADED => Some(Self::ScreenLoaded),
             neo_lvgl_sys::lv_event_code_t_LV_EVENT_SCREEN_UNLOAD_START => Some(Self::ScreenUnloadStart),
             neo_lvgl_sys::lv_event_code_t_LV_EVENT_SCREEN_UNLOADED => Some(Self::ScreenUnloaded),
+            neo_lvgl_sys::lv_event_code_t_LV_EVENT_SCROLL => Some(Self::Scroll),
             neo_lvgl_sys::lv_event_code_t_LV_EVENT_SIZE_CHANGED => Some(Self::SizeChanged),
             neo_lvgl_sys::lv_event_code_t_LV_EVENT_STYLE_CHANGED => Some(Self::StyleChanged),
+            neo_lvgl_sys::lv_event_code_t_LV_EVENT_GESTURE => Some(Self::Gesture),
+            neo_lvgl_sys::lv_event_code_t_LV_EVENT_INSERT => Some(Self::Insert),
+            neo_lvgl_sys::lv_event_code_t_LV_EVENT_RESOLUTION_CHANGED => Some(Self::ResolutionChanged),
+            neo_lvgl_sys::lv_event_code_t_LV_EVENT_REFR_EXT_DRAW_SIZE => {
+                Some(Self::RefreshExtDrawSize)
+            }
             neo_lvgl_sys::lv_event_code_t_LV_EVENT_ALL => Some(Self::All),
             _ => None,
         }
@@ -144,6 +168,95 @@ impl Event {
         unsafe { neo_lvgl_sys::lv_event_get_current_target_obj(self.raw) }
     }
 
+    /// Get the current value of the object that triggered this event.
+    ///
+    /// This lets a generic `ValueChanged` handler read the new value without
+    /// capturing the widget:
+    ///
+    /// - `Bar`, `Slider`, `Arc` - the current value
+    /// - `Roller`, `Dropdown` - the index of the selected option
+    ///
+    /// Returns `None` if the target is none of these widget types.
+    pub fn target_value(&self) -> Option<i32> {
+        use core::ptr::addr_of;
+
+        let target = self.target_raw();
+        if target.is_null() {
+            return None;
+        }
+
+        unsafe {
+            let is = |class: *const neo_lvgl_sys::lv_obj_class_t| {
+                neo_lvgl_sys::lv_obj_check_type(target, class)
+            };
+
+            if is(addr_of!(neo_lvgl_sys::lv_slider_class)) {
+                Some(neo_lvgl_sys::lv_slider_get_value(target))
+            } else if is(addr_of!(neo_lvgl_sys::lv_bar_class)) {
+                Some(neo_lvgl_sys::lv_bar_get_value(target))
+            } else if is(addr_of!(neo_lvgl_sys::lv_arc_class)) {
+                Some(neo_lvgl_sys::lv_arc_get_value(target))
+            } else if is(addr_of!(neo_lvgl_sys::lv_roller_class)) {
+                Some(neo_lvgl_sys::lv_roller_get_selected(target) as i32)
+            } else if is(addr_of!(neo_lvgl_sys::lv_dropdown_class)) {
+                Some(neo_lvgl_sys::lv_dropdown_get_selected(target) as i32)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Get the direction of the gesture that triggered this event.
+    ///
+    /// Only meaningful for `EventCode::Gesture`; returns `GestureDir::None`
+    /// if there is no active input device.
+    pub fn gesture_dir(&self) -> GestureDir {
+        unsafe {
+            let indev = neo_lvgl_sys::lv_indev_active();
+            if indev.is_null() {
+                return GestureDir::None;
+            }
+            GestureDir::from_raw(neo_lvgl_sys::lv_indev_get_gesture_dir(indev))
+        }
+    }
+
+    /// Get the event parameter pointer
+    ///
+    /// Its meaning depends on the event code, e.g. the inserted text for
+    /// `EventCode::Insert`.
+    pub fn param_raw(&self) -> *mut c_void {
+        unsafe { neo_lvgl_sys::lv_event_get_param(self.raw) }
+    }
+
+    /// Reserve `size` pixels of drawing area around the widget
+    ///
+    /// Only meaningful for `EventCode::RefreshExtDrawSize`. LVGL keeps the
+    /// largest size requested by any handler (and by the widget's own
+    /// shadow, outline, etc.), so this never shrinks the area.
+    pub fn set_ext_draw_size(&self, size: i32) {
+        unsafe {
+            neo_lvgl_sys::lv_event_set_ext_draw_size(self.raw, size);
+        }
+    }
+
+    /// Get the object that originally triggered the event
+    ///
+    /// For bubbled events this is the child, not the object the handler is
+    /// registered on.
+    pub fn target(&self) -> Option<Obj<'static>> {
+        unsafe { Obj::from_raw(self.target_raw()) }
+    }
+
+    /// Get the object whose handler is currently running
+    pub fn current_target(&self) -> Option<Obj<'static>> {
+        unsafe { Obj::from_raw(self.current_target_raw()) }
+    }
+
+    /// Check if this event bubbled up from a child
+    pub fn is_bubbled(&self) -> bool {
+        self.target_raw() != self.current_target_raw()
+    }
+
     /// Get user data pointer
     ///
     /// # Safety
@@ -255,6 +368,25 @@ mod closure_support {
         }
     }
 
+    /// Trampoline for one-shot FnOnce closures
+    ///
+    /// Removes its own event descriptor before running the closure, so the
+    /// closure is called at most once and its box is freed afterwards.
+    unsafe extern "C" fn closure_trampoline_once(e: *mut neo_lvgl_sys::lv_event_t) {
+        let user_data = neo_lvgl_sys::lv_event_get_user_data(e);
+        if !user_data.is_null() {
+            let obj = neo_lvgl_sys::lv_event_get_current_target_obj(e);
+            neo_lvgl_sys::lv_obj_remove_event_cb_with_user_data(
+                obj,
+                Some(closure_trampoline_once),
+                user_data,
+            );
+            let closure = Box::from_raw(user_data as *mut Box<dyn FnOnce(&Event)>);
+            let event = Event::from_raw(e);
+            closure(&event);
+        }
+    }
+
     /// Extension trait for closure-based event handlers
     pub trait ClosureEventHandler: EventHandler {
         /// Add a click handler using a closure.
@@ -313,6 +445,92 @@ mod closure_support {
             self.on_event_closure(EventCode::Defocused, handler);
         }
 
+        /// Add a swipe gesture handler using a closure.
+        ///
+        /// Gestures bubble up from children that have `Flag::GESTURE_BUBBLE`
+        /// set (the default), so a handler on a screen sees swipes made
+        /// anywhere on it.
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// let manager = Rc::new(manager);
+        /// let m = manager.clone();
+        /// screen.on_gesture(move |dir| {
+        ///     if dir == GestureDir::Left {
+        ///         m.pop();
+        ///     }
+        /// });
+        /// ```
+        fn on_gesture<F>(&self, handler: F)
+        where
+            F: Fn(GestureDir) + 'static,
+        {
+            self.on_event_closure(EventCode::Gesture, move |e| handler(e.gesture_dir()));
+        }
+
+        /// Call `handler` with the widget's new coordinates whenever its size changes
+        ///
+        /// Sizes are applied during layout, so the handler runs on the next
+        /// refresh (or an explicit `update_layout()`) after the size was set.
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// panel.on_size_changed(move |area| {
+        ///     label.set_width(area.width() as i32 - 20);
+        /// });
+        /// ```
+        fn on_size_changed<F>(&self, handler: F)
+        where
+            F: Fn(crate::display::Area) + 'static,
+        {
+            let obj = self.obj_raw();
+            self.on_event_closure(EventCode::SizeChanged, move |_| {
+                let mut coords = core::mem::MaybeUninit::<neo_lvgl_sys::lv_area_t>::zeroed();
+                let coords = unsafe {
+                    neo_lvgl_sys::lv_obj_get_coords(obj, coords.as_mut_ptr());
+                    coords.assume_init()
+                };
+                handler(crate::display::Area::from_raw(&coords));
+            });
+        }
+
+        /// Handle `event` for all children of this widget in one place.
+        ///
+        /// `handler` receives the event and the child that triggered it. It
+        /// only runs for events that bubbled up from a child, which requires
+        /// the child to have event bubbling enabled (see
+        /// `Widget::enable_event_bubbling`). Events of the widget itself are
+        /// ignored.
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// let list = Container::new(&screen).unwrap().column();
+        /// for _ in 0..10 {
+        ///     let item = Button::new(&list).unwrap();
+        ///     item.enable_event_bubbling(true);
+        /// }
+        ///
+        /// // One handler for all items instead of one per item
+        /// list.on_child_event(EventCode::Clicked, |_e, child| {
+        ///     let index = child.index();
+        /// });
+        /// ```
+        fn on_child_event<F>(&self, event: EventCode, handler: F)
+        where
+            F: Fn(&Event, Obj<'static>) + 'static,
+        {
+            self.on_event_closure(event, move |e| {
+                if e.is_bubbled() {
+                    if let Some(child) = e.target() {
+                        handler(e, child);
+                    }
+                }
+            });
+        }
+
         /// Add an event handler using a closure.
         ///
         /// This allocates the closure on the heap.
@@ -350,6 +568,26 @@ mod closure_support {
                 );
             }
         }
+
+        /// Add an event handler that runs only the first time `event` fires.
+        ///
+        /// The handler is unregistered before it runs and freed once it returns.
+        fn on_event_once<F>(&self, event: EventCode, handler: F)
+        where
+            F: FnOnce(&Event) + 'static,
+        {
+            let boxed: Box<Box<dyn FnOnce(&Event)>> = Box::new(Box::new(handler));
+            let raw = Box::into_raw(boxed);
+
+            unsafe {
+                neo_lvgl_sys::lv_obj_add_event_cb(
+                    self.obj_raw(),
+                    Some(closure_trampoline_once),
+                    event.to_raw(),
+                    raw as *mut c_void,
+                );
+            }
+        }
     }
 
     // Implement ClosureEventHandler for all types that implement EventHandler
@@ -358,3 +596,46 @@ mod closure_support {
 
 #[cfg(feature = "alloc")]
 pub use closure_support::ClosureEventHandler;
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::layout::LayoutExt;
+    use crate::widgets::{Container, Widget};
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_on_size_changed_reports_new_size() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        panel.set_size(50, 50);
+        panel.update_layout();
+
+        let seen = Rc::new(Cell::new(None));
+        let s = seen.clone();
+        panel.on_size_changed(move |area| s.set(Some((area.width(), area.height()))));
+
+        panel.set_size(120, 80);
+        panel.update_layout();
+        assert_eq!(seen.get(), Some((120, 80)));
+    }
+
+    #[test]
+    fn test_ext_draw_size_from_handler() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        assert_eq!(panel.ext_draw_size(), 0);
+
+        panel.on_event_closure(EventCode::RefreshExtDrawSize, |e| e.set_ext_draw_size(12));
+        panel.refresh_ext_draw_size();
+        assert_eq!(panel.ext_draw_size(), 12);
+    }
+}
@@ -58,12 +58,68 @@ impl Font {
         unsafe { neo_lvgl_sys::lv_font_get_line_height(self.raw) }
     }
 
+    /// Get the advance width of `letter` in pixels
+    ///
+    /// With `next`, the kerning between the two letters is included, so
+    /// summing the widths of a string letter by letter (each with its
+    /// successor) gives the same result as LVGL's text layout, apart from
+    /// letter spacing. Returns 0 for letters the font doesn't contain.
+    pub fn glyph_width(&self, letter: char, next: Option<char>) -> u16 {
+        let next = next.map_or(0, u32::from);
+        unsafe { neo_lvgl_sys::lv_font_get_glyph_width(self.raw, letter as u32, next) }
+    }
+
+    /// Get the kerning between `left` and `right` in pixels
+    ///
+    /// Usually negative, e.g. for "AV". LVGL stores kerning in 1/16 pixels
+    /// and only rounds the final advance, so this difference of two rounded
+    /// widths can be off by one pixel from the stored value. Use
+    /// [`glyph_width`](Self::glyph_width) with `next` for exact layout.
+    /// Returns 0 if the font has no kerning data or kerning is disabled.
+    pub fn kern_value(&self, left: char, right: char) -> i32 {
+        self.glyph_width(left, Some(right)) as i32 - self.glyph_width(left, None) as i32
+    }
+
+    /// Check whether kerning is applied when rendering text with this font
+    pub fn is_kerning_enabled(&self) -> bool {
+        let normal = neo_lvgl_sys::lv_font_kerning_t_LV_FONT_KERNING_NORMAL as u8;
+        unsafe { (*self.raw).kerning() == normal }
+    }
+
+    /// Get the subpixel rendering mode the font was converted with
+    pub fn subpixel(&self) -> FontSubpx {
+        match unsafe { (*self.raw).subpx() } as neo_lvgl_sys::lv_font_subpx_t {
+            neo_lvgl_sys::lv_font_subpx_t_LV_FONT_SUBPX_HOR => FontSubpx::Horizontal,
+            neo_lvgl_sys::lv_font_subpx_t_LV_FONT_SUBPX_VER => FontSubpx::Vertical,
+            neo_lvgl_sys::lv_font_subpx_t_LV_FONT_SUBPX_BOTH => FontSubpx::Both,
+            _ => FontSubpx::None,
+        }
+    }
+
     /// Get the default font (as configured in lv_conf.h)
     pub fn default() -> Self {
         unsafe { Self::from_raw(neo_lvgl_sys::lv_font_get_default()) }
     }
 }
 
+/// Subpixel rendering mode of a font
+///
+/// Subpixel fonts triple the horizontal (or vertical) resolution using the
+/// display's RGB stripes. LVGL only renders them that way if
+/// `LV_USE_FONT_SUBPX` is enabled in `lv_conf.h`; otherwise they are drawn
+/// like normal fonts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontSubpx {
+    /// Normal rendering
+    None,
+    /// Horizontal subpixels
+    Horizontal,
+    /// Vertical subpixels
+    Vertical,
+    /// Both directions
+    Both,
+}
+
 /// Error type for font operations
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FontError {
@@ -16,6 +16,18 @@
 //! // Attach to keypad input device
 //! indev.set_group(&group);
 //! ```
+//!
+//! # Order of operations
+//!
+//! Keypad and encoder devices only send keys to the group they are attached
+//! to, and widgets only join the default group if it is set *before* they
+//! are created. The usual setup is:
+//!
+//! 1. Create the display and the keypad/encoder [`Indev`](crate::indev::Indev).
+//! 2. Create a group and call [`Group::make_default_and_attach`] with the indev
+//!    (or [`Group::set_default`] followed by
+//!    [`attach_keypad_to_default_group`](crate::indev::attach_keypad_to_default_group)).
+//! 3. Create the widgets; focusable ones are added to the group automatically.
 
 use crate::indev::Key;
 use crate::widgets::{Obj, Widget};
@@ -55,6 +67,11 @@ impl Group {
         NonNull::new(ptr).map(|raw| Self { raw })
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new() -> Result<Self, crate::LvError> {
+        Self::new().ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Create from raw pointer
     ///
     /// # Safety
@@ -71,6 +88,15 @@ impl Group {
         }
     }
 
+    /// Make this the default group and route `indev` to it
+    ///
+    /// Widgets created afterwards join this group automatically, and key
+    /// presses from `indev` move focus within it.
+    pub fn make_default_and_attach(&self, indev: &crate::indev::Indev) {
+        self.set_default();
+        indev.set_group(self);
+    }
+
     /// Add a widget to the group
     pub fn add<'a, W: Widget<'a>>(&self, widget: &W) {
         unsafe {
@@ -113,6 +139,15 @@ impl Group {
         }
     }
 
+    /// Focus a widget in whichever group it belongs to
+    ///
+    /// Does nothing if the widget is not in a group.
+    pub fn focus_obj<'a, W: Widget<'a>>(widget: &W) {
+        unsafe {
+            neo_lvgl_sys::lv_group_focus_obj(widget.raw());
+        }
+    }
+
     /// Freeze/unfreeze focus (prevent focus changes)
     pub fn set_focus_frozen(&self, frozen: bool) {
         unsafe {
@@ -160,6 +195,36 @@ impl Group {
         unsafe { neo_lvgl_sys::lv_group_get_editing(self.raw.as_ptr()) }
     }
 
+    /// Enter editing mode whenever an editable widget gets focus
+    ///
+    /// With an encoder, a slider, arc or roller normally has to be clicked
+    /// before turning the knob changes its value. With auto-edit, turning
+    /// adjusts the value as soon as the widget is focused. The trade-off is
+    /// that the encoder then no longer moves past such a widget: the user
+    /// clicks to leave editing mode (or the application calls
+    /// [`set_editing(false)`](Self::set_editing)), and only then does
+    /// turning move focus on. This suits screens with one or two values to
+    /// adjust better than long forms.
+    ///
+    /// This installs the group's focus callback, replacing one set with
+    /// [`on_focus`](Self::on_focus). To combine the two, call
+    /// [`edit_if_editable`](Self::edit_if_editable) from the closure
+    /// instead. Pass `false` to remove the callback.
+    pub fn auto_edit_on_focus(&self, enable: bool) {
+        let cb: neo_lvgl_sys::lv_group_focus_cb_t = if enable { Some(auto_edit_cb) } else { None };
+        unsafe {
+            neo_lvgl_sys::lv_group_set_focus_cb(self.raw.as_ptr(), cb);
+        }
+    }
+
+    /// Enter editing mode if the focused widget is editable
+    ///
+    /// Editable widgets are those that take encoder input as values, such
+    /// as sliders, arcs, rollers and text areas. Does nothing otherwise.
+    pub fn edit_if_editable(&self) {
+        unsafe { auto_edit_cb(self.raw.as_ptr()) }
+    }
+
     /// Set the refocus policy
     pub fn set_refocus_policy(&self, policy: RefocusPolicy) {
         unsafe {
@@ -172,6 +237,27 @@ impl Group {
         unsafe { neo_lvgl_sys::lv_group_get_obj_count(self.raw.as_ptr()) }
     }
 
+    /// Get the member at `index`, in focus order
+    pub fn obj_at(&self, index: u32) -> Option<Obj<'static>> {
+        unsafe {
+            let ptr = neo_lvgl_sys::lv_group_get_obj_by_index(self.raw.as_ptr(), index);
+            Obj::from_raw(ptr)
+        }
+    }
+
+    /// Iterate over the members in focus order
+    ///
+    /// Adding or removing members while iterating skips or repeats entries.
+    pub fn objs_iter(&self) -> impl Iterator<Item = Obj<'static>> + '_ {
+        (0..self.obj_count()).filter_map(|i| self.obj_at(i))
+    }
+
+    /// Get the index of the focused member
+    pub fn focused_index(&self) -> Option<u32> {
+        let focused = self.focused()?;
+        self.objs_iter().position(|obj| obj.raw() == focused.raw()).map(|i| i as u32)
+    }
+
     /// Set user data
     pub fn set_user_data(&self, data: *mut c_void) {
         unsafe {
@@ -203,6 +289,13 @@ impl Default for Group {
     }
 }
 
+unsafe extern "C" fn auto_edit_cb(group: *mut neo_lvgl_sys::lv_group_t) {
+    let focused = neo_lvgl_sys::lv_group_get_focused(group);
+    if !focused.is_null() && neo_lvgl_sys::lv_obj_is_editable(focused) {
+        neo_lvgl_sys::lv_group_set_editing(group, true);
+    }
+}
+
 // Callback support
 #[cfg(feature = "alloc")]
 mod callback_support {
@@ -246,3 +339,46 @@ pub fn get_default_group() -> Option<Group> {
     let ptr = unsafe { neo_lvgl_sys::lv_group_get_default() };
     unsafe { Group::from_raw(ptr) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::widgets::{Button, Slider};
+
+    #[test]
+    fn test_auto_edit_on_focus() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let button = Button::new(&screen).unwrap();
+        let slider = Slider::new(&screen).unwrap();
+        let group = Group::new().unwrap();
+        group.add(&button);
+        group.add(&slider);
+
+        group.focus(&slider);
+        assert!(!group.is_editing());
+
+        group.auto_edit_on_focus(true);
+        group.focus(&button);
+        assert!(!group.is_editing());
+        group.focus(&slider);
+        assert!(group.is_editing());
+
+        // Leaving editing mode lets focus move on again
+        group.set_editing(false);
+        group.focus_next();
+        assert_eq!(group.focused().map(|obj| obj.raw()), Some(button.raw()));
+        assert!(!group.is_editing());
+
+        group.auto_edit_on_focus(false);
+        group.focus(&slider);
+        assert!(!group.is_editing());
+        group.edit_if_editable();
+        assert!(group.is_editing());
+
+        group.delete();
+    }
+}
@@ -0,0 +1,75 @@
+//! Image cache control
+//!
+//! LVGL 9 keeps decoded images in a cache managed by its cache manager so that
+//! redrawing an image doesn't decode it again. Two caches are involved:
+//!
+//! - The **image cache** holds decoded pixel data. Its capacity is in bytes and
+//!   defaults to `LV_CACHE_DEF_SIZE` from `lv_conf.h` (0 disables it).
+//! - The **header cache** holds only image headers (size, color format). Its
+//!   capacity is an entry count and defaults to `LV_IMAGE_HEADER_CACHE_DEF_CNT`.
+//!
+//! Images that are already in memory in a drawable format (e.g. an
+//! [`ImageDsc`](crate::widgets::ImageDsc) with uncompressed data) are drawn
+//! directly and never occupy the image cache; it matters for files and
+//! compressed or decoder-backed images.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Bound decoded images to 32 KiB on a small device
+//! lvgl::image::set_cache_size(32 * 1024);
+//!
+//! // Free everything under memory pressure
+//! lvgl::image::drop_cache();
+//! ```
+
+/// Set the image cache capacity in bytes
+///
+/// Entries that no longer fit are evicted immediately. A size of 0 disables
+/// the cache.
+pub fn set_cache_size(bytes: u32) {
+    unsafe {
+        neo_lvgl_sys::lv_image_cache_resize(bytes, true);
+    }
+}
+
+/// Check if the image cache is enabled (its capacity is non-zero)
+pub fn is_cache_enabled() -> bool {
+    unsafe { neo_lvgl_sys::lv_image_cache_is_enabled() }
+}
+
+/// Drop all decoded images from the cache
+///
+/// Images still on screen are decoded again the next time they are drawn.
+pub fn drop_cache() {
+    unsafe {
+        neo_lvgl_sys::lv_image_cache_drop(core::ptr::null());
+    }
+}
+
+/// Drop a single image from the cache
+///
+/// Call this after changing the pixel data behind `dsc` so the stale decoded
+/// copy isn't drawn.
+pub fn drop_cached(dsc: &crate::widgets::ImageDsc) {
+    unsafe {
+        neo_lvgl_sys::lv_image_cache_drop(dsc.raw().cast());
+    }
+}
+
+/// Set the header cache capacity as a number of entries
+///
+/// Entries that no longer fit are evicted immediately. A count of 0 disables
+/// the cache.
+pub fn set_header_cache_count(count: u32) {
+    unsafe {
+        neo_lvgl_sys::lv_image_header_cache_resize(count, true);
+    }
+}
+
+/// Drop all entries from the header cache
+pub fn drop_header_cache() {
+    unsafe {
+        neo_lvgl_sys::lv_image_header_cache_drop(core::ptr::null());
+    }
+}
@@ -22,8 +22,9 @@
 //! });
 //! ```
 
-use crate::widgets::Point;
+use crate::widgets::{Point, Widget};
 use core::ptr::NonNull;
+use core::sync::atomic::{AtomicU32, Ordering};
 
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
@@ -173,6 +174,236 @@ pub struct EncoderData {
     pub state: IndevState,
 }
 
+/// Snapshot of a pointer device, see [`Indev::pointer_state`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointerState {
+    /// Last pressed point
+    pub point: Point,
+    /// Movement since the previous read
+    pub vect: Point,
+    /// Whether the pointer is pressed
+    pub pressed: bool,
+    /// LVGL tick when the snapshot was taken (milliseconds)
+    pub timestamp: u32,
+}
+
+/// The last `N` pointer samples, for custom gesture recognition
+///
+/// LVGL only recognizes straight swipes. Record a [`PointerState`] after
+/// every [`task_handler`](crate::task_handler) call while the pointer is
+/// pressed and match the path against your own shapes (circles, zigzags,
+/// flicks with a minimum speed). The oldest sample is dropped once `N`
+/// samples are stored. No allocation is needed.
+///
+/// # Example
+///
+/// ```ignore
+/// static mut HISTORY: PointerHistory<16> = PointerHistory::new();
+///
+/// // In the main loop, after task_handler():
+/// let state = touch.pointer_state();
+/// let history = unsafe { &mut *core::ptr::addr_of_mut!(HISTORY) };
+/// if state.pressed {
+///     history.push(state);
+/// } else if let Some((vx, _)) = history.velocity() {
+///     if vx > 1000 {
+///         next_page();
+///     }
+///     history.clear();
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PointerHistory<const N: usize> {
+    samples: [PointerState; N],
+    /// Index of the oldest sample
+    start: usize,
+    len: usize,
+}
+
+impl<const N: usize> PointerHistory<N> {
+    /// Create an empty history
+    pub const fn new() -> Self {
+        const EMPTY: PointerState = PointerState {
+            point: Point::new(0, 0),
+            vect: Point::new(0, 0),
+            pressed: false,
+            timestamp: 0,
+        };
+        Self {
+            samples: [EMPTY; N],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Add a sample, dropping the oldest one if the history is full
+    ///
+    /// Samples with the same timestamp as the latest one are ignored, since
+    /// LVGL hadn't read the device again.
+    pub fn push(&mut self, state: PointerState) {
+        if N == 0 || self.latest().is_some_and(|last| last.timestamp == state.timestamp) {
+            return;
+        }
+        if self.len < N {
+            self.samples[(self.start + self.len) % N] = state;
+            self.len += 1;
+        } else {
+            self.samples[self.start] = state;
+            self.start = (self.start + 1) % N;
+        }
+    }
+
+    /// Remove all samples
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// Get the number of stored samples
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether no samples are stored
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterate over the samples from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &PointerState> + '_ {
+        (0..self.len).map(move |i| &self.samples[(self.start + i) % N])
+    }
+
+    /// Get the newest sample
+    pub fn latest(&self) -> Option<&PointerState> {
+        self.len.checked_sub(1).map(|i| &self.samples[(self.start + i) % N])
+    }
+
+    /// Get the average velocity over the stored samples in pixels per second
+    ///
+    /// Returns `None` with fewer than two samples.
+    pub fn velocity(&self) -> Option<(i32, i32)> {
+        let first = self.iter().next()?;
+        let last = self.latest()?;
+        let dt = last.timestamp.wrapping_sub(first.timestamp) as i64;
+        if self.len < 2 || dt == 0 {
+            return None;
+        }
+        let v = |from: i32, to: i32| ((to - from) as i64 * 1000 / dt) as i32;
+        Some((v(first.point.x, last.point.x), v(first.point.y, last.point.y)))
+    }
+
+    /// Get the length of the path through all samples in pixels
+    pub fn path_length(&self) -> u32 {
+        let mut prev: Option<Point> = None;
+        let mut total = 0u64;
+        for sample in self.iter() {
+            if let Some(prev) = prev {
+                let dx = (sample.point.x - prev.x).unsigned_abs() as u64;
+                let dy = (sample.point.y - prev.y).unsigned_abs() as u64;
+                total += (dx * dx + dy * dy).isqrt();
+            }
+            prev = Some(sample.point);
+        }
+        total.min(u32::MAX as u64) as u32
+    }
+}
+
+impl<const N: usize> Default for PointerHistory<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Encoder acceleration
+///
+/// Turns raw encoder steps into larger steps while the knob is turned
+/// quickly, so long ranges can be crossed without dozens of detents while
+/// slow turns keep single-step precision.
+///
+/// Tuning parameters:
+///
+/// - `fast_ms`: if the next rotation arrives within this many milliseconds
+///   of the previous one, the turn counts as fast and the multiplier
+///   doubles. A longer pause resets it to 1. Default: 60 ms.
+/// - `max_multiplier`: upper bound for the multiplier. Default: 16.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut accel = EncoderAccel::new();
+/// // In the encoder read loop, with the spinbox focused:
+/// let data = read_encoder();
+/// if data.diff != 0 {
+///     spinbox.apply_encoder(accel.accelerate(data.diff));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct EncoderAccel {
+    /// Max milliseconds between rotations to count as fast
+    pub fast_ms: u32,
+    /// Upper bound for the step multiplier
+    pub max_multiplier: u16,
+    multiplier: u16,
+    last_tick: Option<u32>,
+}
+
+impl EncoderAccel {
+    /// Create an accelerator with the default tuning
+    pub const fn new() -> Self {
+        Self::with_tuning(60, 16)
+    }
+
+    /// Create an accelerator with custom tuning
+    pub const fn with_tuning(fast_ms: u32, max_multiplier: u16) -> Self {
+        Self {
+            fast_ms,
+            max_multiplier,
+            multiplier: 1,
+            last_tick: None,
+        }
+    }
+
+    /// Scale `diff` by the current acceleration
+    ///
+    /// Call once per non-zero encoder reading. Uses LVGL's tick, so
+    /// [`tick_inc`](crate::tick_inc) must be driven.
+    pub fn accelerate(&mut self, diff: i16) -> i32 {
+        if diff == 0 {
+            return 0;
+        }
+        let now = unsafe { neo_lvgl_sys::lv_tick_get() };
+        let fast = match self.last_tick {
+            Some(last) => unsafe { neo_lvgl_sys::lv_tick_diff(now, last) } <= self.fast_ms,
+            None => false,
+        };
+        self.multiplier = if fast {
+            self.multiplier.saturating_mul(2).min(self.max_multiplier.max(1))
+        } else {
+            1
+        };
+        self.last_tick = Some(now);
+        diff as i32 * self.multiplier as i32
+    }
+
+    /// Get the multiplier applied to the last rotation
+    pub fn multiplier(&self) -> u16 {
+        self.multiplier
+    }
+
+    /// Reset to single steps
+    pub fn reset(&mut self) {
+        self.multiplier = 1;
+        self.last_tick = None;
+    }
+}
+
+impl Default for EncoderAccel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Input device read callback type
 pub type IndevReadCb = unsafe extern "C" fn(*mut neo_lvgl_sys::lv_indev_t, *mut neo_lvgl_sys::lv_indev_data_t);
 
@@ -195,6 +426,14 @@ impl Indev {
         Some(Self { raw: indev })
     }
 
+    /// Like [`new_static`](Self::new_static), but reports why creation failed.
+    pub fn try_new_static(
+        indev_type: IndevType,
+        read_cb: IndevReadCb,
+    ) -> Result<Self, crate::LvError> {
+        Self::new_static(indev_type, read_cb).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Create from raw pointer
     ///
     /// # Safety
@@ -232,12 +471,73 @@ impl Indev {
     }
 
     /// Set cursor object for pointer devices
-    pub fn set_cursor<'a, W: crate::widgets::Widget<'a>>(&self, cursor: &W) {
+    ///
+    /// LVGL moves the object to the display's system layer and keeps
+    /// positioning it at the pointer, so it must outlive the input device.
+    /// Don't delete it while it is the cursor; use
+    /// [`remove_cursor`](Self::remove_cursor) or set another cursor instead.
+    pub fn set_cursor<'a, W: Widget<'a>>(&self, cursor: &W) {
         unsafe {
             neo_lvgl_sys::lv_indev_set_cursor(self.raw.as_ptr(), cursor.raw());
         }
     }
 
+    /// Get the cursor object, if one was set
+    pub fn cursor(&self) -> Option<crate::widgets::Obj<'static>> {
+        unsafe {
+            let cursor = neo_lvgl_sys::lv_indev_get_cursor(self.raw.as_ptr());
+            crate::widgets::Obj::from_raw(cursor)
+        }
+    }
+
+    /// Use a built-in arrow image as the cursor (for pointer devices)
+    ///
+    /// Meant for simulators and desktop targets where the host doesn't draw
+    /// a mouse pointer. The arrow is 12 x 19 pixels with its tip at the
+    /// pointer position. Returns the image so it can be restyled, or `None`
+    /// if LVGL is out of memory.
+    ///
+    /// Must be called from the thread running LVGL.
+    pub fn set_default_cursor(&self) -> Option<crate::widgets::Image<'static>> {
+        let dsc = default_cursor_dsc()?;
+        unsafe {
+            let display = neo_lvgl_sys::lv_indev_get_display(self.raw.as_ptr());
+            let layer = if display.is_null() {
+                neo_lvgl_sys::lv_layer_sys()
+            } else {
+                neo_lvgl_sys::lv_display_get_layer_sys(display)
+            };
+            let obj = crate::widgets::Obj::from_raw(neo_lvgl_sys::lv_image_create(layer))?;
+            let image = crate::widgets::Image::from_obj(obj);
+            image.set_src_dsc(dsc);
+            self.set_cursor(&image);
+            Some(image)
+        }
+    }
+
+    /// Show or hide the cursor object
+    ///
+    /// Does nothing if no cursor was set.
+    pub fn show_cursor(&self, show: bool) {
+        if let Some(cursor) = self.cursor() {
+            cursor.set_hidden(!show);
+        }
+    }
+
+    /// Check whether a cursor is set and visible
+    pub fn is_cursor_visible(&self) -> bool {
+        self.cursor().is_some_and(|cursor| !cursor.is_hidden())
+    }
+
+    /// Stop showing the cursor
+    ///
+    /// LVGL can't detach a cursor from its input device, so the object is
+    /// hidden and stays on the system layer until another cursor is set.
+    /// It still must not be deleted before the input device.
+    pub fn remove_cursor(&self) {
+        self.show_cursor(false);
+    }
+
     /// Get the last pressed point (for pointer devices)
     pub fn point(&self) -> Point {
         let mut point = neo_lvgl_sys::lv_point_t { x: 0, y: 0 };
@@ -274,6 +574,31 @@ impl Indev {
         Point::new(point.x, point.y)
     }
 
+    /// Get a snapshot of the pointer (for pointer devices)
+    ///
+    /// Combines [`point`](Self::point), [`vect`](Self::vect) and the pressed
+    /// state, stamped with the current tick. LVGL updates these once per
+    /// device read, which [`task_handler`](crate::task_handler) runs every
+    /// read period (`LV_DEF_REFR_PERIOD`, 33 ms by default), so sampling more
+    /// often returns the same values.
+    pub fn pointer_state(&self) -> PointerState {
+        let state = unsafe { neo_lvgl_sys::lv_indev_get_state(self.raw.as_ptr()) };
+        PointerState {
+            point: self.point(),
+            vect: self.vect(),
+            pressed: state == neo_lvgl_sys::lv_indev_state_t_LV_INDEV_STATE_PRESSED,
+            timestamp: unsafe { neo_lvgl_sys::lv_tick_get() },
+        }
+    }
+
+    /// Get the object this device is scrolling, if a scroll is in progress
+    pub fn scroll_obj(&self) -> Option<crate::widgets::Obj<'static>> {
+        unsafe {
+            let ptr = neo_lvgl_sys::lv_indev_get_scroll_obj(self.raw.as_ptr());
+            crate::widgets::Obj::from_raw(ptr)
+        }
+    }
+
     /// Set long press time
     pub fn set_long_press_time(&self, time_ms: u16) {
         unsafe {
@@ -281,6 +606,13 @@ impl Indev {
         }
     }
 
+    /// Set the interval between `LongPressedRepeat` events while held
+    pub fn set_long_press_repeat_time(&self, time_ms: u16) {
+        unsafe {
+            neo_lvgl_sys::lv_indev_set_long_press_repeat_time(self.raw.as_ptr(), time_ms);
+        }
+    }
+
     /// Set scroll limit (minimum drag distance to start scrolling)
     pub fn set_scroll_limit(&self, limit: u8) {
         unsafe {
@@ -341,7 +673,7 @@ pub enum GestureDir {
 }
 
 impl GestureDir {
-    fn from_raw(raw: neo_lvgl_sys::lv_dir_t) -> Self {
+    pub(crate) fn from_raw(raw: neo_lvgl_sys::lv_dir_t) -> Self {
         match raw {
             neo_lvgl_sys::lv_dir_t_LV_DIR_LEFT => GestureDir::Left,
             neo_lvgl_sys::lv_dir_t_LV_DIR_RIGHT => GestureDir::Right,
@@ -422,14 +754,14 @@ mod closure_support {
         where
             F: FnMut() -> PointerData + 'static,
         {
+            let ptr = unsafe { neo_lvgl_sys::lv_indev_create() };
+            let indev = NonNull::new(ptr)?;
+
             let closure = Box::new(PointerReadClosure {
                 callback: Box::new(read_cb),
             });
             let raw_closure = Box::into_raw(closure);
 
-            let ptr = unsafe { neo_lvgl_sys::lv_indev_create() };
-            let indev = NonNull::new(ptr)?;
-
             unsafe {
                 neo_lvgl_sys::lv_indev_set_type(ptr, IndevType::Pointer.to_raw());
                 neo_lvgl_sys::lv_indev_set_read_cb(ptr, Some(pointer_trampoline));
@@ -439,19 +771,27 @@ mod closure_support {
             Some(Self { raw: indev })
         }
 
+        /// Like [`new_pointer`](Self::new_pointer), but reports why creation failed.
+        pub fn try_new_pointer<F>(read_cb: F) -> Result<Self, crate::LvError>
+        where
+            F: FnMut() -> PointerData + 'static,
+        {
+            Self::new_pointer(read_cb).ok_or(crate::LvError::OutOfMemory)
+        }
+
         /// Create a new keypad input device with a closure
         pub fn new_keypad<F>(read_cb: F) -> Option<Self>
         where
             F: FnMut() -> KeypadData + 'static,
         {
+            let ptr = unsafe { neo_lvgl_sys::lv_indev_create() };
+            let indev = NonNull::new(ptr)?;
+
             let closure = Box::new(KeypadReadClosure {
                 callback: Box::new(read_cb),
             });
             let raw_closure = Box::into_raw(closure);
 
-            let ptr = unsafe { neo_lvgl_sys::lv_indev_create() };
-            let indev = NonNull::new(ptr)?;
-
             unsafe {
                 neo_lvgl_sys::lv_indev_set_type(ptr, IndevType::Keypad.to_raw());
                 neo_lvgl_sys::lv_indev_set_read_cb(ptr, Some(keypad_trampoline));
@@ -461,19 +801,27 @@ mod closure_support {
             Some(Self { raw: indev })
         }
 
+        /// Like [`new_keypad`](Self::new_keypad), but reports why creation failed.
+        pub fn try_new_keypad<F>(read_cb: F) -> Result<Self, crate::LvError>
+        where
+            F: FnMut() -> KeypadData + 'static,
+        {
+            Self::new_keypad(read_cb).ok_or(crate::LvError::OutOfMemory)
+        }
+
         /// Create a new encoder input device with a closure
         pub fn new_encoder<F>(read_cb: F) -> Option<Self>
         where
             F: FnMut() -> EncoderData + 'static,
         {
+            let ptr = unsafe { neo_lvgl_sys::lv_indev_create() };
+            let indev = NonNull::new(ptr)?;
+
             let closure = Box::new(EncoderReadClosure {
                 callback: Box::new(read_cb),
             });
             let raw_closure = Box::into_raw(closure);
 
-            let ptr = unsafe { neo_lvgl_sys::lv_indev_create() };
-            let indev = NonNull::new(ptr)?;
-
             unsafe {
                 neo_lvgl_sys::lv_indev_set_type(ptr, IndevType::Encoder.to_raw());
                 neo_lvgl_sys::lv_indev_set_read_cb(ptr, Some(encoder_trampoline));
@@ -482,6 +830,14 @@ mod closure_support {
 
             Some(Self { raw: indev })
         }
+
+        /// Like [`new_encoder`](Self::new_encoder), but reports why creation failed.
+        pub fn try_new_encoder<F>(read_cb: F) -> Result<Self, crate::LvError>
+        where
+            F: FnMut() -> EncoderData + 'static,
+        {
+            Self::new_encoder(read_cb).ok_or(crate::LvError::OutOfMemory)
+        }
     }
 }
 
@@ -490,3 +846,370 @@ pub fn indev_active() -> Option<Indev> {
     let ptr = unsafe { neo_lvgl_sys::lv_indev_active() };
     unsafe { Indev::from_raw(ptr) }
 }
+
+/// Get the object the input device being processed is acting on
+///
+/// Like [`indev_active`], this is only meaningful inside event handlers and
+/// read callbacks. For a pointer it is the pressed object, for a keypad or
+/// encoder the focused one.
+pub fn active_obj() -> Option<crate::widgets::Obj<'static>> {
+    unsafe {
+        let ptr = neo_lvgl_sys::lv_indev_get_active_obj();
+        crate::widgets::Obj::from_raw(ptr)
+    }
+}
+
+/// Enable or disable every registered input device
+pub fn set_all_enabled(en: bool) {
+    unsafe {
+        let mut indev = neo_lvgl_sys::lv_indev_get_next(core::ptr::null_mut());
+        while !indev.is_null() {
+            neo_lvgl_sys::lv_indev_enable(indev, en);
+            indev = neo_lvgl_sys::lv_indev_get_next(indev);
+        }
+    }
+}
+
+/// Attach every keypad and encoder to the default group
+///
+/// Call this after [`Group::set_default`](crate::group::Group::set_default)
+/// and after the input devices are created. Returns the number of devices
+/// attached, or 0 if there is no default group.
+pub fn attach_keypad_to_default_group() -> u32 {
+    let mut count = 0;
+    unsafe {
+        let group = neo_lvgl_sys::lv_group_get_default();
+        if group.is_null() {
+            return 0;
+        }
+        let mut indev = neo_lvgl_sys::lv_indev_get_next(core::ptr::null_mut());
+        while !indev.is_null() {
+            let ty = IndevType::from_raw(neo_lvgl_sys::lv_indev_get_type(indev));
+            if matches!(ty, Some(IndevType::Keypad | IndevType::Encoder)) {
+                neo_lvgl_sys::lv_indev_set_group(indev, group);
+                count += 1;
+            }
+            indev = neo_lvgl_sys::lv_indev_get_next(indev);
+        }
+    }
+    count
+}
+
+/// RAII guard that blocks all user input while it is alive
+///
+/// Creating an `InputLock` stops LVGL from reading every input device;
+/// dropping it lets them be read again. Use it to freeze the UI during long
+/// operations such as flashing firmware or loading data.
+///
+/// Locks nest: input stays blocked until the last live lock is dropped, in
+/// any order. The lock pauses each device's read timer rather than
+/// disabling it, so a device turned off with [`Indev::enable`] stays off.
+///
+/// This is unrelated to the global LVGL lock ([`crate::sync::lvgl_lock`]):
+/// it does not make LVGL thread-safe and does not take that lock. Like any
+/// other LVGL call, create and drop it on the LVGL thread or while holding
+/// the global lock.
+///
+/// # Example
+///
+/// ```ignore
+/// {
+///     let _lock = InputLock::with_busy_overlay();
+///     flash_firmware();
+/// } // input re-enabled, overlay removed
+/// ```
+pub struct InputLock {
+    overlay: Option<NonNull<neo_lvgl_sys::lv_obj_t>>,
+}
+
+impl InputLock {
+    /// Block all input devices
+    pub fn new() -> Self {
+        lock_input();
+        Self { overlay: None }
+    }
+
+    /// Block all input devices and show a dimmed busy overlay
+    ///
+    /// The overlay covers the top layer of the default display. With the
+    /// `widgets-extra` feature it also shows a spinner.
+    pub fn with_busy_overlay() -> Self {
+        lock_input();
+
+        let overlay = unsafe {
+            let obj = neo_lvgl_sys::lv_obj_create(neo_lvgl_sys::lv_layer_top());
+            if !obj.is_null() {
+                neo_lvgl_sys::lv_obj_remove_style_all(obj);
+                neo_lvgl_sys::lv_obj_set_size(
+                    obj,
+                    neo_lvgl_sys::lv_pct(100),
+                    neo_lvgl_sys::lv_pct(100),
+                );
+                neo_lvgl_sys::lv_obj_set_style_bg_color(
+                    obj,
+                    crate::color::Color::black().to_raw(),
+                    neo_lvgl_sys::lv_part_t_LV_PART_MAIN,
+                );
+                neo_lvgl_sys::lv_obj_set_style_bg_opa(
+                    obj,
+                    crate::color::Opacity::OPA_50.to_raw(),
+                    neo_lvgl_sys::lv_part_t_LV_PART_MAIN,
+                );
+
+                #[cfg(feature = "widgets-extra")]
+                {
+                    let spinner = neo_lvgl_sys::lv_spinner_create(obj);
+                    if !spinner.is_null() {
+                        neo_lvgl_sys::lv_obj_set_size(spinner, 48, 48);
+                        neo_lvgl_sys::lv_obj_center(spinner);
+                    }
+                }
+            }
+            NonNull::new(obj)
+        };
+
+        Self { overlay }
+    }
+}
+
+impl Default for InputLock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for InputLock {
+    fn drop(&mut self) {
+        if let Some(overlay) = self.overlay.take() {
+            unsafe {
+                neo_lvgl_sys::lv_obj_delete(overlay.as_ptr());
+            }
+        }
+        unlock_input();
+    }
+}
+
+/// Number of live [`InputLock`]s
+///
+/// Only touched on the LVGL thread, so plain loads and stores suffice.
+static INPUT_LOCKS: AtomicU32 = AtomicU32::new(0);
+
+fn lock_input() {
+    let locks = INPUT_LOCKS.load(Ordering::Relaxed);
+    INPUT_LOCKS.store(locks + 1, Ordering::Relaxed);
+    if locks == 0 {
+        set_all_reading(false);
+    }
+}
+
+fn unlock_input() {
+    let locks = INPUT_LOCKS.load(Ordering::Relaxed).saturating_sub(1);
+    INPUT_LOCKS.store(locks, Ordering::Relaxed);
+    if locks == 0 {
+        set_all_reading(true);
+    }
+}
+
+/// Pause or resume the read timer of every registered input device
+fn set_all_reading(en: bool) {
+    unsafe {
+        let mut indev = neo_lvgl_sys::lv_indev_get_next(core::ptr::null_mut());
+        while !indev.is_null() {
+            let timer = neo_lvgl_sys::lv_indev_get_read_timer(indev);
+            if !timer.is_null() {
+                if en {
+                    neo_lvgl_sys::lv_timer_resume(timer);
+                } else {
+                    neo_lvgl_sys::lv_timer_pause(timer);
+                }
+            }
+            indev = neo_lvgl_sys::lv_indev_get_next(indev);
+        }
+    }
+}
+
+/// Arrow cursor: `#` is the outline, `.` the fill
+const ARROW_MASK: [&[u8; ARROW_WIDTH]; ARROW_HEIGHT] = [
+    b"#           ",
+    b"##          ",
+    b"#.#         ",
+    b"#..#        ",
+    b"#...#       ",
+    b"#....#      ",
+    b"#.....#     ",
+    b"#......#    ",
+    b"#.......#   ",
+    b"#........#  ",
+    b"#.........# ",
+    b"#......#####",
+    b"#...#..#    ",
+    b"#..##..#    ",
+    b"#.#  #..#   ",
+    b"##   #..#   ",
+    b"#     #..#  ",
+    b"      #..#  ",
+    b"       ##   ",
+];
+const ARROW_WIDTH: usize = 12;
+const ARROW_HEIGHT: usize = 19;
+
+/// The arrow as ARGB8888 pixels (stored as B, G, R, A)
+static ARROW_PIXELS: [u8; ARROW_WIDTH * ARROW_HEIGHT * 4] = {
+    let mut px = [0; ARROW_WIDTH * ARROW_HEIGHT * 4];
+    let mut i = 0;
+    while i < ARROW_WIDTH * ARROW_HEIGHT {
+        let (value, alpha) = match ARROW_MASK[i / ARROW_WIDTH][i % ARROW_WIDTH] {
+            b'#' => (0x00, 0xFF),
+            b'.' => (0xFF, 0xFF),
+            _ => (0x00, 0x00),
+        };
+        px[i * 4] = value;
+        px[i * 4 + 1] = value;
+        px[i * 4 + 2] = value;
+        px[i * 4 + 3] = alpha;
+        i += 1;
+    }
+    px
+};
+
+struct CursorDsc(core::cell::UnsafeCell<Option<crate::widgets::ImageDsc>>);
+
+// SAFETY: only accessed from the thread running LVGL
+unsafe impl Sync for CursorDsc {}
+
+static ARROW_DSC: CursorDsc = CursorDsc(core::cell::UnsafeCell::new(None));
+
+/// Get the arrow's image descriptor, creating it on first use
+fn default_cursor_dsc() -> Option<&'static crate::widgets::ImageDsc> {
+    // SAFETY: only called from the thread running LVGL, and the descriptor
+    // is never replaced once created
+    let slot = unsafe { &mut *ARROW_DSC.0.get() };
+    if slot.is_none() {
+        let dsc = crate::widgets::ImageDsc::new(
+            ARROW_WIDTH as u16,
+            ARROW_HEIGHT as u16,
+            crate::display::ColorFormat::Argb8888,
+            &ARROW_PIXELS,
+        );
+        *slot = Some(dsc.ok()?);
+    }
+    slot.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(x: i32, y: i32, timestamp: u32) -> PointerState {
+        PointerState {
+            point: Point::new(x, y),
+            vect: Point::new(0, 0),
+            pressed: true,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_pointer_history_keeps_last_samples() {
+        let mut history = PointerHistory::<3>::new();
+        assert_eq!(history.velocity(), None);
+
+        for (i, x) in [0, 10, 20, 30].into_iter().enumerate() {
+            history.push(sample(x, 0, i as u32 * 100));
+        }
+        // Same timestamp as the latest sample: not read again
+        history.push(sample(99, 99, 300));
+
+        assert_eq!(history.len(), 3);
+        let xs: std::vec::Vec<i32> = history.iter().map(|s| s.point.x).collect();
+        assert_eq!(xs, [10, 20, 30]);
+        assert_eq!(history.velocity(), Some((100, 0)));
+        assert_eq!(history.path_length(), 20);
+
+        history.clear();
+        assert!(history.is_empty());
+        assert_eq!(history.latest(), None);
+    }
+
+    #[test]
+    fn test_default_cursor_show_hide() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::display::Display::new(320, 240).unwrap();
+        let indev = Indev::new_pointer(|| PointerData {
+            point: Point::new(50, 60),
+            state: IndevState::Released,
+        })
+        .unwrap();
+        indev.set_display(&display);
+        assert!(indev.cursor().is_none());
+        assert!(!indev.is_cursor_visible());
+
+        let arrow = indev.set_default_cursor().unwrap();
+        assert_eq!((arrow.src_width(), arrow.src_height()), (12, 19));
+        assert_eq!(indev.cursor().map(|c| c.raw()), Some(arrow.raw()));
+        assert!(indev.is_cursor_visible());
+
+        unsafe { neo_lvgl_sys::lv_indev_read(indev.raw()) };
+        assert_eq!((arrow.x(), arrow.y()), (50, 60));
+
+        indev.remove_cursor();
+        assert!(!indev.is_cursor_visible());
+        indev.show_cursor(true);
+        assert!(indev.is_cursor_visible());
+    }
+
+    fn counting_pointer(reads: &std::rc::Rc<core::cell::Cell<u32>>) -> Indev {
+        let reads = reads.clone();
+        Indev::new_pointer(move || {
+            reads.set(reads.get() + 1);
+            PointerData {
+                point: Point::new(0, 0),
+                state: IndevState::Released,
+            }
+        })
+        .unwrap()
+    }
+
+    fn run_timers() {
+        crate::tick_inc(100);
+        crate::task_handler();
+    }
+
+    #[test]
+    fn test_input_lock_keeps_disabled_indev_off() {
+        let _lvgl = crate::test_lvgl();
+
+        let _display = crate::display::Display::new(320, 240).unwrap();
+        let reads = std::rc::Rc::new(core::cell::Cell::new(0));
+        let indev = counting_pointer(&reads);
+        indev.enable(false);
+
+        drop(InputLock::new());
+        run_timers();
+        assert_eq!(reads.get(), 0);
+
+        indev.enable(true);
+        run_timers();
+        assert!(reads.get() > 0);
+    }
+
+    #[test]
+    fn test_input_lock_nests() {
+        let _lvgl = crate::test_lvgl();
+
+        let _display = crate::display::Display::new(320, 240).unwrap();
+        let reads = std::rc::Rc::new(core::cell::Cell::new(0));
+        let _indev = counting_pointer(&reads);
+
+        let outer = InputLock::new();
+        let inner = InputLock::new();
+        drop(outer);
+        run_timers();
+        assert_eq!(reads.get(), 0);
+
+        drop(inner);
+        run_timers();
+        assert!(reads.get() > 0);
+    }
+}
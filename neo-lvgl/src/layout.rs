@@ -223,6 +223,35 @@ pub trait LayoutExt<'a>: crate::widgets::Widget<'a> {
         }
     }
 
+    /// Set only the main axis alignment of this flex container
+    ///
+    /// Unlike [`set_flex_align`](Self::set_flex_align), the other two
+    /// alignments are left as they are.
+    fn set_flex_main_place(&self, place: FlexAlign) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_flex_main_place(self.raw(), place.to_raw(), 0);
+        }
+    }
+
+    /// Set only how items are aligned across the axis within their track
+    ///
+    /// The `Space*` values behave like `Start` here.
+    fn set_flex_cross_place(&self, place: FlexAlign) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_flex_cross_place(self.raw(), place.to_raw(), 0);
+        }
+    }
+
+    /// Set only how the tracks of a wrapping flex container are distributed
+    ///
+    /// Only has an effect with a wrapping [`FlexFlow`] and spare space on
+    /// the cross axis.
+    fn set_flex_track_place(&self, place: FlexAlign) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_flex_track_place(self.raw(), place.to_raw(), 0);
+        }
+    }
+
     /// Set the flex grow factor for this widget
     ///
     /// Widgets with higher grow values will take more available space.
@@ -307,6 +336,115 @@ pub trait LayoutExt<'a>: crate::widgets::Widget<'a> {
         }
     }
 
+    /// Change only the column placement of this widget in its grid cell
+    ///
+    /// The row placement set with [`set_grid_cell`](Self::set_grid_cell)
+    /// or [`set_grid_row`](Self::set_grid_row) is kept, so an item can be
+    /// re-spanned without repeating it.
+    fn set_grid_column(&self, align: GridAlign, pos: u8, span: u8) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_grid_cell_x_align(self.raw(), align.to_raw(), 0);
+            neo_lvgl_sys::lv_obj_set_style_grid_cell_column_pos(self.raw(), pos as i32, 0);
+            neo_lvgl_sys::lv_obj_set_style_grid_cell_column_span(self.raw(), span as i32, 0);
+        }
+    }
+
+    /// Change only the row placement of this widget in its grid cell
+    ///
+    /// The column placement is kept, see
+    /// [`set_grid_column`](Self::set_grid_column).
+    fn set_grid_row(&self, align: GridAlign, pos: u8, span: u8) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_grid_cell_y_align(self.raw(), align.to_raw(), 0);
+            neo_lvgl_sys::lv_obj_set_style_grid_cell_row_pos(self.raw(), pos as i32, 0);
+            neo_lvgl_sys::lv_obj_set_style_grid_cell_row_span(self.raw(), span as i32, 0);
+        }
+    }
+
+    /// Get the grid cell this widget was placed in
+    ///
+    /// Returns `(col_pos, col_span, row_pos, row_span)`, or `None` if the
+    /// parent does not use a grid layout.
+    fn grid_cell(&self) -> Option<(u8, u8, u8, u8)> {
+        unsafe {
+            let parent = neo_lvgl_sys::lv_obj_get_parent(self.raw());
+            if parent.is_null() {
+                return None;
+            }
+            let layout = style_num(parent, neo_lvgl_sys::_lv_style_id_t_LV_STYLE_LAYOUT);
+            if layout != neo_lvgl_sys::lv_layout_t_LV_LAYOUT_GRID as i32 {
+                return None;
+            }
+
+            let obj = self.raw();
+            Some((
+                style_num(obj, neo_lvgl_sys::_lv_style_id_t_LV_STYLE_GRID_CELL_COLUMN_POS) as u8,
+                style_num(obj, neo_lvgl_sys::_lv_style_id_t_LV_STYLE_GRID_CELL_COLUMN_SPAN) as u8,
+                style_num(obj, neo_lvgl_sys::_lv_style_id_t_LV_STYLE_GRID_CELL_ROW_POS) as u8,
+                style_num(obj, neo_lvgl_sys::_lv_style_id_t_LV_STYLE_GRID_CELL_ROW_SPAN) as u8,
+            ))
+        }
+    }
+
+    /// Place all children of this grid container into cells automatically
+    ///
+    /// Children are assigned one cell each, left-to-right and top-to-bottom,
+    /// wrapping after the last column of the column template. Hidden children
+    /// and children with `Flag::IGNORE_LAYOUT` are skipped.
+    ///
+    /// Returns the number of rows used.
+    ///
+    /// # Limitations
+    ///
+    /// Unlike flex wrapping, this is a one-shot placement: children added
+    /// later are not placed until this is called again. The row template set
+    /// with [`set_grid_dsc_array`](Self::set_grid_dsc_array) must define
+    /// enough rows for all children, otherwise the extra rows have no size.
+    fn auto_place_grid(&self, col_align: GridAlign, row_align: GridAlign) -> u32 {
+        unsafe {
+            let container = self.raw();
+            let col_dsc = neo_lvgl_sys::lv_obj_get_style_prop(
+                container,
+                neo_lvgl_sys::lv_part_t_LV_PART_MAIN,
+                neo_lvgl_sys::_lv_style_id_t_LV_STYLE_GRID_COLUMN_DSC_ARRAY as u8,
+            )
+            .ptr as *const i32;
+            if col_dsc.is_null() {
+                return 0;
+            }
+
+            let mut cols = 0;
+            while *col_dsc.add(cols) != GRID_TEMPLATE_LAST {
+                cols += 1;
+            }
+            if cols == 0 {
+                return 0;
+            }
+
+            let skip = neo_lvgl_sys::lv_obj_flag_t_LV_OBJ_FLAG_HIDDEN
+                | neo_lvgl_sys::lv_obj_flag_t_LV_OBJ_FLAG_IGNORE_LAYOUT;
+            let mut cell = 0u32;
+            for i in 0..neo_lvgl_sys::lv_obj_get_child_count(container) {
+                let child = neo_lvgl_sys::lv_obj_get_child(container, i as i32);
+                if neo_lvgl_sys::lv_obj_has_flag_any(child, skip) {
+                    continue;
+                }
+                neo_lvgl_sys::lv_obj_set_grid_cell(
+                    child,
+                    col_align.to_raw(),
+                    (cell % cols as u32) as i32,
+                    1,
+                    row_align.to_raw(),
+                    (cell / cols as u32) as i32,
+                    1,
+                );
+                cell += 1;
+            }
+
+            cell.div_ceil(cols as u32)
+        }
+    }
+
     /// Update the layout of this widget and its children
     ///
     /// Call this after modifying layout properties to force an immediate recalculation.
@@ -320,6 +458,11 @@ pub trait LayoutExt<'a>: crate::widgets::Widget<'a> {
 // Implement LayoutExt for all Widget types
 impl<'a, T: crate::widgets::Widget<'a>> LayoutExt<'a> for T {}
 
+/// Read a numeric style property from the main part of an object
+unsafe fn style_num(obj: *mut neo_lvgl_sys::lv_obj_t, prop: neo_lvgl_sys::_lv_style_id_t) -> i32 {
+    neo_lvgl_sys::lv_obj_get_style_prop(obj, neo_lvgl_sys::lv_part_t_LV_PART_MAIN, prop as u8).num
+}
+
 /// Initialize the flex layout system
 ///
 /// This is called automatically by LVGL during init, but can be called
@@ -339,3 +482,113 @@ pub fn grid_init() {
         neo_lvgl_sys::lv_grid_init();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::widgets::{Container, Widget};
+
+    #[test]
+    fn test_flex_places_set_individually() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+
+        let row = Container::new(&screen).unwrap();
+        unsafe { neo_lvgl_sys::lv_obj_remove_style_all(row.raw()) };
+        row.set_size(200, 100);
+        row.set_flex_flow(FlexFlow::Row);
+        let items: [Container; 2] = core::array::from_fn(|_| {
+            let item = Container::new(&row).unwrap();
+            unsafe { neo_lvgl_sys::lv_obj_remove_style_all(item.raw()) };
+            item.set_size(20, 20);
+            item
+        });
+
+        row.set_flex_cross_place(FlexAlign::Center);
+        row.set_flex_main_place(FlexAlign::End);
+        row.update_layout();
+        // Setting the main place kept the cross place
+        assert_eq!(items[0].y(), 40);
+        assert_eq!(items[0].x(), 160);
+        assert_eq!(items[1].x(), 180);
+
+        let wrap = Container::new(&screen).unwrap();
+        unsafe { neo_lvgl_sys::lv_obj_remove_style_all(wrap.raw()) };
+        wrap.set_size(100, 100);
+        wrap.set_flex_flow(FlexFlow::RowWrap);
+        let items: [Container; 6] = core::array::from_fn(|_| {
+            let item = Container::new(&wrap).unwrap();
+            unsafe { neo_lvgl_sys::lv_obj_remove_style_all(item.raw()) };
+            item.set_size(40, 20);
+            item
+        });
+
+        // Three tracks of 20 px pushed to the bottom
+        wrap.set_flex_track_place(FlexAlign::End);
+        wrap.update_layout();
+        assert_eq!(items[0].y(), 40);
+    }
+
+    #[test]
+    fn test_grid_column_respan() {
+        static COLS: [i32; 3] = [100, 100, GRID_TEMPLATE_LAST];
+        static ROWS: [i32; 3] = [50, 50, GRID_TEMPLATE_LAST];
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let grid = Container::new(&screen).unwrap();
+        grid.set_size(200, 100);
+        grid.set_grid_dsc_array(&COLS, &ROWS);
+
+        let item = Container::new(&grid).unwrap();
+        item.set_grid_cell(GridAlign::Stretch, 0, 1, GridAlign::Stretch, 1, 1);
+        grid.update_layout();
+        assert_eq!((item.x(), item.y(), item.width()), (0, 50, 100));
+
+        item.set_grid_column(GridAlign::Stretch, 0, 2);
+        grid.update_layout();
+        assert_eq!((item.x(), item.y(), item.width()), (0, 50, 200));
+        assert_eq!(item.grid_cell(), Some((0, 2, 1, 1)));
+
+        item.set_grid_row(GridAlign::Start, 0, 1);
+        grid.update_layout();
+        assert_eq!((item.y(), item.width()), (0, 200));
+    }
+
+    #[test]
+    fn test_auto_place_grid() {
+        static COLS: [i32; 4] = [50, 50, 50, GRID_TEMPLATE_LAST];
+        static ROWS: [i32; 3] = [40, 40, GRID_TEMPLATE_LAST];
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let grid = Container::new(&screen).unwrap();
+        grid.set_size(150, 80);
+        assert_eq!(grid.auto_place_grid(GridAlign::Stretch, GridAlign::Stretch), 0);
+
+        grid.set_grid_dsc_array(&COLS, &ROWS);
+        let items: [Container; 5] = core::array::from_fn(|_| Container::new(&grid).unwrap());
+        items[1].add_flag(crate::widgets::Flag::HIDDEN);
+
+        assert_eq!(grid.auto_place_grid(GridAlign::Stretch, GridAlign::Stretch), 2);
+        grid.update_layout();
+
+        // The hidden child is skipped and the fourth placed one wraps
+        assert_eq!(items[0].grid_cell(), Some((0, 1, 0, 1)));
+        assert_eq!(items[2].grid_cell(), Some((1, 1, 0, 1)));
+        assert_eq!(items[3].grid_cell(), Some((2, 1, 0, 1)));
+        assert_eq!(items[4].grid_cell(), Some((0, 1, 1, 1)));
+        assert_eq!((items[3].x(), items[3].y()), (100, 0));
+        assert_eq!((items[4].x(), items[4].y(), items[4].width()), (0, 40, 50));
+
+        // Not in a grid
+        assert_eq!(grid.grid_cell(), None);
+    }
+}
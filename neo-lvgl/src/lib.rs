@@ -8,6 +8,8 @@
 //! - `alloc` - Enable closure-based event handlers (requires allocator)
 //! - `widgets-core` - Core widgets (Button, Label, etc.) - enabled by default
 //! - `widgets-extra` - Additional widgets (Chart, Calendar, etc.)
+//! - `debug` - Debugging helpers such as `Widget::dump_tree` (requires `alloc`)
+//! - `log` - LVGL's log module and the [`log`] capture buffer
 //!
 //! # Example
 //!
@@ -33,24 +35,92 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(test)]
+extern crate std;
+
 pub mod anim;
 pub mod color;
 pub mod display;
+pub mod draw_buf;
 pub mod event;
 pub mod font;
 pub mod fragment;
 pub mod group;
+pub mod image;
 pub mod indev;
 pub mod layout;
+#[cfg(feature = "alloc")]
+pub mod list_adapter;
+#[cfg(feature = "log")]
+pub mod log;
 pub mod observer;
 pub mod prelude;
+#[cfg(feature = "alloc")]
+pub mod screen;
 pub mod scroll;
 pub mod style;
+pub mod symbols;
 pub mod sync;
 pub mod timer;
+pub mod toast;
 pub mod widgets;
 pub mod xml;
 
+pub use toast::toast;
+
+/// Create a `&'static CStr` from a string literal at compile time
+///
+/// All text APIs take `&CStr`. This is equivalent to the built-in `c"..."`
+/// literal, which the crate's minimum Rust version already supports. The
+/// terminating nul is added, and a nul inside the text is a compile error.
+///
+/// ```
+/// use core::ffi::CStr;
+///
+/// let title: &'static CStr = neo_lvgl::cstr!("Settings");
+/// assert_eq!(title.to_bytes(), b"Settings");
+///
+/// // Also usable in constants
+/// const OK: &CStr = neo_lvgl::cstr!("OK");
+/// assert_eq!(OK.to_str(), Ok("OK"));
+/// ```
+///
+/// ```compile_fail
+/// let bad = neo_lvgl::cstr!("a\0b");
+/// ```
+#[macro_export]
+macro_rules! cstr {
+    ($text:literal) => {{
+        const CSTR: &::core::ffi::CStr =
+            $crate::cstr_from_bytes_with_nul(::core::concat!($text, "\0").as_bytes());
+        CSTR
+    }};
+}
+
+/// Convert nul-terminated bytes to a `&CStr`, panicking if they aren't
+///
+/// Meant for constants, where the panic becomes a compile error; at run
+/// time prefer `CStr::from_bytes_with_nul`. The bytes must end with the
+/// only nul byte.
+///
+/// ```
+/// const NAME: &core::ffi::CStr = neo_lvgl::cstr_from_bytes_with_nul(b"lvgl\0");
+/// assert_eq!(NAME.to_bytes(), b"lvgl");
+/// ```
+pub const fn cstr_from_bytes_with_nul(bytes: &[u8]) -> &core::ffi::CStr {
+    match core::ffi::CStr::from_bytes_with_nul(bytes) {
+        Ok(cstr) => cstr,
+        Err(_) => panic!("string must end with its only nul byte"),
+    }
+}
+
+/// Error type for LVGL object creation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LvError {
+    /// LVGL could not allocate the object (its heap is exhausted)
+    OutOfMemory,
+}
+
 /// Initialize LVGL.
 ///
 /// This must be called before any other LVGL functions.
@@ -61,6 +131,24 @@ pub fn init() {
     }
 }
 
+/// Deinitialize LVGL and release all of its global state.
+///
+/// After this returns, [`init`] can be called again to start from a clean
+/// slate, which keeps tests that create displays and widgets independent
+/// of each other.
+///
+/// # Safety
+///
+/// Every wrapper that owns LVGL memory ([`display::Display`],
+/// [`style::Style`], [`timer::Timer`], subjects, groups, etc.) must be
+/// dropped or deleted before calling this. Any raw handle obtained before
+/// the call is dangling afterwards.
+pub unsafe fn deinit() {
+    if is_initialized() {
+        neo_lvgl_sys::lv_deinit();
+    }
+}
+
 /// Check if LVGL is initialized.
 pub fn is_initialized() -> bool {
     unsafe { neo_lvgl_sys::lv_is_initialized() }
@@ -80,6 +168,63 @@ pub fn tick_inc(ms: u32) {
     }
 }
 
+/// Callback returning the milliseconds elapsed since an arbitrary start
+///
+/// It should wrap around at `u32::MAX` like a free-running hardware timer.
+pub type TickGetCb = unsafe extern "C" fn() -> u32;
+
+/// Let LVGL read the time from `cb` instead of counting [`tick_inc`] calls
+///
+/// With a callback, LVGL asks for the time whenever it needs it and
+/// [`tick_inc`] has no effect, so no periodic interrupt is needed. Pass
+/// `None` to go back to [`tick_inc`].
+///
+/// LVGL has a single tick source shared by all displays.
+pub fn set_tick_cb(cb: Option<TickGetCb>) {
+    unsafe {
+        neo_lvgl_sys::lv_tick_set_cb(cb);
+    }
+}
+
+/// Get LVGL's current time in milliseconds
+///
+/// This is the clock animations and timers run on: the sum of all
+/// [`tick_inc`] calls, or the value of the callback given to
+/// [`set_tick_cb`]. Use it to align application timing with LVGL's.
+pub fn elapsed_ms() -> u32 {
+    unsafe { neo_lvgl_sys::lv_tick_get() }
+}
+
+/// Get the milliseconds elapsed since `prev`, a value from [`elapsed_ms`]
+///
+/// Handles the tick counter wrapping around.
+pub fn elapsed_since(prev: u32) -> u32 {
+    unsafe { neo_lvgl_sys::lv_tick_elaps(prev) }
+}
+
+/// Get the milliseconds since the last user input on any display
+///
+/// Input devices reset the time when they read an event. Call
+/// [`Display::trigger_activity`](display::Display::trigger_activity) to
+/// reset it from other sources, e.g. a hardware button.
+///
+/// # Example
+///
+/// ```ignore
+/// // Dim the backlight after 30 s without input
+/// let timer = Timer::new(500, || {
+///     if lvgl::inactive_time_ms() > 30_000 {
+///         backlight.set_level(10);
+///     } else {
+///         backlight.set_level(100);
+///     }
+/// })
+/// .unwrap();
+/// ```
+pub fn inactive_time_ms() -> u32 {
+    unsafe { neo_lvgl_sys::lv_display_get_inactive_time(core::ptr::null_mut()) }
+}
+
 /// Run LVGL task handler.
 ///
 /// Call this periodically in your main loop to handle:
@@ -93,8 +238,171 @@ pub fn task_handler() -> u32 {
     unsafe { neo_lvgl_sys::lv_timer_handler() }
 }
 
+/// Get the active screen of the default display.
+///
+/// Returns `None` if no default display has been created.
+pub fn active_screen() -> Option<widgets::Screen<'static>> {
+    let ptr = unsafe { neo_lvgl_sys::lv_screen_active() };
+    if ptr.is_null() {
+        None
+    } else {
+        Some(widgets::Screen::from_raw(ptr))
+    }
+}
+
 /// Get LVGL version as a string.
 pub fn version_info() -> &'static str {
     // LVGL 9.x version
     "9.x"
 }
+
+/// Serialize tests that touch LVGL's global state.
+#[cfg(test)]
+pub(crate) fn test_lock() -> std::sync::MutexGuard<'static, ()> {
+    static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Initialize LVGL for a test and deinitialize it when dropped
+///
+/// Holds [`test_lock`] for its lifetime. Bind it first so that displays,
+/// styles and other wrappers created afterwards are dropped before it.
+#[cfg(test)]
+pub(crate) struct TestLvgl {
+    _lock: std::sync::MutexGuard<'static, ()>,
+}
+
+#[cfg(test)]
+pub(crate) fn test_lvgl() -> TestLvgl {
+    let lock = test_lock();
+    init();
+    TestLvgl { _lock: lock }
+}
+
+#[cfg(test)]
+impl Drop for TestLvgl {
+    fn drop(&mut self) {
+        unsafe { deinit() };
+    }
+}
+
+/// Leak a zeroed draw buffer of `len` bytes for a test display
+#[cfg(test)]
+pub(crate) fn test_buffer(len: usize) -> &'static mut [u8] {
+    std::boxed::Box::leak(std::vec![0u8; len].into_boxed_slice())
+}
+
+/// Default display rendering into an ARGB8888 buffer that tests can read
+///
+/// Renders in full mode to a leaked buffer with a flush callback that only
+/// signals completion. Derefs to the [`display::Display`].
+#[cfg(test)]
+pub(crate) struct TestFramebuffer {
+    display: display::Display,
+    buf: *const u8,
+    width: usize,
+}
+
+#[cfg(test)]
+impl TestFramebuffer {
+    pub(crate) fn new(width: i32, height: i32) -> Self {
+        unsafe extern "C" fn flush_cb(
+            disp: *mut neo_lvgl_sys::lv_display_t,
+            _area: *const neo_lvgl_sys::lv_area_t,
+            _px_map: *mut u8,
+        ) {
+            neo_lvgl_sys::lv_display_flush_ready(disp);
+        }
+
+        let display = display::Display::new(width, height).unwrap();
+        display.set_color_format(display::ColorFormat::Argb8888);
+        let buf = test_buffer(width as usize * height as usize * 4);
+        let ptr = buf.as_ptr();
+        unsafe { display.set_buffers(buf, None, display::RenderMode::Full) };
+        display.set_flush_cb(flush_cb);
+        display.set_default();
+        Self {
+            display,
+            buf: ptr,
+            width: width as usize,
+        }
+    }
+
+    /// Get the pixel at (`x`, `y`) of the last render as `[r, g, b, a]`
+    pub(crate) fn pixel(&self, x: usize, y: usize) -> [u8; 4] {
+        // ARGB8888 is stored as B, G, R, A
+        let [b, g, r, a] = unsafe { *self.buf.add((y * self.width + x) * 4).cast::<[u8; 4]>() };
+        [r, g, b, a]
+    }
+}
+
+#[cfg(test)]
+impl core::ops::Deref for TestFramebuffer {
+    type Target = display::Display;
+
+    fn deref(&self) -> &display::Display {
+        &self.display
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_deinit_reinit() {
+        let _lock = test_lock();
+        init();
+        assert!(is_initialized());
+
+        {
+            let display = display::Display::new(64, 64).unwrap();
+            display.set_default();
+            let screen = display.active_screen();
+            let _label = widgets::Label::new(&screen).unwrap();
+        }
+
+        unsafe { deinit() };
+        assert!(!is_initialized());
+
+        init();
+        assert!(is_initialized());
+        unsafe { deinit() };
+    }
+
+    static FAKE_TICK: core::sync::atomic::AtomicU32 = core::sync::atomic::AtomicU32::new(0);
+
+    unsafe extern "C" fn fake_tick() -> u32 {
+        FAKE_TICK.load(core::sync::atomic::Ordering::Relaxed)
+    }
+
+    #[test]
+    fn test_elapsed_ms() {
+        let _lvgl = crate::test_lvgl();
+
+        let start = elapsed_ms();
+        tick_inc(50);
+        assert_eq!(elapsed_since(start), 50);
+
+        FAKE_TICK.store(1000, core::sync::atomic::Ordering::Relaxed);
+        set_tick_cb(Some(fake_tick));
+        assert_eq!(elapsed_ms(), 1000);
+        tick_inc(50);
+        assert_eq!(elapsed_ms(), 1000);
+        set_tick_cb(None);
+    }
+
+    #[test]
+    fn test_inactive_time() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = display::Display::new(64, 64).unwrap();
+        display.trigger_activity();
+        tick_inc(300);
+        assert_eq!(inactive_time_ms(), 300);
+        assert_eq!(display.inactive_time_ms(), 300);
+
+        display.trigger_activity();
+        assert_eq!(inactive_time_ms(), 0);
+    }
+}
@@ -0,0 +1,346 @@
+//! Virtualized lists
+//!
+//! A [`ListAdapter`] shows a long list of items in a scrollable container
+//! while only keeping enough row widgets alive to fill the visible area.
+//! When the container scrolls, rows that leave the view are moved to the
+//! other end and rendered again with the item that now belongs there.
+//!
+//! # Recycling window
+//!
+//! The adapter keeps one row per visible item plus [`OVERSCAN`] rows above
+//! and below, so a short fling doesn't show empty space before the next
+//! `Scroll` event arrives. A 500-item list in a 200 px tall container with
+//! 40 px rows uses 9 row widgets.
+//!
+//! # Item height
+//!
+//! Every row has the same height, given when the adapter is created. Row
+//! positions and the scroll range are computed from it, so content taller
+//! than the row is clipped rather than pushing the following rows down.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use lvgl::list_adapter::ListAdapter;
+//!
+//! let names: Vec<CString> = (0..500)
+//!     .map(|i| CString::new(format!("Item {i}")).unwrap())
+//!     .collect();
+//! let list = ListAdapter::new(&screen, names, 40, |name, row| {
+//!     // Rows are reused, so drop what the previous item put there
+//!     row.clean();
+//!     Label::new(row).unwrap().set_text(name);
+//! })
+//! .unwrap();
+//! list.set_size(200, 200);
+//! ```
+
+use crate::event::EventHandler;
+use crate::scroll::ScrollExt;
+use crate::widgets::{Obj, Widget};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+/// Number of extra rows kept above and below the visible area
+pub const OVERSCAN: usize = 2;
+
+/// Row slot that isn't showing any item yet
+const UNBOUND: usize = usize::MAX;
+
+type RenderFn<T> = Box<dyn FnMut(&T, &Obj<'_>)>;
+
+struct State<T> {
+    items: Vec<T>,
+    render: RenderFn<T>,
+    item_height: i32,
+    /// Pool of row widgets and the item index each one shows
+    rows: Vec<(*mut neo_lvgl_sys::lv_obj_t, usize)>,
+    /// Invisible child that gives the container its full scroll range
+    spacer: *mut neo_lvgl_sys::lv_obj_t,
+}
+
+/// A scrollable list that renders only the visible items
+///
+/// See the [module documentation](self) for how rows are recycled.
+///
+/// The adapter's state is freed when the container is deleted; the handle
+/// must not be used after that.
+pub struct ListAdapter<'a, T> {
+    obj: Obj<'a>,
+    state: NonNull<RefCell<State<T>>>,
+}
+
+impl<'a, T: 'static> ListAdapter<'a, T> {
+    /// Create a list container as a child of `parent`
+    ///
+    /// `render` is called with an item and the row widget that should show
+    /// it. Rows are reused for other items, so `render` has to clean up or
+    /// update the children it created for the previous item. It must not
+    /// call back into the adapter.
+    pub fn new<F>(
+        parent: &'a impl Widget<'a>,
+        items: Vec<T>,
+        item_height: i32,
+        render: F,
+    ) -> Option<Self>
+    where
+        F: FnMut(&T, &Obj<'_>) + 'static,
+    {
+        let item_height = item_height.max(1);
+        unsafe {
+            let ptr = neo_lvgl_sys::lv_obj_create(parent.raw());
+            let obj = Obj::from_raw(ptr)?;
+
+            let spacer = neo_lvgl_sys::lv_obj_create(ptr);
+            if spacer.is_null() {
+                neo_lvgl_sys::lv_obj_delete(ptr);
+                return None;
+            }
+            neo_lvgl_sys::lv_obj_remove_style_all(spacer);
+            neo_lvgl_sys::lv_obj_remove_flag(
+                spacer,
+                neo_lvgl_sys::lv_obj_flag_t_LV_OBJ_FLAG_CLICKABLE,
+            );
+
+            let state = Box::new(RefCell::new(State {
+                items,
+                render: Box::new(render),
+                item_height,
+                rows: Vec::new(),
+                spacer,
+            }));
+            let state = NonNull::from(Box::leak(state));
+
+            for code in [
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_SCROLL,
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_SIZE_CHANGED,
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_DELETE,
+            ] {
+                neo_lvgl_sys::lv_obj_add_event_cb(
+                    ptr,
+                    Some(adapter_cb::<T>),
+                    code,
+                    state.as_ptr() as *mut c_void,
+                );
+            }
+
+            let adapter = Self { obj, state };
+            adapter.state().borrow_mut().resize_spacer();
+            adapter.sync();
+            Some(adapter)
+        }
+    }
+
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new<F>(
+        parent: &'a impl Widget<'a>,
+        items: Vec<T>,
+        item_height: i32,
+        render: F,
+    ) -> Result<Self, crate::LvError>
+    where
+        F: FnMut(&T, &Obj<'_>) + 'static,
+    {
+        Self::new(parent, items, item_height, render).ok_or(crate::LvError::OutOfMemory)
+    }
+
+    /// Replace all items and render the visible ones again
+    pub fn set_items(&self, items: Vec<T>) {
+        {
+            let mut state = self.state().borrow_mut();
+            state.items = items;
+            for row in state.rows.iter_mut() {
+                row.1 = UNBOUND;
+            }
+            state.resize_spacer();
+        }
+        self.sync();
+    }
+
+    /// Render the visible items again, e.g. after they were changed in place
+    pub fn refresh(&self) {
+        for row in self.state().borrow_mut().rows.iter_mut() {
+            row.1 = UNBOUND;
+        }
+        self.sync();
+    }
+
+    /// Change an item and render it again if it is visible
+    ///
+    /// Returns `false` if `index` is out of range.
+    pub fn update_item(&self, index: usize, f: impl FnOnce(&mut T)) -> bool {
+        let mut state = self.state().borrow_mut();
+        let Some(item) = state.items.get_mut(index) else {
+            return false;
+        };
+        f(item);
+        if let Some(row) = state.rows.iter_mut().find(|row| row.1 == index) {
+            row.1 = UNBOUND;
+        }
+        drop(state);
+        self.sync();
+        true
+    }
+
+    /// Get the number of items
+    pub fn len(&self) -> usize {
+        self.state().borrow().items.len()
+    }
+
+    /// Check whether the list has no items
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the number of row widgets currently alive
+    pub fn row_count(&self) -> usize {
+        self.state().borrow().rows.len()
+    }
+
+    /// Scroll so that item `index` is at the top
+    pub fn scroll_to_index(&self, index: usize, anim: bool) {
+        let y = index as i32 * self.state().borrow().item_height;
+        self.obj.scroll_to_y(y, anim);
+    }
+
+    fn state(&self) -> &RefCell<State<T>> {
+        unsafe { self.state.as_ref() }
+    }
+
+    fn sync(&self) {
+        unsafe { self.state().borrow_mut().sync(self.obj.raw()) };
+    }
+}
+
+impl<T> State<T> {
+    fn resize_spacer(&mut self) {
+        let height = self.items.len() as i32 * self.item_height;
+        unsafe { neo_lvgl_sys::lv_obj_set_size(self.spacer, 1, height.max(1)) };
+    }
+
+    /// Match the row pool to the visible area and bind rows to items
+    unsafe fn sync(&mut self, obj: *mut neo_lvgl_sys::lv_obj_t) {
+        let height = neo_lvgl_sys::lv_obj_get_content_height(obj).max(0);
+        let visible = (height + self.item_height - 1) / self.item_height;
+        let wanted = (visible as usize + 2 * OVERSCAN).min(self.items.len());
+
+        if self.rows.len() != wanted {
+            // Slots are assigned by index modulo the pool size, so resizing
+            // the pool invalidates every binding
+            while self.rows.len() > wanted {
+                let (row, _) = self.rows.pop().unwrap();
+                neo_lvgl_sys::lv_obj_delete(row);
+            }
+            while self.rows.len() < wanted {
+                let row = neo_lvgl_sys::lv_obj_create(obj);
+                if row.is_null() {
+                    break;
+                }
+                neo_lvgl_sys::lv_obj_remove_style_all(row);
+                neo_lvgl_sys::lv_obj_remove_flag(
+                    row,
+                    neo_lvgl_sys::lv_obj_flag_t_LV_OBJ_FLAG_SCROLLABLE,
+                );
+                neo_lvgl_sys::lv_obj_set_size(row, neo_lvgl_sys::lv_pct(100), self.item_height);
+                self.rows.push((row, UNBOUND));
+            }
+            for row in self.rows.iter_mut() {
+                row.1 = UNBOUND;
+            }
+        }
+
+        let pool = self.rows.len();
+        if pool == 0 {
+            return;
+        }
+        let scroll_y = Obj::from_raw(obj).map_or(0, |o| o.scroll_y());
+        let first = (scroll_y / self.item_height).max(0) as usize;
+        let first = first.saturating_sub(OVERSCAN).min(self.items.len() - pool);
+
+        for index in first..first + pool {
+            let (row, bound) = &mut self.rows[index % pool];
+            if *bound == index {
+                continue;
+            }
+            *bound = index;
+            neo_lvgl_sys::lv_obj_set_y(*row, index as i32 * self.item_height);
+            if let Some(row) = Obj::from_raw(*row) {
+                (self.render)(&self.items[index], &row);
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn adapter_cb<T>(e: *mut neo_lvgl_sys::lv_event_t) {
+    let state = neo_lvgl_sys::lv_event_get_user_data(e) as *mut RefCell<State<T>>;
+    let obj = neo_lvgl_sys::lv_event_get_current_target(e) as *mut neo_lvgl_sys::lv_obj_t;
+    match neo_lvgl_sys::lv_event_get_code(e) {
+        neo_lvgl_sys::lv_event_code_t_LV_EVENT_DELETE => {
+            // Deleting the rows can still raise events on the container
+            neo_lvgl_sys::lv_obj_remove_event_cb_with_user_data(
+                obj,
+                Some(adapter_cb::<T>),
+                state as *mut c_void,
+            );
+            drop(Box::from_raw(state));
+        }
+        _ => {
+            // Skip events raised by our own row updates
+            if let Ok(mut state) = (*state).try_borrow_mut() {
+                state.sync(obj);
+            }
+        }
+    }
+}
+
+impl<'a, T> Widget<'a> for ListAdapter<'a, T> {
+    fn obj(&self) -> &Obj<'a> {
+        &self.obj
+    }
+}
+
+impl<T> EventHandler for ListAdapter<'_, T> {
+    fn obj_raw(&self) -> *mut neo_lvgl_sys::lv_obj_t {
+        self.obj.raw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::layout::LayoutExt;
+    use core::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_long_list_recycles_rows() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+
+        let last = Rc::new(Cell::new(None));
+        let seen = last.clone();
+        let items: Vec<usize> = (0..500).collect();
+        let list = ListAdapter::new(&screen, items, 20, move |item, _row| {
+            seen.set(Some(*item));
+        })
+        .unwrap();
+        unsafe { neo_lvgl_sys::lv_obj_remove_style_all(list.raw()) };
+        list.set_size(100, 100);
+        list.update_layout();
+
+        // 5 visible rows plus the overscan on both sides
+        assert_eq!(list.row_count(), 5 + 2 * OVERSCAN);
+        assert_eq!(list.child_count() as usize, list.row_count() + 1);
+
+        list.scroll_to_index(200, false);
+        list.update_layout();
+        assert_eq!(list.row_count(), 5 + 2 * OVERSCAN);
+        assert!(matches!(last.get(), Some(200..=210)), "{:?}", last.get());
+    }
+}
@@ -0,0 +1,340 @@
+//! Capturing LVGL's log output
+//!
+//! LVGL reports problems such as a missing font or an invalid parameter
+//! through its log module. Devices without a console lose these messages,
+//! so [`RingLog`] keeps the last `N` lines in a fixed buffer that can be
+//! shown on a diagnostics screen.
+//!
+//! Only available with the `log` feature, which turns on LVGL's log module
+//! (`LV_USE_LOG`) in the bundled `lv_conf.h`; without it LVGL's log calls
+//! compile to nothing. Only messages at or above `LV_LOG_LEVEL` reach the
+//! log, and the bundled `lv_conf.h` passes warnings and errors.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use lvgl::log::RingLog;
+//!
+//! static LOG: RingLog<16> = RingLog::new();
+//! LOG.install();
+//!
+//! // Later, on the diagnostics screen
+//! for line in LOG.lines() {
+//!     let label = Label::new(&list).unwrap();
+//!     label.set_text(line.as_c_str());
+//! }
+//! ```
+
+use core::cell::UnsafeCell;
+use core::ffi::{c_char, c_void, CStr};
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+/// Maximum length of a captured line in bytes; longer lines are cut off
+pub const LOG_LINE_LEN: usize = 120;
+
+/// Severity of a log message
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// Detailed tracing
+    Trace,
+    /// Important events
+    Info,
+    /// Something unexpected that didn't cause a failure
+    Warn,
+    /// A failure
+    Error,
+    /// Messages printed with `lv_log`
+    User,
+}
+
+impl LogLevel {
+    fn from_raw(raw: neo_lvgl_sys::lv_log_level_t) -> Self {
+        match raw as u32 {
+            neo_lvgl_sys::LV_LOG_LEVEL_TRACE => LogLevel::Trace,
+            neo_lvgl_sys::LV_LOG_LEVEL_INFO => LogLevel::Info,
+            neo_lvgl_sys::LV_LOG_LEVEL_WARN => LogLevel::Warn,
+            neo_lvgl_sys::LV_LOG_LEVEL_ERROR => LogLevel::Error,
+            _ => LogLevel::User,
+        }
+    }
+}
+
+/// A captured log line
+#[derive(Clone, Copy)]
+pub struct LogLine {
+    level: LogLevel,
+    len: u8,
+    /// UTF-8 text followed by at least one nul byte
+    text: [u8; LOG_LINE_LEN + 1],
+}
+
+impl LogLine {
+    const EMPTY: Self = Self {
+        level: LogLevel::User,
+        len: 0,
+        text: [0; LOG_LINE_LEN + 1],
+    };
+
+    fn new(level: LogLevel, msg: &[u8]) -> Self {
+        let msg = match msg.iter().position(|&b| b == 0) {
+            Some(nul) => &msg[..nul],
+            None => msg,
+        };
+        let mut msg = msg.trim_ascii_end();
+        msg = &msg[..msg.len().min(LOG_LINE_LEN)];
+        // Drop a character cut in half, or anything after invalid UTF-8
+        if let Err(e) = core::str::from_utf8(msg) {
+            msg = &msg[..e.valid_up_to()];
+        }
+        let mut line = Self::EMPTY;
+        line.level = level;
+        line.len = msg.len() as u8;
+        line.text[..msg.len()].copy_from_slice(msg);
+        line
+    }
+
+    /// Get the severity of the message
+    pub fn level(&self) -> LogLevel {
+        self.level
+    }
+
+    /// Get the text without the trailing newline
+    pub fn text(&self) -> &str {
+        // SAFETY: `new` only keeps a valid UTF-8 prefix
+        unsafe { core::str::from_utf8_unchecked(&self.text[..self.len as usize]) }
+    }
+
+    /// Get the text as a C string, e.g. for a label
+    pub fn as_c_str(&self) -> &CStr {
+        // SAFETY: the text has no nul bytes and is followed by at least one
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.text[..=self.len as usize]) }
+    }
+}
+
+impl core::fmt::Debug for LogLine {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}: {:?}", self.level, self.text())
+    }
+}
+
+/// The log registered with LVGL, if any
+///
+/// Points to a [`RingLog`] of any size. Its first field is the matching
+/// [`PushFn`], so the pointer and the code that understands it are always
+/// swapped together.
+static INSTALLED: AtomicPtr<c_void> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Type-erased [`RingLog::push`]
+type PushFn = unsafe fn(*const c_void, LogLevel, &[u8]) -> bool;
+
+/// Fixed-size buffer of the last `N` LVGL log lines
+///
+/// The buffer lives inline, so a `static` log needs no allocator. A line
+/// that arrives while the log is being read, or while another line is being
+/// written (e.g. logging from an interrupt), is dropped rather than waited
+/// for, so the log callback never blocks.
+#[repr(C)]
+pub struct RingLog<const N: usize> {
+    /// Must stay first, see [`INSTALLED`]
+    push: PushFn,
+    lines: UnsafeCell<[LogLine; N]>,
+    /// Number of lines pushed since the last clear
+    written: AtomicUsize,
+    /// Set while a line is being written or copied out
+    busy: AtomicBool,
+}
+
+// SAFETY: `lines` is only accessed while holding `busy`
+unsafe impl<const N: usize> Sync for RingLog<N> {}
+
+impl<const N: usize> RingLog<N> {
+    /// Create an empty log
+    pub const fn new() -> Self {
+        Self {
+            push: push_erased::<N>,
+            lines: UnsafeCell::new([LogLine::EMPTY; N]),
+            written: AtomicUsize::new(0),
+            busy: AtomicBool::new(false),
+        }
+    }
+
+    /// Send LVGL's log output to this log
+    ///
+    /// LVGL has a single log callback, so this replaces any log installed
+    /// before.
+    pub fn install(&'static self) {
+        INSTALLED.store(self as *const Self as *mut c_void, Ordering::Release);
+        unsafe {
+            neo_lvgl_sys::lv_log_register_print_cb(Some(print_cb));
+        }
+    }
+
+    /// Stop capturing LVGL's log output, if this log is installed
+    pub fn uninstall(&self) {
+        let this = self as *const Self as *mut c_void;
+        let installed = INSTALLED.compare_exchange(
+            this,
+            core::ptr::null_mut(),
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        );
+        if installed.is_ok() {
+            unsafe {
+                neo_lvgl_sys::lv_log_register_print_cb(None);
+            }
+        }
+    }
+
+    /// Add a line, replacing the oldest one if the log is full
+    ///
+    /// Trailing whitespace is removed and the text is cut off after
+    /// [`LOG_LINE_LEN`] bytes. Returns `false` if the line was dropped
+    /// because the log was busy.
+    pub fn push(&self, level: LogLevel, msg: &[u8]) -> bool {
+        if N == 0 || self.busy.swap(true, Ordering::Acquire) {
+            return false;
+        }
+        let index = self.written.load(Ordering::Relaxed);
+        // SAFETY: `busy` is held
+        unsafe { (*self.lines.get())[index % N] = LogLine::new(level, msg) };
+        self.written.store(index + 1, Ordering::Relaxed);
+        self.busy.store(false, Ordering::Release);
+        true
+    }
+
+    /// Iterate over the stored lines, oldest first
+    ///
+    /// Each line is copied out, so lines pushed during the iteration don't
+    /// invalidate it; lines overwritten before they are reached are skipped.
+    pub fn lines(&self) -> Lines<'_, N> {
+        let end = self.written.load(Ordering::Acquire);
+        Lines {
+            log: self,
+            next: end.saturating_sub(N),
+            end,
+        }
+    }
+
+    /// Get the number of stored lines
+    pub fn len(&self) -> usize {
+        self.written.load(Ordering::Acquire).min(N)
+    }
+
+    /// Check whether no lines are stored
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the number of lines pushed since the last clear, including
+    /// those that were overwritten
+    pub fn total(&self) -> usize {
+        self.written.load(Ordering::Acquire)
+    }
+
+    /// Remove all lines
+    pub fn clear(&self) {
+        self.lock();
+        self.written.store(0, Ordering::Relaxed);
+        self.busy.store(false, Ordering::Release);
+    }
+
+    fn lock(&self) {
+        while self.busy.swap(true, Ordering::Acquire) {
+            core::hint::spin_loop();
+        }
+    }
+}
+
+impl<const N: usize> Default for RingLog<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the lines of a [`RingLog`], see [`RingLog::lines`]
+pub struct Lines<'a, const N: usize> {
+    log: &'a RingLog<N>,
+    next: usize,
+    end: usize,
+}
+
+impl<const N: usize> Iterator for Lines<'_, N> {
+    type Item = LogLine;
+
+    fn next(&mut self) -> Option<LogLine> {
+        self.log.lock();
+        let written = self.log.written.load(Ordering::Relaxed);
+        // Skip lines overwritten since the last step
+        self.next = self.next.max(written.saturating_sub(N));
+        let line = if self.next < self.end.min(written) {
+            // SAFETY: `busy` is held
+            let line = unsafe { (*self.log.lines.get())[self.next % N] };
+            self.next += 1;
+            Some(line)
+        } else {
+            None
+        };
+        self.log.busy.store(false, Ordering::Release);
+        line
+    }
+}
+
+unsafe fn push_erased<const N: usize>(log: *const c_void, level: LogLevel, msg: &[u8]) -> bool {
+    (*log.cast::<RingLog<N>>()).push(level, msg)
+}
+
+unsafe extern "C" fn print_cb(level: neo_lvgl_sys::lv_log_level_t, buf: *const c_char) {
+    let log = INSTALLED.load(Ordering::Acquire);
+    if log.is_null() || buf.is_null() {
+        return;
+    }
+    // SAFETY: `INSTALLED` only holds `RingLog`s, which start with their push
+    let push = *log.cast::<PushFn>();
+    push(log, LogLevel::from_raw(level), CStr::from_ptr(buf).to_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_ring_log_keeps_last_lines() {
+        let log = RingLog::<3>::new();
+        for i in 0..5u8 {
+            assert!(log.push(LogLevel::Warn, &[b'0' + i, b'\n']));
+        }
+        let lines: Vec<_> = log.lines().map(|l| l.text().as_bytes()[0]).collect();
+        assert_eq!(lines, b"234");
+        assert_eq!(log.len(), 3);
+        assert_eq!(log.total(), 5);
+
+        let long = [b'x'; LOG_LINE_LEN + 10];
+        log.push(LogLevel::Error, &long);
+        let last = log.lines().last().unwrap();
+        assert_eq!(last.level(), LogLevel::Error);
+        assert_eq!(last.text().len(), LOG_LINE_LEN);
+        assert_eq!(last.as_c_str().to_bytes().len(), LOG_LINE_LEN);
+
+        log.clear();
+        assert!(log.is_empty());
+        assert_eq!(log.lines().count(), 0);
+    }
+
+    #[test]
+    fn test_ring_log_captures_lvgl_output() {
+        static LOG: RingLog<4> = RingLog::new();
+
+        let _lvgl = crate::test_lvgl();
+
+        LOG.install();
+        unsafe { neo_lvgl_sys::lv_log(c"hello from lvgl\n".as_ptr()) };
+        LOG.uninstall();
+        unsafe { neo_lvgl_sys::lv_log(c"not captured\n".as_ptr()) };
+
+        let line = LOG.lines().next().unwrap();
+        assert_eq!(line.level(), LogLevel::User);
+        assert_eq!(line.text(), "hello from lvgl");
+        assert_eq!(LOG.len(), 1);
+    }
+}
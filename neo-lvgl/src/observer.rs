@@ -49,9 +49,20 @@ impl Observer {
     }
 
     /// Remove this observer (stop receiving updates)
+    ///
+    /// The closure of a widget binding such as
+    /// [`ObserverBindExt::bind_int_with`] is freed as well.
     pub fn remove(self) {
         unsafe {
+            #[cfg(feature = "alloc")]
+            let target = neo_lvgl_sys::lv_observer_get_target_obj(self.raw.as_ptr());
+            #[cfg(feature = "alloc")]
+            let user_data = self.user_data();
             neo_lvgl_sys::lv_observer_remove(self.raw.as_ptr());
+            #[cfg(feature = "alloc")]
+            closure_support::free_widget_callback(target, user_data);
+            #[cfg(feature = "alloc")]
+            crate::widgets::label::free_removed_text_format(target, user_data);
         }
         // Don't run Drop since we already removed it
         core::mem::forget(self);
@@ -80,8 +91,32 @@ pub trait Subject {
 ///
 /// Use this to create reactive integer data that can be bound to widgets
 /// like sliders, bars, arcs, etc.
+///
+/// # Batching updates
+///
+/// Every [`set`](Self::set) notifies all observers, which redraws every
+/// bound widget. For high-frequency sources such as sensors, use
+/// [`set_deferred`](Self::set_deferred) instead: it only stores the value,
+/// and observers are notified once with the latest value on the next timer
+/// run, or after the window set with
+/// [`notify_rate_limit`](Self::notify_rate_limit). The trade-off is latency:
+/// widgets lag the source by up to one timer period (or the rate-limit
+/// window), and [`get`](Self::get) returns the last notified value until
+/// then.
 pub struct IntSubject {
     raw: neo_lvgl_sys::lv_subject_t,
+    deferred: *mut Deferred,
+    rate_limit_ms: u32,
+}
+
+/// A deferred value, shared by an [`IntSubject`] and its flush timer
+///
+/// Lives in LVGL's heap so the timer never points into the subject itself.
+struct Deferred {
+    /// Where the subject was at the last `set_deferred`
+    subject: *mut neo_lvgl_sys::lv_subject_t,
+    pending: Option<i32>,
+    timer: *mut neo_lvgl_sys::lv_timer_t,
 }
 
 impl IntSubject {
@@ -92,17 +127,97 @@ impl IntSubject {
             neo_lvgl_sys::lv_subject_init_int(raw.as_mut_ptr(), initial_value);
             Self {
                 raw: raw.assume_init(),
+                deferred: core::ptr::null_mut(),
+                rate_limit_ms: 0,
             }
         }
     }
 
     /// Set the current value (notifies all observers)
+    ///
+    /// Discards a value pending from [`set_deferred`](Self::set_deferred).
     pub fn set(&mut self, value: i32) {
+        if !self.deferred.is_null() {
+            unsafe { (*self.deferred).pending = None };
+        }
         unsafe {
             neo_lvgl_sys::lv_subject_set_int(&mut self.raw, value);
         }
     }
 
+    /// Store a new value and notify observers later, coalescing rapid updates
+    ///
+    /// Only the latest value set before the notification is delivered. The
+    /// notification happens on the next LVGL timer run after the rate-limit
+    /// window (0 ms by default), or earlier with [`flush`](Self::flush).
+    ///
+    /// The subject must not be moved while a value is pending, the same as
+    /// while observers are bound to it.
+    pub fn set_deferred(&mut self, value: i32) {
+        unsafe {
+            if self.deferred.is_null() {
+                let state = neo_lvgl_sys::lv_malloc(core::mem::size_of::<Deferred>());
+                if state.is_null() {
+                    // No memory to defer: fall back to notifying right away
+                    self.set(value);
+                    return;
+                }
+                self.deferred = state.cast();
+                self.deferred.write(Deferred {
+                    subject: core::ptr::null_mut(),
+                    pending: None,
+                    timer: core::ptr::null_mut(),
+                });
+            }
+
+            let deferred = &mut *self.deferred;
+            deferred.subject = &mut self.raw;
+            deferred.pending = Some(value);
+            if !deferred.timer.is_null() {
+                return;
+            }
+            let timer = neo_lvgl_sys::lv_timer_create(
+                Some(deferred_flush_cb),
+                self.rate_limit_ms,
+                self.deferred.cast(),
+            );
+            if timer.is_null() {
+                // No memory for the timer: fall back to notifying right away
+                self.flush();
+                return;
+            }
+            neo_lvgl_sys::lv_timer_set_repeat_count(timer, 1);
+            neo_lvgl_sys::lv_timer_set_auto_delete(timer, true);
+            deferred.timer = timer;
+        }
+    }
+
+    /// Notify observers of a pending [`set_deferred`](Self::set_deferred) value now
+    ///
+    /// Does nothing if no value is pending.
+    pub fn flush(&mut self) {
+        if self.deferred.is_null() {
+            return;
+        }
+        let deferred = unsafe { &mut *self.deferred };
+        if !deferred.timer.is_null() {
+            unsafe { neo_lvgl_sys::lv_timer_delete(deferred.timer) };
+            deferred.timer = core::ptr::null_mut();
+        }
+        if let Some(value) = deferred.pending.take() {
+            self.set(value);
+        }
+    }
+
+    /// Notify observers at most once per `ms` milliseconds for deferred values
+    ///
+    /// Applies to [`set_deferred`](Self::set_deferred): the first deferred
+    /// value opens a window of `ms`, and observers receive the latest value
+    /// when it closes. Larger windows mean fewer redraws but more lag.
+    pub fn notify_rate_limit(&mut self, ms: u32) {
+        self.rate_limit_ms = ms;
+    }
+
     /// Get the current value
     pub fn get(&mut self) -> i32 {
         unsafe { neo_lvgl_sys::lv_subject_get_int(&mut self.raw) }
@@ -134,9 +249,28 @@ impl Subject for IntSubject {
     }
 }
 
+/// Timer callback delivering a deferred value
+unsafe extern "C" fn deferred_flush_cb(timer: *mut neo_lvgl_sys::lv_timer_t) {
+    let deferred = neo_lvgl_sys::lv_timer_get_user_data(timer) as *mut Deferred;
+    if let Some(deferred) = deferred.as_mut() {
+        // The timer deletes itself after this run
+        deferred.timer = core::ptr::null_mut();
+        if let Some(value) = deferred.pending.take() {
+            neo_lvgl_sys::lv_subject_set_int(deferred.subject, value);
+        }
+    }
+}
+
 impl Drop for IntSubject {
     fn drop(&mut self) {
         unsafe {
+            if !self.deferred.is_null() {
+                let timer = (*self.deferred).timer;
+                if !timer.is_null() {
+                    neo_lvgl_sys::lv_timer_delete(timer);
+                }
+                neo_lvgl_sys::lv_free(self.deferred.cast());
+            }
             neo_lvgl_sys::lv_subject_deinit(&mut self.raw);
         }
     }
@@ -337,9 +471,300 @@ pub trait ObserverBindExt<'a>: crate::widgets::Widget<'a> {
             Observer::from_raw(ptr)
         }
     }
+
+    /// Update this widget from an integer subject with a closure
+    ///
+    /// `update` is called with the subject's value right away and on every
+    /// change. Use it when the value stands for one of a few states that
+    /// change the widget in ways no built-in binding covers, e.g. a color
+    /// or an icon per state.
+    ///
+    /// The closure is freed when the widget is deleted, which also ends the
+    /// subscription, or when the returned observer is
+    /// [removed](Observer::remove). Like [`IntSubject::bind_to`], `update`
+    /// must not set the subject.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// const IDLE: i32 = 0;
+    /// const RUNNING: i32 = 1;
+    /// const ERROR: i32 = 2;
+    ///
+    /// let mut state = IntSubject::new(IDLE);
+    /// led.bind_int_with(&mut state, move |value| match value {
+    ///     RUNNING => led.set_color(Color::hex(0x00C853)),
+    ///     ERROR => led.set_color(Color::hex(0xD50000)),
+    ///     _ => led.set_color(Color::hex(0x9E9E9E)),
+    /// });
+    ///
+    /// state.set(ERROR); // the LED turns red
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn bind_int_with<F>(&self, subject: &mut IntSubject, update: F) -> Option<Observer>
+    where
+        F: FnMut(i32) + 'static,
+    {
+        closure_support::bind_int_with(self.raw(), subject, update)
+    }
 }
 
 impl<'a, T: crate::widgets::Widget<'a>> ObserverBindExt<'a> for T {}
 
 // Widget-specific bindings are implemented in their respective modules
 // Here we provide the core observer infrastructure
+
+// Closure observers (requires alloc feature)
+#[cfg(feature = "alloc")]
+mod closure_support {
+    use super::*;
+    use alloc::boxed::Box;
+
+    type IntCallback = Box<dyn FnMut(i32)>;
+
+    /// Trampoline for closure observers on integer subjects
+    unsafe extern "C" fn int_trampoline(
+        observer: *mut neo_lvgl_sys::lv_observer_t,
+        subject: *mut neo_lvgl_sys::lv_subject_t,
+    ) {
+        let user_data = neo_lvgl_sys::lv_observer_get_user_data(observer);
+        if !user_data.is_null() {
+            let callback = &mut *(user_data as *mut IntCallback);
+            callback(neo_lvgl_sys::lv_subject_get_int(subject));
+        }
+    }
+
+    /// Free the closure of a widget binding when the widget is deleted
+    unsafe extern "C" fn free_int_callback(e: *mut neo_lvgl_sys::lv_event_t) {
+        let callback = neo_lvgl_sys::lv_event_get_user_data(e) as *mut IntCallback;
+        drop(Box::from_raw(callback));
+    }
+
+    /// Free the closure of a widget binding whose observer was removed
+    ///
+    /// Does nothing unless `user_data` was set up by [`bind_int_with`] on
+    /// `obj`, so it is safe to call for any removed observer.
+    pub(super) unsafe fn free_widget_callback(
+        obj: *mut neo_lvgl_sys::lv_obj_t,
+        user_data: *mut core::ffi::c_void,
+    ) {
+        if obj.is_null() || user_data.is_null() {
+            return;
+        }
+        let removed = neo_lvgl_sys::lv_obj_remove_event_cb_with_user_data(
+            obj,
+            Some(free_int_callback),
+            user_data,
+        );
+        if removed > 0 {
+            drop(Box::from_raw(user_data as *mut IntCallback));
+        }
+    }
+
+    /// Subscribe `update` to `subject` for as long as `obj` lives
+    pub(super) fn bind_int_with<F>(
+        obj: *mut neo_lvgl_sys::lv_obj_t,
+        subject: &mut IntSubject,
+        update: F,
+    ) -> Option<Observer>
+    where
+        F: FnMut(i32) + 'static,
+    {
+        let callback: IntCallback = Box::new(update);
+        let callback = Box::into_raw(Box::new(callback));
+
+        let ptr = unsafe {
+            neo_lvgl_sys::lv_subject_add_observer_obj(
+                subject.raw(),
+                Some(int_trampoline),
+                obj,
+                callback.cast(),
+            )
+        };
+        if ptr.is_null() {
+            drop(unsafe { Box::from_raw(callback) });
+            return None;
+        }
+
+        unsafe {
+            neo_lvgl_sys::lv_obj_add_event_cb(
+                obj,
+                Some(free_int_callback),
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_DELETE,
+                callback.cast(),
+            );
+            Observer::from_raw(ptr)
+        }
+    }
+
+    /// A closure subscribed to a subject
+    ///
+    /// Created by [`IntSubject::bind_to`]. Call [`remove`](Self::remove) to
+    /// unsubscribe and free the closure. Dropping the binding without
+    /// removing it keeps it active for the rest of the subject's life; the
+    /// closure is then leaked when the subject is dropped.
+    pub struct ObserverBinding {
+        observer: Observer,
+        callback: *mut IntCallback,
+    }
+
+    impl ObserverBinding {
+        /// Get the underlying observer
+        pub fn observer(&self) -> &Observer {
+            &self.observer
+        }
+
+        /// Unsubscribe and free the closure (and the target it owns)
+        ///
+        /// Must be called while the subject is still alive.
+        pub fn remove(self) {
+            self.observer.remove();
+            drop(unsafe { Box::from_raw(self.callback) });
+        }
+    }
+
+    impl IntSubject {
+        /// Keep `target` in sync with this subject by running `setter` on each change
+        ///
+        /// Unlike the widget bindings, `target` can be any state, e.g. a PWM
+        /// channel driving the backlight. The binding takes ownership of it.
+        /// `setter` runs once immediately with the current value and then
+        /// after every change.
+        ///
+        /// # Re-entrancy
+        ///
+        /// `setter` runs inside the subject's notification, so it must not
+        /// call `set` on this subject (that would notify recursively) and
+        /// should not add or remove observers of it.
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// let mut brightness = IntSubject::new(80);
+        /// let binding = brightness.bind_to(backlight_pwm, |pwm, percent| {
+        ///     pwm.set_duty(percent as u16 * 10);
+        /// });
+        /// brightness.set(40); // duty updated
+        /// binding.unwrap().remove();
+        /// ```
+        pub fn bind_to<T, F>(&mut self, mut target: T, mut setter: F) -> Option<ObserverBinding>
+        where
+            T: 'static,
+            F: FnMut(&mut T, i32) + 'static,
+        {
+            let callback: IntCallback = Box::new(move |value| setter(&mut target, value));
+            let callback = Box::into_raw(Box::new(callback));
+
+            let ptr = unsafe {
+                neo_lvgl_sys::lv_subject_add_observer(
+                    self.raw(),
+                    Some(int_trampoline),
+                    callback as *mut core::ffi::c_void,
+                )
+            };
+            match unsafe { Observer::from_raw(ptr) } {
+                Some(observer) => Some(ObserverBinding { observer, callback }),
+                None => {
+                    drop(unsafe { Box::from_raw(callback) });
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use closure_support::ObserverBinding;
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::widgets::{Obj, Widget};
+    use std::boxed::Box;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_set_deferred_coalesces() {
+        let _lvgl = crate::test_lvgl();
+
+        let mut state = IntSubject::new(0);
+        state.set_deferred(1);
+        state.set_deferred(2);
+        assert_eq!(state.get(), 0);
+        crate::task_handler();
+        assert_eq!(state.get(), 2);
+
+        // A moved subject defers through its new address
+        let mut moved = Box::new(state);
+        moved.set_deferred(3);
+        crate::task_handler();
+        assert_eq!(moved.get(), 3);
+
+        // Dropping with a value pending cancels the timer
+        moved.set_deferred(4);
+        drop(moved);
+        crate::task_handler();
+    }
+
+    #[test]
+    fn test_bind_int_with_frees_on_delete() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Obj::new(&screen).unwrap();
+        let mut state = IntSubject::new(0);
+
+        let seen = Rc::new(Cell::new(-1));
+        let seen_cb = seen.clone();
+        let observer = panel.bind_int_with(&mut state, move |v| seen_cb.set(v));
+        assert!(observer.is_some());
+        assert_eq!(seen.get(), 0);
+
+        state.set(2);
+        assert_eq!(seen.get(), 2);
+
+        // Deleting the widget unsubscribes and drops the closure
+        unsafe { panel.delete() };
+        assert_eq!(Rc::strong_count(&seen), 1);
+        state.set(1);
+        assert_eq!(seen.get(), 2);
+    }
+
+    #[test]
+    fn test_remove_frees_closure() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Obj::new(&screen).unwrap();
+        let label = crate::widgets::Label::new(&screen).unwrap();
+        let mut state = IntSubject::new(0);
+
+        let seen = Rc::new(Cell::new(-1));
+        let seen_cb = seen.clone();
+        let observer = panel.bind_int_with(&mut state, move |v| seen_cb.set(v)).unwrap();
+        let counter = seen.clone();
+        let text = label
+            .bind_text_with(&mut state, move |v| {
+                let _ = &counter;
+                alloc::ffi::CString::new(alloc::format!("#{v}")).unwrap()
+            })
+            .unwrap();
+        assert_eq!(Rc::strong_count(&seen), 3);
+
+        observer.remove();
+        text.remove();
+        assert_eq!(Rc::strong_count(&seen), 1);
+        state.set(1);
+        assert_eq!(seen.get(), 0);
+
+        // The widgets no longer hold the freed closures
+        unsafe {
+            panel.delete();
+            label.delete();
+        }
+    }
+}
@@ -14,14 +14,18 @@
 //! ```
 
 // Core types
+pub use crate::cstr;
+pub use crate::LvError;
 pub use crate::color::Color;
-pub use crate::display::{Area, ColorFormat, Display, DisplayDriver, RenderMode};
+pub use crate::display::{Area, ColorFormat, Display, DisplayDriver, DisplayRotation, RenderMode};
 #[cfg(feature = "alloc")]
 pub use crate::display::ManagedDisplay;
-pub use crate::style::{Style, StyleSelector};
+pub use crate::style::{Style, StyleProp, StyleSelector, StyleValue};
+#[cfg(feature = "alloc")]
+pub use crate::style::SharedStyle;
 
 // Widgets
-pub use crate::widgets::{Obj, Widget};
+pub use crate::widgets::{pct, Obj, Widget};
 pub use crate::widgets::{Button, Label};
 
 // Events
@@ -47,7 +51,7 @@ pub use crate::anim::{Anim, AnimHandle, AnimPath, RepeatCount};
 pub use crate::observer::{IntSubject, Observer, Subject};
 
 // Input
-pub use crate::indev::{Indev, IndevState, IndevType, Key};
+pub use crate::indev::{GestureDir, Indev, IndevState, IndevType, Key};
 
 // Groups
 pub use crate::group::Group;
@@ -0,0 +1,213 @@
+//! Screen navigation
+//!
+//! A [`Navigator`] keeps a stack of [`Screen`]s for simple push/pop
+//! navigation, without the lifecycle callbacks of the
+//! [fragment](crate::fragment) system.
+//!
+//! # Ownership
+//!
+//! Screens pushed onto a navigator belong to it. With auto delete on (the
+//! default), a popped screen is deleted as soon as the screen below it has
+//! finished loading, so handles to the popped screen and its widgets must
+//! not be used after [`pop`](Navigator::pop). With auto delete off, popped
+//! screens stay alive and the caller is responsible for deleting them or
+//! pushing them again.
+//!
+//! Dropping the navigator doesn't delete any screen.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use lvgl::screen::Navigator;
+//!
+//! let mut nav = Navigator::with_root(lvgl::active_screen().unwrap());
+//!
+//! let settings = Screen::new().unwrap();
+//! Label::new(&settings).unwrap().set_text(c"Settings");
+//! nav.push(settings, ScreenLoadAnim::MoveLeft, 300);
+//!
+//! // Back button
+//! nav.pop(ScreenLoadAnim::MoveRight, 300);
+//! ```
+
+use crate::widgets::{Screen, ScreenLoadAnim, Widget};
+use alloc::vec::Vec;
+
+/// A stack of screens with animated push and pop
+pub struct Navigator {
+    stack: Vec<Screen<'static>>,
+    auto_delete: bool,
+}
+
+impl Navigator {
+    /// Create an empty navigator
+    ///
+    /// The first screen pushed becomes the root.
+    pub fn new() -> Self {
+        Self {
+            stack: Vec::new(),
+            auto_delete: true,
+        }
+    }
+
+    /// Create a navigator whose root is `root`, usually the active screen
+    ///
+    /// `root` is not loaded.
+    pub fn with_root(root: Screen<'static>) -> Self {
+        let mut nav = Self::new();
+        nav.stack.push(root);
+        nav
+    }
+
+    /// Set whether popped screens are deleted (default `true`)
+    ///
+    /// See the [module documentation](self) for ownership.
+    pub fn set_auto_delete(&mut self, auto_delete: bool) {
+        self.auto_delete = auto_delete;
+    }
+
+    /// Load `screen` on top of the stack
+    ///
+    /// The screen below stays alive so it can be shown again by
+    /// [`pop`](Self::pop).
+    pub fn push(&mut self, screen: Screen<'static>, anim: ScreenLoadAnim, time_ms: u32) {
+        screen.load_anim(anim, time_ms, 0, false);
+        self.stack.push(screen);
+    }
+
+    /// Go back to the previous screen
+    ///
+    /// Does nothing if only the root is left. With auto delete off, returns
+    /// the removed screen, which the caller now owns. With auto delete on,
+    /// the screen is deleted once the previous one has loaded, so no handle
+    /// is returned; compare [`depth`](Self::depth) to see whether a screen
+    /// was popped.
+    pub fn pop(&mut self, anim: ScreenLoadAnim, time_ms: u32) -> Option<Screen<'static>> {
+        if self.stack.len() < 2 {
+            return None;
+        }
+        let popped = self.stack.pop();
+        if let Some(previous) = self.stack.last() {
+            previous.load_anim(anim, time_ms, 0, self.auto_delete);
+        }
+        popped.filter(|_| !self.auto_delete)
+    }
+
+    /// Go back to the root screen, removing every screen above it
+    ///
+    /// Only the top screen is animated away; the ones in between are
+    /// deleted right away if auto delete is on. Like [`pop`](Self::pop),
+    /// the removed screens are only returned with auto delete off, from the
+    /// bottom of the stack up.
+    pub fn pop_to_root(&mut self, anim: ScreenLoadAnim, time_ms: u32) -> Vec<Screen<'static>> {
+        if self.stack.len() < 2 {
+            return Vec::new();
+        }
+        let mut removed: Vec<_> = self.stack.drain(1..self.stack.len() - 1).collect();
+        if self.auto_delete {
+            for screen in removed.drain(..) {
+                unsafe { neo_lvgl_sys::lv_obj_delete(screen.raw()) };
+            }
+        }
+        removed.extend(self.pop(anim, time_ms));
+        removed
+    }
+
+    /// Get the screen on top of the stack
+    pub fn current(&self) -> Option<Screen<'static>> {
+        self.stack.last().copied()
+    }
+
+    /// Get the number of screens on the stack, including the root
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Check whether nothing has been pushed yet
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+}
+
+impl Default for Navigator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+
+    fn active() -> *mut neo_lvgl_sys::lv_obj_t {
+        unsafe { neo_lvgl_sys::lv_screen_active() }
+    }
+
+    #[test]
+    fn test_push_and_pop() {
+        let _lvgl = crate::test_lvgl();
+
+        let _display = Display::new(320, 240).unwrap();
+        let root = crate::active_screen().unwrap();
+        let mut nav = Navigator::with_root(root);
+        assert!(nav.pop(ScreenLoadAnim::None, 0).is_none());
+        assert!(nav.pop_to_root(ScreenLoadAnim::None, 0).is_empty());
+
+        let first = Screen::new().unwrap();
+        let second = Screen::new().unwrap();
+        let (first_raw, second_raw) = (first.raw(), second.raw());
+        nav.push(first, ScreenLoadAnim::None, 0);
+        nav.push(second, ScreenLoadAnim::None, 0);
+        assert_eq!(nav.depth(), 3);
+        assert_eq!(active(), second_raw);
+
+        // Auto delete on: the popped screens are gone, no handles come back
+        assert!(nav.pop(ScreenLoadAnim::None, 0).is_none());
+        assert_eq!(nav.depth(), 2);
+        assert_eq!(active(), first_raw);
+        assert!(!unsafe { neo_lvgl_sys::lv_obj_is_valid(second_raw) });
+
+        let third = Screen::new().unwrap();
+        let third_raw = third.raw();
+        nav.push(third, ScreenLoadAnim::None, 0);
+        assert!(nav.pop_to_root(ScreenLoadAnim::None, 0).is_empty());
+        assert_eq!(nav.depth(), 1);
+        assert_eq!(active(), root.raw());
+        assert!(!unsafe { neo_lvgl_sys::lv_obj_is_valid(third_raw) });
+        assert!(!unsafe { neo_lvgl_sys::lv_obj_is_valid(first_raw) });
+    }
+
+    #[test]
+    fn test_pop_returns_screens_without_auto_delete() {
+        let _lvgl = crate::test_lvgl();
+
+        let _display = Display::new(320, 240).unwrap();
+        let root = crate::active_screen().unwrap();
+        let mut nav = Navigator::with_root(root);
+        nav.set_auto_delete(false);
+
+        let first = Screen::new().unwrap();
+        let second = Screen::new().unwrap();
+        let third = Screen::new().unwrap();
+        nav.push(first, ScreenLoadAnim::None, 0);
+        nav.push(second, ScreenLoadAnim::None, 0);
+
+        let popped = nav.pop(ScreenLoadAnim::None, 0).unwrap();
+        assert_eq!(popped.raw(), second.raw());
+        assert!(unsafe { neo_lvgl_sys::lv_obj_is_valid(second.raw()) });
+
+        nav.push(third, ScreenLoadAnim::None, 0);
+        let removed: Vec<_> = nav
+            .pop_to_root(ScreenLoadAnim::None, 0)
+            .iter()
+            .map(|screen| screen.raw())
+            .collect();
+        assert_eq!(removed, [first.raw(), third.raw()]);
+        assert_eq!(active(), root.raw());
+        for screen in [first, second, third] {
+            assert!(unsafe { neo_lvgl_sys::lv_obj_is_valid(screen.raw()) });
+            unsafe { screen.delete() };
+        }
+    }
+}
@@ -26,7 +26,9 @@
 //! }
 //! ```
 
+use crate::color::{Color, Opacity};
 use crate::layout::Direction;
+use crate::style::StyleSelector;
 use crate::widgets::Point;
 
 /// Scrollbar visibility mode
@@ -108,6 +110,29 @@ pub trait ScrollExt<'a>: crate::widgets::Widget<'a> {
         }
     }
 
+    /// Set the scrollbar color (local style on `StyleSelector::SCROLLBAR`)
+    ///
+    /// Also makes the scrollbar fully opaque, since themes often draw it
+    /// semi-transparent.
+    fn set_scrollbar_color(&self, color: Color) {
+        let part = StyleSelector::SCROLLBAR.bits();
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_bg_color(self.raw(), color.raw(), part);
+            neo_lvgl_sys::lv_obj_set_style_bg_opa(self.raw(), Opacity::COVER.raw(), part);
+        }
+    }
+
+    /// Set the scrollbar thickness in pixels (local style on `StyleSelector::SCROLLBAR`)
+    fn set_scrollbar_width(&self, width: i32) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_width(
+                self.raw(),
+                width,
+                StyleSelector::SCROLLBAR.bits(),
+            );
+        }
+    }
+
     /// Get the current scrollbar mode
     fn scrollbar_mode(&self) -> ScrollbarMode {
         unsafe { ScrollbarMode::from_raw(neo_lvgl_sys::lv_obj_get_scrollbar_mode(self.raw())) }
@@ -125,6 +150,42 @@ pub trait ScrollExt<'a>: crate::widgets::Widget<'a> {
         unsafe { Direction::from_raw(neo_lvgl_sys::lv_obj_get_scroll_dir(self.raw())) }
     }
 
+    /// Set whether the content can be dragged past its ends
+    ///
+    /// With elastic scrolling (the default), dragging beyond the first or
+    /// last item moves the content by a fraction of the drag and it springs
+    /// back on release. Turn it off for paginated content that should stop
+    /// exactly at its edges.
+    fn set_scroll_elastic(&self, elastic: bool) {
+        if elastic {
+            self.add_flag(crate::widgets::Flag::SCROLL_ELASTIC);
+        } else {
+            self.remove_flag(crate::widgets::Flag::SCROLL_ELASTIC);
+        }
+    }
+
+    /// Check whether the content can be dragged past its ends
+    fn is_scroll_elastic(&self) -> bool {
+        self.has_flag(crate::widgets::Flag::SCROLL_ELASTIC)
+    }
+
+    /// Set whether scrolling continues after a quick drag is released
+    ///
+    /// Enabled by default. Without momentum, scrolling stops where the
+    /// finger is lifted.
+    fn set_scroll_momentum(&self, momentum: bool) {
+        if momentum {
+            self.add_flag(crate::widgets::Flag::SCROLL_MOMENTUM);
+        } else {
+            self.remove_flag(crate::widgets::Flag::SCROLL_MOMENTUM);
+        }
+    }
+
+    /// Check whether scrolling continues after a quick drag is released
+    fn has_scroll_momentum(&self) -> bool {
+        self.has_flag(crate::widgets::Flag::SCROLL_MOMENTUM)
+    }
+
     /// Set horizontal scroll snapping behavior
     fn set_scroll_snap_x(&self, snap: ScrollSnap) {
         unsafe {
@@ -196,6 +257,20 @@ pub trait ScrollExt<'a>: crate::widgets::Widget<'a> {
         }
     }
 
+    /// Scroll by a given amount, stopping at the ends of the content
+    ///
+    /// Like [`scroll_by`](Self::scroll_by), but the result is clamped to the
+    /// scrollable range, so the content never ends up past its edges, e.g.
+    /// when paging by a fixed step near the end.
+    ///
+    /// As with `scroll_by`, the content moves by (`dx`, `dy`): a negative
+    /// `dy` reveals content further down.
+    fn scroll_by_bounded(&self, dx: i32, dy: i32, anim: bool) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_scroll_by_bounded(self.raw(), dx, dy, anim);
+        }
+    }
+
     /// Scroll to an absolute position
     ///
     /// # Arguments
@@ -240,6 +315,40 @@ pub trait ScrollExt<'a>: crate::widgets::Widget<'a> {
         }
     }
 
+    /// Scroll this container so that `child` becomes visible
+    ///
+    /// `child` may be nested: every container between it and this widget is
+    /// scrolled as needed, but nothing above this widget is. Returns `false`
+    /// (and does nothing) if `child` is not a descendant of this widget.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Select a search hit in a long list
+    /// list.scroll_to_child(&items[hit], true);
+    /// ```
+    fn scroll_to_child<'b>(&self, child: &impl crate::widgets::Widget<'b>, anim: bool) -> bool {
+        let container = self.raw();
+        unsafe {
+            let mut obj = child.raw();
+            let mut parent = neo_lvgl_sys::lv_obj_get_parent(obj);
+            while !parent.is_null() && parent != container {
+                obj = parent;
+                parent = neo_lvgl_sys::lv_obj_get_parent(obj);
+            }
+            if parent.is_null() {
+                return false;
+            }
+
+            obj = child.raw();
+            while obj != container {
+                neo_lvgl_sys::lv_obj_scroll_to_view(obj, anim);
+                obj = neo_lvgl_sys::lv_obj_get_parent(obj);
+            }
+        }
+        true
+    }
+
     // === Scroll state ===
 
     /// Check if the widget is currently being scrolled
@@ -268,6 +377,22 @@ pub trait ScrollExt<'a>: crate::widgets::Widget<'a> {
         }
     }
 
+    /// Re-align a snapping container after its children changed
+    ///
+    /// Snap points are only applied when a scroll ends, so after adding,
+    /// removing or resizing children the container can rest between two
+    /// items. Call this once after such changes: it updates the layout,
+    /// clamps the scroll position to the new content size, scrolls to the
+    /// nearest snap point and redraws the scrollbars.
+    fn refresh_snap(&self, anim: bool) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_update_layout(self.raw());
+        }
+        self.readjust_scroll(anim);
+        self.update_snap(anim);
+        self.scrollbar_invalidate();
+    }
+
     /// Invalidate (redraw) the scrollbars
     fn scrollbar_invalidate(&self) {
         unsafe {
@@ -287,3 +412,125 @@ pub trait ScrollExt<'a>: crate::widgets::Widget<'a> {
 
 // Implement ScrollExt for all Widget types
 impl<'a, T: crate::widgets::Widget<'a>> ScrollExt<'a> for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::layout::LayoutExt;
+    use crate::widgets::{Button, Container, Widget};
+
+    #[test]
+    fn test_scrollbar_style_targets_scrollbar_part() {
+        use crate::style::StyleProp;
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        panel.set_size(100, 100);
+        let main_width = panel.style_prop(StyleProp::Width, StyleSelector::MAIN).as_int();
+
+        panel.set_scrollbar_color(Color::hex(0xFF0000));
+        panel.set_scrollbar_width(6);
+
+        let bar = StyleSelector::SCROLLBAR;
+        let color = panel.style_prop(StyleProp::BgColor, bar).as_color().unwrap();
+        assert_eq!(color.to_rgb888(), (0xFF, 0, 0));
+        assert!(panel.style_prop(StyleProp::BgOpa, bar).as_opa() == Some(Opacity::COVER));
+        assert_eq!(panel.style_prop(StyleProp::Width, bar).as_int(), Some(6));
+
+        // The main part is left alone
+        let main_color = panel.style_prop(StyleProp::BgColor, StyleSelector::MAIN);
+        assert_ne!(main_color.as_color().unwrap().to_rgb888(), (0xFF, 0, 0));
+        let width = panel.style_prop(StyleProp::Width, StyleSelector::MAIN);
+        assert_eq!(width.as_int(), main_width);
+    }
+
+    #[test]
+    fn test_scroll_to_child_makes_item_visible() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let list = Container::new(&screen).unwrap().column();
+        list.set_size(100, 100);
+        let items: [Button; 10] = core::array::from_fn(|_| {
+            let item = Button::new(&list).unwrap();
+            item.set_size(80, 40);
+            item
+        });
+        list.update_layout();
+
+        let last = &items[9];
+        assert!(!unsafe { neo_lvgl_sys::lv_obj_is_visible(last.raw()) });
+
+        assert!(list.scroll_to_child(last, false));
+        assert!(list.scroll_y() > 0);
+        assert!(unsafe { neo_lvgl_sys::lv_obj_is_visible(last.raw()) });
+
+        assert!(!items[0].scroll_to_child(last, false));
+    }
+
+    #[test]
+    fn test_refresh_snap_after_inserting_item() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let carousel = Container::new(&screen).unwrap().row();
+        carousel.set_size(100, 50);
+        carousel.add_flag(crate::widgets::Flag::SCROLLABLE);
+        carousel.set_scroll_snap_x(ScrollSnap::Start);
+        for _ in 0..3 {
+            Container::new(&carousel).unwrap().set_size(100, 50);
+        }
+        carousel.update_layout();
+        carousel.scroll_to_x(100, false);
+        assert_eq!(carousel.scroll_x(), 100);
+
+        // A narrow item in front leaves the view between two items
+        let first = Container::new(&carousel).unwrap();
+        first.set_size(30, 50);
+        unsafe { neo_lvgl_sys::lv_obj_move_to_index(first.raw(), 0) };
+        carousel.update_layout();
+        assert_eq!(carousel.scroll_x(), 100);
+
+        carousel.refresh_snap(false);
+        assert_eq!(carousel.scroll_x(), 130);
+    }
+
+    #[test]
+    fn test_scroll_by_bounded_clamps() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let pages = Container::new(&screen).unwrap().column();
+        pages.set_size(100, 100);
+        pages.add_flag(crate::widgets::Flag::SCROLLABLE);
+        for _ in 0..5 {
+            Button::new(&pages).unwrap().set_size(80, 80);
+        }
+        pages.update_layout();
+        let max = pages.scroll_bottom();
+        assert!(max > 0);
+
+        pages.scroll_by_bounded(0, -100_000, false);
+        assert_eq!(pages.scroll_y(), max);
+        assert_eq!(pages.scroll_bottom(), 0);
+        pages.scroll_by_bounded(0, 100_000, false);
+        assert_eq!(pages.scroll_y(), 0);
+
+        // The unbounded version overshoots
+        pages.scroll_by(0, -100_000, false);
+        assert!(pages.scroll_y() > max);
+
+        assert!(pages.is_scroll_elastic());
+        pages.set_scroll_elastic(false);
+        pages.set_scroll_momentum(false);
+        assert!(!pages.is_scroll_elastic());
+        assert!(!pages.has_scroll_momentum());
+    }
+}
@@ -83,6 +83,121 @@ impl Style {
         }
     }
 
+    /// Set background gradient end opacity
+    pub fn set_bg_grad_opa(&mut self, opa: Opacity) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_bg_grad_opa(&mut self.raw, opa.raw());
+        }
+    }
+
+    /// Set where the gradient starts (0-255 along the gradient direction)
+    ///
+    /// The area before the stop is filled with the background color.
+    pub fn set_bg_main_stop(&mut self, stop: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_bg_main_stop(&mut self.raw, stop);
+        }
+    }
+
+    /// Set where the gradient ends (0-255 along the gradient direction)
+    ///
+    /// The area after the stop is filled with the gradient color.
+    pub fn set_bg_grad_stop(&mut self, stop: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_bg_grad_stop(&mut self.raw, stop);
+        }
+    }
+
+    /// Set a multi-stop background gradient
+    ///
+    /// Takes precedence over the two-color gradient set with
+    /// [`set_bg_grad_color`](Self::set_bg_grad_color) and
+    /// [`set_bg_grad_dir`](Self::set_bg_grad_dir).
+    pub fn set_bg_grad(&mut self, grad: &'static Gradient) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_bg_grad(&mut self.raw, grad.raw());
+        }
+    }
+
+    // Background image properties
+
+    /// Set background image source
+    ///
+    /// An `A8` mask is drawn with the color set by
+    /// [`set_bg_image_recolor`](Self::set_bg_image_recolor).
+    pub fn set_bg_image_src(&mut self, dsc: &'static crate::widgets::ImageDsc) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_bg_image_src(&mut self.raw, dsc.raw().cast());
+        }
+    }
+
+    /// Set background image recolor
+    pub fn set_bg_image_recolor(&mut self, color: Color) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_bg_image_recolor(&mut self.raw, color.raw());
+        }
+    }
+
+    /// Set background image recolor opacity
+    pub fn set_bg_image_recolor_opa(&mut self, opa: Opacity) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_bg_image_recolor_opa(&mut self.raw, opa.to_raw());
+        }
+    }
+
+    // Image properties
+
+    /// Set the color used to tint images
+    ///
+    /// Applies to whatever the widget draws as an image: an `Image`, the
+    /// parts of an `ImageButton`, and so on. Image properties aren't
+    /// inherited, so add the style to the icon itself rather than to its
+    /// parent button. Symbol fonts are text and follow the text color
+    /// instead. The tint only shows with a non-zero
+    /// [`set_image_recolor_opa`](Self::set_image_recolor_opa).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Highlight the icon of the active tab
+    /// let mut active = Style::new();
+    /// active.set_image_recolor(Color::hex(0x2196F3));
+    /// active.set_image_recolor_opa(Opacity::COVER);
+    /// icon.add_style(&active, StyleSelector::CHECKED);
+    /// ```
+    pub fn set_image_recolor(&mut self, color: Color) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_image_recolor(&mut self.raw, color.raw());
+        }
+    }
+
+    /// Set how strongly the image recolor is mixed in
+    ///
+    /// `Opacity::COVER` replaces the image colors entirely, which suits
+    /// monochrome icons.
+    pub fn set_image_recolor_opa(&mut self, opa: Opacity) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_image_recolor_opa(&mut self.raw, opa.to_raw());
+        }
+    }
+
+    /// Set the opacity of images
+    pub fn set_image_opa(&mut self, opa: Opacity) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_image_opa(&mut self.raw, opa.to_raw());
+        }
+    }
+
     // Border properties
 
     /// Set border color
@@ -130,6 +245,17 @@ impl Style {
         }
     }
 
+    /// Clip children to the rounded corners set by [`set_radius`](Self::set_radius)
+    ///
+    /// Drawing the widget through an extra layer makes this slower, so only
+    /// enable it where children actually reach the corners.
+    pub fn set_clip_corner(&mut self, clip: bool) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_clip_corner(&mut self.raw, clip);
+        }
+    }
+
     // Padding
 
     /// Set all padding values
@@ -200,6 +326,51 @@ impl Style {
         }
     }
 
+    // Margin
+
+    /// Set all margin values
+    ///
+    /// Margins add space outside the widget and are respected by flex and
+    /// grid layouts when placing the widget among its siblings.
+    pub fn set_margin_all(&mut self, margin: i32) {
+        self.set_margin_top(margin);
+        self.set_margin_bottom(margin);
+        self.set_margin_left(margin);
+        self.set_margin_right(margin);
+    }
+
+    /// Set top margin
+    pub fn set_margin_top(&mut self, margin: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_margin_top(&mut self.raw, margin);
+        }
+    }
+
+    /// Set bottom margin
+    pub fn set_margin_bottom(&mut self, margin: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_margin_bottom(&mut self.raw, margin);
+        }
+    }
+
+    /// Set left margin
+    pub fn set_margin_left(&mut self, margin: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_margin_left(&mut self.raw, margin);
+        }
+    }
+
+    /// Set right margin
+    pub fn set_margin_right(&mut self, margin: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_margin_right(&mut self.raw, margin);
+        }
+    }
+
     // Size
 
     /// Set width
@@ -376,6 +547,44 @@ impl Style {
         }
     }
 
+    // Arc and line
+
+    /// Set arc width
+    pub fn set_arc_width(&mut self, width: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_arc_width(&mut self.raw, width);
+        }
+    }
+
+    /// Set whether arc ends are rounded
+    ///
+    /// Rounded ends are drawn anti-aliased when the display has
+    /// anti-aliasing enabled (see `Display::set_antialiasing`); square ends
+    /// give crisp edges.
+    pub fn set_arc_rounded(&mut self, rounded: bool) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_arc_rounded(&mut self.raw, rounded);
+        }
+    }
+
+    /// Set line width
+    pub fn set_line_width(&mut self, width: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_line_width(&mut self.raw, width);
+        }
+    }
+
+    /// Set whether line ends are rounded
+    pub fn set_line_rounded(&mut self, rounded: bool) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_line_rounded(&mut self.raw, rounded);
+        }
+    }
+
     // Transform
 
     /// Set rotation angle (0.1 degree units, e.g., 450 = 45 degrees)
@@ -386,6 +595,12 @@ impl Style {
         }
     }
 
+    /// Set horizontal and vertical scale together (256 = 100%)
+    pub fn set_transform_scale(&mut self, scale: i32) {
+        self.set_transform_scale_x(scale);
+        self.set_transform_scale_y(scale);
+    }
+
     /// Set horizontal scale (256 = 100%)
     pub fn set_transform_scale_x(&mut self, scale: i32) {
         self.ensure_init();
@@ -402,6 +617,26 @@ impl Style {
         }
     }
 
+    /// Set extra width added on both sides when drawing
+    ///
+    /// The widget is drawn `width` pixels wider on the left and the right
+    /// without affecting the layout, so it suits press feedback animations.
+    /// Negative values shrink it.
+    pub fn set_transform_width(&mut self, width: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_transform_width(&mut self.raw, width);
+        }
+    }
+
+    /// Set extra height added on both top and bottom when drawing
+    pub fn set_transform_height(&mut self, height: i32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_transform_height(&mut self.raw, height);
+        }
+    }
+
     // Opacity
 
     /// Set overall opacity
@@ -412,6 +647,53 @@ impl Style {
         }
     }
 
+    /// Set how the widget is blended onto what is behind it
+    ///
+    /// See [`BlendMode`] for render support.
+    pub fn set_blend_mode(&mut self, mode: BlendMode) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_blend_mode(&mut self.raw, mode.to_raw());
+        }
+    }
+
+    // Animation
+
+    /// Set the animation template used by the widget
+    ///
+    /// Only widgets that animate internally read this property (e.g. the
+    /// Spinner's arc, a Label in scrolling long mode, the TextArea cursor
+    /// blink). It has no effect on other widgets.
+    pub fn set_anim(&mut self, anim: &'static crate::anim::Anim) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_anim(&mut self.raw, anim.raw());
+        }
+    }
+
+    /// Set the duration of the widget's internal animation in milliseconds
+    ///
+    /// See [`set_anim`](Self::set_anim) for which widgets use it.
+    pub fn set_anim_duration(&mut self, duration_ms: u32) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_anim_duration(&mut self.raw, duration_ms);
+        }
+    }
+
+    /// Animate property changes caused by state changes
+    ///
+    /// Add the transition to the style of the state being entered: a
+    /// transition on a `PRESSED` style plays when the widget is pressed, one
+    /// on the default style plays when it is released. Without a transition
+    /// the new values apply immediately.
+    pub fn set_transition(&mut self, transition: &'static Transition) {
+        self.ensure_init();
+        unsafe {
+            neo_lvgl_sys::lv_style_set_transition(&mut self.raw, transition.raw());
+        }
+    }
+
     // Layout
 
     /// Set flex flow direction
@@ -457,6 +739,346 @@ impl Drop for Style {
     }
 }
 
+/// Maximum number of properties a [`Transition`] can animate
+pub const MAX_TRANSITION_PROPS: usize = 8;
+
+/// Description of how style properties animate on state changes
+///
+/// Used with [`Style::set_transition`]. The transition is referenced by the
+/// style, so it has to be `'static`.
+///
+/// # Example
+///
+/// ```ignore
+/// let props = [StyleProp::BgColor, StyleProp::BgOpa];
+/// let fade = Transition::new(&props, AnimPath::Linear, 200, 0).unwrap();
+/// let fade: &'static Transition = Box::leak(Box::new(fade));
+///
+/// let mut pressed = Style::new();
+/// pressed.set_bg_color(Color::hex(0x1565C0));
+/// pressed.set_transition(fade);
+/// btn.add_style(&pressed, StyleSelector::PRESSED);
+/// ```
+pub struct Transition {
+    raw: core::cell::UnsafeCell<neo_lvgl_sys::lv_style_transition_dsc_t>,
+    /// Property list terminated by `LV_STYLE_PROP_INV` (0)
+    props: [neo_lvgl_sys::lv_style_prop_t; MAX_TRANSITION_PROPS + 1],
+}
+
+impl Transition {
+    /// Describe a transition of `props` over `duration_ms`, starting after
+    /// `delay_ms`
+    ///
+    /// [`AnimPath::CustomBezier`](crate::anim::AnimPath::CustomBezier) and
+    /// [`AnimPath::Custom`](crate::anim::AnimPath::Custom) can't carry their
+    /// parameters into a transition and fall back to `EaseInOut`. Returns
+    /// `None` if `props` is empty or has more than [`MAX_TRANSITION_PROPS`]
+    /// entries.
+    pub fn new(
+        props: &[StyleProp],
+        path: crate::anim::AnimPath,
+        duration_ms: u32,
+        delay_ms: u32,
+    ) -> Option<Self> {
+        if props.is_empty() || props.len() > MAX_TRANSITION_PROPS {
+            return None;
+        }
+        let mut list = [0; MAX_TRANSITION_PROPS + 1];
+        for (slot, prop) in list.iter_mut().zip(props) {
+            *slot = prop.raw();
+        }
+        let mut raw: neo_lvgl_sys::lv_style_transition_dsc_t =
+            unsafe { MaybeUninit::zeroed().assume_init() };
+        unsafe {
+            // The property list is linked in `raw()`, once the transition
+            // has its final address
+            neo_lvgl_sys::lv_style_transition_dsc_init(
+                &mut raw,
+                core::ptr::null(),
+                path.to_raw(),
+                duration_ms,
+                delay_ms,
+                core::ptr::null_mut(),
+            );
+        }
+        Some(Self {
+            raw: core::cell::UnsafeCell::new(raw),
+            props: list,
+        })
+    }
+
+    /// Get the transition duration in milliseconds
+    pub fn duration(&self) -> u32 {
+        unsafe { (*self.raw.get()).time }
+    }
+
+    /// Get the delay before the transition starts in milliseconds
+    pub fn delay(&self) -> u32 {
+        unsafe { (*self.raw.get()).delay }
+    }
+
+    /// Get raw pointer to the transition descriptor
+    pub fn raw(&'static self) -> *const neo_lvgl_sys::lv_style_transition_dsc_t {
+        unsafe { (*self.raw.get()).props = self.props.as_ptr() };
+        self.raw.get()
+    }
+}
+
+/// Reference-counted style that stays alive while any widget uses it
+///
+/// A plain [`Style`] resets itself when dropped, leaving widgets that still
+/// reference it with a dangling style. `SharedStyle` is cheap to clone, and
+/// every widget it is added to with `Widget::add_shared_style` holds a
+/// reference until the widget is deleted. The style is only reset when the
+/// last reference is dropped.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut style = Style::new();
+/// style.set_bg_color(Color::hex(0x2196F3));
+/// let style = SharedStyle::new(style);
+///
+/// for _ in 0..3 {
+///     let btn = Button::new(&screen).unwrap();
+///     btn.add_shared_style(&style, StyleSelector::default());
+/// }
+/// drop(style); // buttons keep it alive
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct SharedStyle {
+    inner: alloc::rc::Rc<core::cell::RefCell<Style>>,
+}
+
+#[cfg(feature = "alloc")]
+impl SharedStyle {
+    /// Wrap a style for sharing
+    pub fn new(style: Style) -> Self {
+        Self {
+            inner: alloc::rc::Rc::new(core::cell::RefCell::new(style)),
+        }
+    }
+
+    /// Modify the style and refresh every widget using it
+    pub fn modify<R>(&self, f: impl FnOnce(&mut Style) -> R) -> R {
+        let mut style = self.inner.borrow_mut();
+        let result = f(&mut style);
+        unsafe {
+            neo_lvgl_sys::lv_obj_report_style_change(style.raw_mut());
+        }
+        result
+    }
+
+    /// Get raw pointer to the style
+    ///
+    /// The pointer is stable for as long as any clone of this `SharedStyle` lives.
+    /// It can be taken while [`modify`](Self::modify) is running, e.g. by a
+    /// widget the closure adds the style to.
+    #[inline]
+    pub fn raw(&self) -> *mut neo_lvgl_sys::lv_style_t {
+        // No borrow: only the address is taken, the style isn't accessed
+        unsafe { core::ptr::addr_of_mut!((*self.inner.as_ptr()).raw) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Style> for SharedStyle {
+    fn from(style: Style) -> Self {
+        Self::new(style)
+    }
+}
+
+/// Style property identifier for generic reads
+///
+/// Covers the commonly used properties; see `Widget::style_prop`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum StyleProp {
+    /// Width
+    Width = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_WIDTH,
+    /// Minimum width
+    MinWidth = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_MIN_WIDTH,
+    /// Maximum width
+    MaxWidth = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_MAX_WIDTH,
+    /// Height
+    Height = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_HEIGHT,
+    /// Minimum height
+    MinHeight = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_MIN_HEIGHT,
+    /// Maximum height
+    MaxHeight = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_MAX_HEIGHT,
+    /// X position
+    X = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_X,
+    /// Y position
+    Y = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_Y,
+    /// Alignment
+    Align = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_ALIGN,
+    /// Transform width
+    TransformWidth = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TRANSFORM_WIDTH,
+    /// Transform height
+    TransformHeight = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TRANSFORM_HEIGHT,
+    /// Horizontal translation
+    TranslateX = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TRANSLATE_X,
+    /// Vertical translation
+    TranslateY = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TRANSLATE_Y,
+    /// Horizontal scale (256 = 100%)
+    TransformScaleX = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TRANSFORM_SCALE_X,
+    /// Vertical scale (256 = 100%)
+    TransformScaleY = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TRANSFORM_SCALE_Y,
+    /// Rotation in 0.1 degree units
+    TransformRotation = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TRANSFORM_ROTATION,
+    /// Top padding
+    PadTop = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_TOP,
+    /// Bottom padding
+    PadBottom = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_BOTTOM,
+    /// Left padding
+    PadLeft = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_LEFT,
+    /// Right padding
+    PadRight = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_RIGHT,
+    /// Padding between rows
+    PadRow = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_ROW,
+    /// Padding between columns
+    PadColumn = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_COLUMN,
+    /// Top margin
+    MarginTop = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_MARGIN_TOP,
+    /// Bottom margin
+    MarginBottom = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_MARGIN_BOTTOM,
+    /// Left margin
+    MarginLeft = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_MARGIN_LEFT,
+    /// Right margin
+    MarginRight = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_MARGIN_RIGHT,
+    /// Corner radius
+    Radius = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_RADIUS,
+    /// Overall opacity
+    Opa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_OPA,
+    /// Background color
+    BgColor = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BG_COLOR,
+    /// Background opacity
+    BgOpa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BG_OPA,
+    /// Background gradient color
+    BgGradColor = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BG_GRAD_COLOR,
+    /// Background image source
+    BgImageSrc = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BG_IMAGE_SRC,
+    /// Border color
+    BorderColor = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BORDER_COLOR,
+    /// Border opacity
+    BorderOpa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BORDER_OPA,
+    /// Border width
+    BorderWidth = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BORDER_WIDTH,
+    /// Outline width
+    OutlineWidth = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_OUTLINE_WIDTH,
+    /// Outline color
+    OutlineColor = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_OUTLINE_COLOR,
+    /// Outline opacity
+    OutlineOpa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_OUTLINE_OPA,
+    /// Outline padding
+    OutlinePad = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_OUTLINE_PAD,
+    /// Shadow width
+    ShadowWidth = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_SHADOW_WIDTH,
+    /// Shadow horizontal offset
+    ShadowOffsetX = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_SHADOW_OFFSET_X,
+    /// Shadow vertical offset
+    ShadowOffsetY = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_SHADOW_OFFSET_Y,
+    /// Shadow spread
+    ShadowSpread = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_SHADOW_SPREAD,
+    /// Shadow color
+    ShadowColor = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_SHADOW_COLOR,
+    /// Shadow opacity
+    ShadowOpa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_SHADOW_OPA,
+    /// Image opacity
+    ImageOpa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_IMAGE_OPA,
+    /// Image recolor
+    ImageRecolor = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_IMAGE_RECOLOR,
+    /// Image recolor intensity
+    ImageRecolorOpa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_IMAGE_RECOLOR_OPA,
+    /// Line width
+    LineWidth = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_LINE_WIDTH,
+    /// Line color
+    LineColor = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_LINE_COLOR,
+    /// Line opacity
+    LineOpa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_LINE_OPA,
+    /// Arc width
+    ArcWidth = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_ARC_WIDTH,
+    /// Arc color
+    ArcColor = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_ARC_COLOR,
+    /// Arc opacity
+    ArcOpa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_ARC_OPA,
+    /// Text color
+    TextColor = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TEXT_COLOR,
+    /// Text opacity
+    TextOpa = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TEXT_OPA,
+    /// Text font
+    TextFont = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TEXT_FONT,
+    /// Letter spacing
+    TextLetterSpace = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TEXT_LETTER_SPACE,
+    /// Line spacing
+    TextLineSpace = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TEXT_LINE_SPACE,
+    /// Animation duration in milliseconds
+    AnimDuration = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_ANIM_DURATION,
+}
+
+impl StyleProp {
+    /// Get the raw LVGL property id
+    #[inline]
+    pub fn raw(self) -> neo_lvgl_sys::lv_style_prop_t {
+        self as u32 as neo_lvgl_sys::lv_style_prop_t
+    }
+
+    /// Convert a raw value of this property to a [`StyleValue`]
+    pub(crate) fn value_from_raw(self, raw: neo_lvgl_sys::lv_style_value_t) -> StyleValue {
+        use StyleProp::*;
+        unsafe {
+            match self {
+                BgColor | BgGradColor | BorderColor | OutlineColor | ShadowColor | ImageRecolor
+                | LineColor | ArcColor | TextColor => StyleValue::Color(Color::from_raw(raw.color)),
+                Opa | BgOpa | BorderOpa | OutlineOpa | ShadowOpa | ImageOpa | ImageRecolorOpa
+                | LineOpa | ArcOpa | TextOpa => StyleValue::Opa(Opacity::new(raw.num as u8)),
+                BgImageSrc | TextFont => StyleValue::Ptr(raw.ptr),
+                _ => StyleValue::Int(raw.num),
+            }
+        }
+    }
+}
+
+/// Value of a style property read with `Widget::style_prop`
+#[derive(Clone, Copy)]
+pub enum StyleValue {
+    /// Numeric value (sizes, coordinates, enums, ...)
+    Int(i32),
+    /// Color value
+    Color(Color),
+    /// Opacity value
+    Opa(Opacity),
+    /// Pointer value (fonts, image sources, ...)
+    Ptr(*const core::ffi::c_void),
+}
+
+impl StyleValue {
+    /// Get the numeric value, if this is an `Int`
+    pub fn as_int(&self) -> Option<i32> {
+        match self {
+            StyleValue::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Get the color, if this is a `Color`
+    pub fn as_color(&self) -> Option<Color> {
+        match self {
+            StyleValue::Color(c) => Some(*c),
+            _ => None,
+        }
+    }
+
+    /// Get the opacity, if this is an `Opa`
+    pub fn as_opa(&self) -> Option<Opacity> {
+        match self {
+            StyleValue::Opa(o) => Some(*o),
+            _ => None,
+        }
+    }
+}
+
 bitflags! {
     /// Style selector for specifying widget parts and states
     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -508,6 +1130,131 @@ impl GradDir {
             GradDir::Vertical => neo_lvgl_sys::lv_grad_dir_t_LV_GRAD_DIR_VER,
         }
     }
+
+    fn from_raw(raw: neo_lvgl_sys::lv_grad_dir_t) -> Self {
+        match raw {
+            neo_lvgl_sys::lv_grad_dir_t_LV_GRAD_DIR_HOR => GradDir::Horizontal,
+            neo_lvgl_sys::lv_grad_dir_t_LV_GRAD_DIR_VER => GradDir::Vertical,
+            _ => GradDir::None,
+        }
+    }
+}
+
+/// Maximum number of color stops in a [`Gradient`]
+pub const MAX_GRADIENT_STOPS: usize = neo_lvgl_sys::LV_GRADIENT_MAX_STOPS as usize;
+
+/// A gradient through two or more color stops
+///
+/// The same gradient can be used as a style background with
+/// [`Style::set_bg_grad`], as a widget's local background (e.g. a chart's)
+/// with `Widget::set_style_bg_grad`, or to fill a canvas with
+/// `Canvas::fill_gradient`. Styles keep a pointer to the gradient, so it has
+/// to be `'static` there; the canvas copies it.
+///
+/// # Example
+///
+/// ```ignore
+/// let sky = Gradient::new(
+///     &[(Color::hex(0x0D47A1), 0), (Color::hex(0x42A5F5), 160), (Color::white(), 255)],
+///     GradDir::Vertical,
+/// )
+/// .unwrap();
+/// let sky: &'static Gradient = Box::leak(Box::new(sky));
+///
+/// chart.set_style_bg_grad(sky, StyleSelector::MAIN);
+/// ```
+#[derive(Clone)]
+pub struct Gradient {
+    raw: neo_lvgl_sys::lv_grad_dsc_t,
+}
+
+impl Gradient {
+    /// Build a gradient from `(color, position)` stops
+    ///
+    /// Positions run from 0 at the start to 255 at the end of the gradient
+    /// direction and must not decrease. All stops are opaque. Returns
+    /// `None` with fewer than 2 or more than [`MAX_GRADIENT_STOPS`] stops,
+    /// or if the positions are out of order.
+    pub fn new(stops: &[(Color, u8)], dir: GradDir) -> Option<Self> {
+        if stops.len() < 2 || stops.len() > MAX_GRADIENT_STOPS {
+            return None;
+        }
+        if stops.windows(2).any(|pair| pair[0].1 > pair[1].1) {
+            return None;
+        }
+        let mut raw: neo_lvgl_sys::lv_grad_dsc_t = unsafe { MaybeUninit::zeroed().assume_init() };
+        for (slot, (color, frac)) in raw.stops.iter_mut().zip(stops) {
+            slot.color = color.raw();
+            slot.opa = Opacity::COVER.raw();
+            slot.frac = *frac;
+        }
+        raw.stops_count = stops.len() as u8;
+        raw.set_dir(dir.to_raw());
+        Some(Self { raw })
+    }
+
+    /// Get the number of stops
+    pub fn stop_count(&self) -> usize {
+        self.raw.stops_count as usize
+    }
+
+    /// Get the color and position of stop `index`
+    pub fn stop(&self, index: usize) -> Option<(Color, u8)> {
+        if index >= self.stop_count() {
+            return None;
+        }
+        let stop = &self.raw.stops[index];
+        Some((Color::from_raw(stop.color), stop.frac))
+    }
+
+    /// Get the gradient direction
+    pub fn dir(&self) -> GradDir {
+        GradDir::from_raw(self.raw.dir())
+    }
+
+    /// Get raw pointer to the gradient descriptor
+    pub fn raw(&self) -> *const neo_lvgl_sys::lv_grad_dsc_t {
+        &self.raw
+    }
+
+    /// Get a copy of the raw gradient descriptor
+    pub(crate) fn to_raw(&self) -> neo_lvgl_sys::lv_grad_dsc_t {
+        self.raw
+    }
+}
+
+/// How a widget's pixels are combined with the pixels behind it
+///
+/// Any mode other than `Normal` makes LVGL render the widget and its
+/// children into an intermediate layer first and then blend the layer, so
+/// it costs extra memory (see `LV_DRAW_LAYER_SIMPLE_BUF_SIZE`) and time. The
+/// style's [`opa`](Style::set_opa) is applied when the layer is blended.
+///
+/// The software renderer supports every mode for RGB565, RGB888, XRGB8888
+/// and ARGB8888 displays in all render modes. Other color formats, and GPU
+/// draw units that lack the mode, fall back to `Normal`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// Draw over the background
+    #[default]
+    Normal,
+    /// Add the color to the background, e.g. for glow effects
+    Additive,
+    /// Subtract the color from the background
+    Subtractive,
+    /// Multiply the background with the color
+    Multiply,
+}
+
+impl BlendMode {
+    pub(crate) fn to_raw(self) -> neo_lvgl_sys::lv_blend_mode_t {
+        match self {
+            BlendMode::Normal => neo_lvgl_sys::lv_blend_mode_t_LV_BLEND_MODE_NORMAL,
+            BlendMode::Additive => neo_lvgl_sys::lv_blend_mode_t_LV_BLEND_MODE_ADDITIVE,
+            BlendMode::Subtractive => neo_lvgl_sys::lv_blend_mode_t_LV_BLEND_MODE_SUBTRACTIVE,
+            BlendMode::Multiply => neo_lvgl_sys::lv_blend_mode_t_LV_BLEND_MODE_MULTIPLY,
+        }
+    }
 }
 
 bitflags! {
@@ -570,3 +1317,233 @@ impl FlexFlow {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::layout::LayoutExt;
+    use crate::widgets::{Button, Container, Image, Widget};
+    use std::boxed::Box;
+
+    #[test]
+    fn test_clip_corner_clips_child() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::TestFramebuffer::new(40, 40);
+
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        panel.set_size(40, 40);
+        let mut style = Style::new();
+        style.set_radius(20);
+        style.set_clip_corner(true);
+        panel.add_style(&style, StyleSelector::default());
+
+        let child = Container::new(&panel).unwrap();
+        child.set_size(40, 40);
+        let mut fill = Style::new();
+        fill.set_bg_color(Color::rgb(255, 0, 0));
+        fill.set_bg_opa(Opacity::COVER);
+        child.add_style(&fill, StyleSelector::default());
+        display.refresh_now();
+
+        assert_eq!(display.pixel(20, 20), [255, 0, 0, 255]);
+        assert_ne!(display.pixel(0, 0), [255, 0, 0, 255]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_shared_style_added_inside_modify() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::TestFramebuffer::new(20, 20);
+
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        panel.set_size(20, 20);
+        let style = SharedStyle::new(Style::new());
+        style.modify(|s| {
+            s.set_bg_color(Color::rgb(0, 0, 255));
+            s.set_bg_opa(Opacity::COVER);
+            panel.add_shared_style(&style, StyleSelector::default());
+        });
+        display.refresh_now();
+
+        assert_eq!(display.pixel(10, 10), [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn test_transition_interpolates_on_state_change() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::TestFramebuffer::new(40, 40);
+
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        panel.set_size(40, 40);
+
+        let path = crate::anim::AnimPath::Linear;
+        let fade = Transition::new(&[StyleProp::BgColor], path, 200, 0).unwrap();
+        let fade: &'static Transition = Box::leak(Box::new(fade));
+        let mut normal = Style::new();
+        normal.set_bg_color(Color::rgb(0, 0, 0));
+        normal.set_bg_opa(Opacity::COVER);
+        let mut pressed = Style::new();
+        pressed.set_bg_color(Color::rgb(200, 0, 0));
+        pressed.set_transition(fade);
+        panel.add_style(&normal, StyleSelector::default());
+        panel.add_style(&pressed, StyleSelector::PRESSED);
+        display.refresh_now();
+
+        let red = || display.pixel(20, 20)[0];
+        assert_eq!(red(), 0);
+
+        panel.add_state(crate::widgets::State::PRESSED);
+        crate::tick_inc(100);
+        crate::task_handler();
+        display.refresh_now();
+        assert!((60..=140).contains(&red()), "halfway red was {}", red());
+
+        crate::tick_inc(150);
+        crate::task_handler();
+        display.refresh_now();
+        assert_eq!(red(), 200);
+    }
+
+    #[test]
+    fn test_additive_blend_adds_colors() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::TestFramebuffer::new(40, 40);
+
+        let mut fill = Style::new();
+        fill.set_bg_color(Color::rgb(100, 0, 40));
+        fill.set_bg_opa(Opacity::COVER);
+
+        let screen = display.active_screen();
+        let back = Container::new(&screen).unwrap();
+        back.set_size(40, 40);
+        back.add_style(&fill, StyleSelector::default());
+        let glow = Container::new(&back).unwrap();
+        glow.set_size(20, 40);
+        glow.add_style(&fill, StyleSelector::default());
+        glow.set_style_blend_mode(BlendMode::Additive, StyleSelector::MAIN);
+        display.refresh_now();
+
+        assert_eq!(display.pixel(10, 20), [200, 0, 80, 255]);
+        assert_eq!(display.pixel(30, 20), [100, 0, 40, 255]);
+    }
+
+    #[test]
+    fn test_three_stop_gradient() {
+        let _lvgl = crate::test_lvgl();
+
+        let red = Color::rgb(255, 0, 0);
+        let green = Color::rgb(0, 255, 0);
+        let blue = Color::rgb(0, 0, 255);
+        let stops = [(red, 0), (green, 128), (blue, 255)];
+        let grad = Gradient::new(&stops, GradDir::Horizontal).unwrap();
+        assert_eq!(grad.stop_count(), 3);
+        assert_eq!(grad.dir(), GradDir::Horizontal);
+        assert_eq!(grad.stop(1).map(|s| s.1), Some(128));
+        assert!(grad.stop(3).is_none());
+        assert!(Gradient::new(&[(red, 0)], GradDir::Vertical).is_none());
+        assert!(Gradient::new(&[(red, 200), (blue, 100)], GradDir::Vertical).is_none());
+        let grad: &'static Gradient = Box::leak(Box::new(grad));
+
+        let display = crate::TestFramebuffer::new(64, 8);
+
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        unsafe { neo_lvgl_sys::lv_obj_remove_style_all(panel.raw()) };
+        panel.set_size(64, 8);
+        let mut style = Style::new();
+        style.set_bg_opa(Opacity::COVER);
+        style.set_bg_grad(grad);
+        panel.add_style(&style, StyleSelector::default());
+        display.refresh_now();
+
+        let pixel = |x: usize| {
+            let [r, g, b, _] = display.pixel(x, 4);
+            (r, g, b)
+        };
+        let (r, g, b) = pixel(0);
+        assert!(r > 200 && g < 50 && b < 50, "start was {:?}", (r, g, b));
+        let (r, g, b) = pixel(32);
+        assert!(g > 200 && r < 50 && b < 50, "middle was {:?}", (r, g, b));
+        let (r, g, b) = pixel(63);
+        assert!(b > 200 && r < 50 && g < 50, "end was {:?}", (r, g, b));
+    }
+
+    #[test]
+    fn test_margin_shifts_flex_child() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let row = Container::new(&screen).unwrap().row();
+        let first = Button::new(&row).unwrap();
+        let second = Button::new(&row).unwrap();
+        first.set_size(40, 20);
+        second.set_size(40, 20);
+        row.update_layout();
+        assert_eq!(second.x(), 40);
+
+        let mut style = Style::new();
+        style.set_margin_left(15);
+        second.add_style(&style, StyleSelector::default());
+        row.update_layout();
+        assert_eq!(second.x(), 55);
+
+        second.remove_all_styles();
+        second.set_style_margin_top(8, StyleSelector::default());
+        row.update_layout();
+        assert_eq!(second.x(), 40);
+        assert_eq!(second.y(), 8);
+    }
+
+    #[test]
+    fn test_image_recolor_tints_icon() {
+        static WHITE: [u8; 4 * 4 * 4] = [255; 4 * 4 * 4];
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::TestFramebuffer::new(20, 20);
+
+        let icon_dsc = crate::widgets::ImageDsc::new(
+            4,
+            4,
+            crate::display::ColorFormat::Argb8888,
+            &WHITE,
+        )
+        .unwrap();
+        let icon_dsc: &'static _ = Box::leak(Box::new(icon_dsc));
+
+        let screen = display.active_screen();
+        let mut bg = Style::new();
+        bg.set_bg_color(Color::black());
+        bg.set_bg_opa(Opacity::COVER);
+        screen.add_style(&bg, StyleSelector::default());
+
+        let mut tint = Style::new();
+        tint.set_image_recolor(Color::rgb(255, 0, 0));
+        tint.set_image_recolor_opa(Opacity::COVER);
+        let icon = Image::new(&screen).unwrap();
+        icon.set_src_dsc(icon_dsc);
+        icon.set_pos(0, 0);
+        icon.add_style(&tint, StyleSelector::default());
+
+        let mut hidden = Style::new();
+        hidden.set_image_opa(Opacity::TRANSPARENT);
+        let faded = Image::new(&screen).unwrap();
+        faded.set_src_dsc(icon_dsc);
+        faded.set_pos(10, 10);
+        faded.add_style(&hidden, StyleSelector::default());
+        display.refresh_now();
+
+        assert_eq!(display.pixel(1, 1), [255, 0, 0, 255]);
+        assert_eq!(display.pixel(11, 11), [0, 0, 0, 255]);
+        assert_eq!(display.pixel(5, 5), [0, 0, 0, 255]);
+    }
+}
@@ -0,0 +1,147 @@
+//! Built-in symbol glyphs
+//!
+//! The Montserrat fonts shipped with LVGL include a set of icons from
+//! FontAwesome in the Unicode private use area. These constants are the
+//! UTF-8 strings of LVGL's `LV_SYMBOL_*` macros, ready to pass to
+//! [`Label::set_text`](crate::widgets::Label::set_text) or
+//! [`Image::set_src_symbol`](crate::widgets::Image::set_src_symbol).
+//!
+//! Symbols can be mixed with text, but then the whole string has to be
+//! built at runtime or spelled out with the same escape:
+//!
+//! ```ignore
+//! use lvgl::symbols;
+//!
+//! label.set_text(symbols::WIFI);
+//! button_label.set_text(c"\u{F00C} Apply"); // same glyph as symbols::OK
+//! ```
+//!
+//! Custom fonts only render these if they were converted with the symbol
+//! range included.
+
+use core::ffi::CStr;
+
+/// Bullet point (`LV_SYMBOL_BULLET`)
+pub const BULLET: &CStr = c"\u{2022}";
+/// Audio (`LV_SYMBOL_AUDIO`)
+pub const AUDIO: &CStr = c"\u{F001}";
+/// Video (`LV_SYMBOL_VIDEO`)
+pub const VIDEO: &CStr = c"\u{F008}";
+/// List (`LV_SYMBOL_LIST`)
+pub const LIST: &CStr = c"\u{F00B}";
+/// Check mark (`LV_SYMBOL_OK`)
+pub const OK: &CStr = c"\u{F00C}";
+/// Cross (`LV_SYMBOL_CLOSE`)
+pub const CLOSE: &CStr = c"\u{F00D}";
+/// Power (`LV_SYMBOL_POWER`)
+pub const POWER: &CStr = c"\u{F011}";
+/// Gear (`LV_SYMBOL_SETTINGS`)
+pub const SETTINGS: &CStr = c"\u{F013}";
+/// Home (`LV_SYMBOL_HOME`)
+pub const HOME: &CStr = c"\u{F015}";
+/// Download (`LV_SYMBOL_DOWNLOAD`)
+pub const DOWNLOAD: &CStr = c"\u{F019}";
+/// Drive (`LV_SYMBOL_DRIVE`)
+pub const DRIVE: &CStr = c"\u{F01C}";
+/// Refresh (`LV_SYMBOL_REFRESH`)
+pub const REFRESH: &CStr = c"\u{F021}";
+/// Mute (`LV_SYMBOL_MUTE`)
+pub const MUTE: &CStr = c"\u{F026}";
+/// Volume mid (`LV_SYMBOL_VOLUME_MID`)
+pub const VOLUME_MID: &CStr = c"\u{F027}";
+/// Volume max (`LV_SYMBOL_VOLUME_MAX`)
+pub const VOLUME_MAX: &CStr = c"\u{F028}";
+/// Image (`LV_SYMBOL_IMAGE`)
+pub const IMAGE: &CStr = c"\u{F03E}";
+/// Tint (`LV_SYMBOL_TINT`)
+pub const TINT: &CStr = c"\u{F043}";
+/// Prev (`LV_SYMBOL_PREV`)
+pub const PREV: &CStr = c"\u{F048}";
+/// Play (`LV_SYMBOL_PLAY`)
+pub const PLAY: &CStr = c"\u{F04B}";
+/// Pause (`LV_SYMBOL_PAUSE`)
+pub const PAUSE: &CStr = c"\u{F04C}";
+/// Stop (`LV_SYMBOL_STOP`)
+pub const STOP: &CStr = c"\u{F04D}";
+/// Next (`LV_SYMBOL_NEXT`)
+pub const NEXT: &CStr = c"\u{F051}";
+/// Eject (`LV_SYMBOL_EJECT`)
+pub const EJECT: &CStr = c"\u{F052}";
+/// Left (`LV_SYMBOL_LEFT`)
+pub const LEFT: &CStr = c"\u{F053}";
+/// Right (`LV_SYMBOL_RIGHT`)
+pub const RIGHT: &CStr = c"\u{F054}";
+/// Plus (`LV_SYMBOL_PLUS`)
+pub const PLUS: &CStr = c"\u{F067}";
+/// Minus (`LV_SYMBOL_MINUS`)
+pub const MINUS: &CStr = c"\u{F068}";
+/// Eye open (`LV_SYMBOL_EYE_OPEN`)
+pub const EYE_OPEN: &CStr = c"\u{F06E}";
+/// Eye close (`LV_SYMBOL_EYE_CLOSE`)
+pub const EYE_CLOSE: &CStr = c"\u{F070}";
+/// Warning (`LV_SYMBOL_WARNING`)
+pub const WARNING: &CStr = c"\u{F071}";
+/// Shuffle (`LV_SYMBOL_SHUFFLE`)
+pub const SHUFFLE: &CStr = c"\u{F074}";
+/// Up (`LV_SYMBOL_UP`)
+pub const UP: &CStr = c"\u{F077}";
+/// Down (`LV_SYMBOL_DOWN`)
+pub const DOWN: &CStr = c"\u{F078}";
+/// Loop (`LV_SYMBOL_LOOP`)
+pub const LOOP: &CStr = c"\u{F079}";
+/// Directory (`LV_SYMBOL_DIRECTORY`)
+pub const DIRECTORY: &CStr = c"\u{F07B}";
+/// Upload (`LV_SYMBOL_UPLOAD`)
+pub const UPLOAD: &CStr = c"\u{F093}";
+/// Call (`LV_SYMBOL_CALL`)
+pub const CALL: &CStr = c"\u{F095}";
+/// Cut (`LV_SYMBOL_CUT`)
+pub const CUT: &CStr = c"\u{F0C4}";
+/// Copy (`LV_SYMBOL_COPY`)
+pub const COPY: &CStr = c"\u{F0C5}";
+/// Save (`LV_SYMBOL_SAVE`)
+pub const SAVE: &CStr = c"\u{F0C7}";
+/// Hamburger menu (`LV_SYMBOL_BARS`)
+pub const BARS: &CStr = c"\u{F0C9}";
+/// Envelope (`LV_SYMBOL_ENVELOPE`)
+pub const ENVELOPE: &CStr = c"\u{F0E0}";
+/// Lightning bolt (`LV_SYMBOL_CHARGE`)
+pub const CHARGE: &CStr = c"\u{F0E7}";
+/// Paste (`LV_SYMBOL_PASTE`)
+pub const PASTE: &CStr = c"\u{F0EA}";
+/// Bell (`LV_SYMBOL_BELL`)
+pub const BELL: &CStr = c"\u{F0F3}";
+/// Keyboard (`LV_SYMBOL_KEYBOARD`)
+pub const KEYBOARD: &CStr = c"\u{F11C}";
+/// Gps (`LV_SYMBOL_GPS`)
+pub const GPS: &CStr = c"\u{F124}";
+/// File (`LV_SYMBOL_FILE`)
+pub const FILE: &CStr = c"\u{F15B}";
+/// Wifi (`LV_SYMBOL_WIFI`)
+pub const WIFI: &CStr = c"\u{F1EB}";
+/// Battery full (`LV_SYMBOL_BATTERY_FULL`)
+pub const BATTERY_FULL: &CStr = c"\u{F240}";
+/// Battery 3/4 full (`LV_SYMBOL_BATTERY_3`)
+pub const BATTERY_3: &CStr = c"\u{F241}";
+/// Battery half full (`LV_SYMBOL_BATTERY_2`)
+pub const BATTERY_2: &CStr = c"\u{F242}";
+/// Battery 1/4 full (`LV_SYMBOL_BATTERY_1`)
+pub const BATTERY_1: &CStr = c"\u{F243}";
+/// Battery empty (`LV_SYMBOL_BATTERY_EMPTY`)
+pub const BATTERY_EMPTY: &CStr = c"\u{F244}";
+/// Usb (`LV_SYMBOL_USB`)
+pub const USB: &CStr = c"\u{F287}";
+/// Bluetooth (`LV_SYMBOL_BLUETOOTH`)
+pub const BLUETOOTH: &CStr = c"\u{F293}";
+/// Trash (`LV_SYMBOL_TRASH`)
+pub const TRASH: &CStr = c"\u{F2ED}";
+/// Pencil (`LV_SYMBOL_EDIT`)
+pub const EDIT: &CStr = c"\u{F304}";
+/// Backspace (`LV_SYMBOL_BACKSPACE`)
+pub const BACKSPACE: &CStr = c"\u{F55A}";
+/// Sd card (`LV_SYMBOL_SD_CARD`)
+pub const SD_CARD: &CStr = c"\u{F7C2}";
+/// New line (`LV_SYMBOL_NEW_LINE`)
+pub const NEW_LINE: &CStr = c"\u{F8A2}";
+/// Invisible placeholder, useful to reserve room for a symbol (`LV_SYMBOL_DUMMY`)
+pub const DUMMY: &CStr = c"\u{F8FF}";
@@ -52,6 +52,11 @@ impl Timer {
         NonNull::new(ptr).map(|raw| Self { raw })
     }
 
+    /// Like [`new_static`](Self::new_static), but reports why creation failed.
+    pub fn try_new_static(period_ms: u32, cb: TimerCb) -> Result<Self, crate::LvError> {
+        Self::new_static(period_ms, cb).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Create a new timer with user data and a static callback
     ///
     /// # Safety
@@ -199,7 +204,60 @@ mod closure_support {
         }
     }
 
+    /// Container for one-shot timer closure
+    struct OnceClosure {
+        callback: Box<dyn FnOnce()>,
+    }
+
+    /// Trampoline for one-shot closures: takes ownership of and frees the closure
+    unsafe extern "C" fn once_trampoline(timer: *mut neo_lvgl_sys::lv_timer_t) {
+        let user_data = neo_lvgl_sys::lv_timer_get_user_data(timer);
+        if !user_data.is_null() {
+            neo_lvgl_sys::lv_timer_set_user_data(timer, core::ptr::null_mut());
+            let closure = Box::from_raw(user_data as *mut OnceClosure);
+            (closure.callback)();
+        }
+    }
+
     impl Timer {
+        /// Run `callback` once after `delay_ms` milliseconds
+        ///
+        /// The timer fires exactly once, deletes itself and frees the closure
+        /// afterwards, so there is no handle to keep or clean up.
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// Timer::once(2000, move || unsafe { toast.delete() })?;
+        /// ```
+        pub fn once<F>(delay_ms: u32, callback: F) -> Result<(), crate::LvError>
+        where
+            F: FnOnce() + 'static,
+        {
+            let closure = Box::new(OnceClosure {
+                callback: Box::new(callback),
+            });
+            let raw_closure = Box::into_raw(closure);
+
+            let ptr = unsafe {
+                neo_lvgl_sys::lv_timer_create(
+                    Some(once_trampoline),
+                    delay_ms,
+                    raw_closure as *mut c_void,
+                )
+            };
+            if ptr.is_null() {
+                drop(unsafe { Box::from_raw(raw_closure) });
+                return Err(crate::LvError::OutOfMemory);
+            }
+
+            unsafe {
+                neo_lvgl_sys::lv_timer_set_repeat_count(ptr, 1);
+                neo_lvgl_sys::lv_timer_set_auto_delete(ptr, true);
+            }
+            Ok(())
+        }
+
         /// Create a new timer with a closure callback
         ///
         /// # Example
@@ -271,3 +329,31 @@ pub fn timer_get_idle() -> u32 {
 pub fn timer_get_time_until_next() -> u32 {
     unsafe { neo_lvgl_sys::lv_timer_get_time_until_next() }
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_once_runs_once_and_frees_closure() {
+        let _lvgl = crate::test_lvgl();
+
+        let runs = Rc::new(Cell::new(0));
+        let runs_cb = runs.clone();
+        Timer::once(50, move || runs_cb.set(runs_cb.get() + 1)).unwrap();
+        assert_eq!(Rc::strong_count(&runs), 2);
+
+        crate::tick_inc(20);
+        crate::task_handler();
+        assert_eq!(runs.get(), 0);
+
+        for _ in 0..3 {
+            crate::tick_inc(50);
+            crate::task_handler();
+        }
+        assert_eq!(runs.get(), 1);
+        assert_eq!(Rc::strong_count(&runs), 1);
+    }
+}
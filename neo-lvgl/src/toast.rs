@@ -0,0 +1,172 @@
+//! Transient messages ("toasts" or "snackbars")
+//!
+//! A toast is a short message shown on the top layer of the default display
+//! that fades in, stays for a while, fades out and deletes itself.
+//!
+//! The fade-out is scheduled as a delayed animation on the toast itself
+//! rather than a separate timer, so it is cancelled together with the toast
+//! if the top layer is cleaned early, and nothing is left to free.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Quick message with the default look
+//! lvgl::toast(c"Saved", 2000)?;
+//!
+//! // Custom position and style
+//! static mut TOAST_STYLE: Style = Style::new();
+//! Toast::new(c"Connection lost")
+//!     .position(ToastPosition::Top)
+//!     .style(unsafe { &*core::ptr::addr_of!(TOAST_STYLE) })
+//!     .duration(4000)
+//!     .show()?;
+//! ```
+
+use crate::anim::{Anim, AnimPath};
+use crate::color::{Color, Opacity};
+use crate::style::Style;
+use crate::widgets::fade_exec;
+use core::ffi::{c_void, CStr};
+
+/// Where a toast appears on the screen
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ToastPosition {
+    /// Centered at the top edge
+    Top,
+    /// Centered at the bottom edge
+    #[default]
+    Bottom,
+}
+
+/// Builder for a toast message
+pub struct Toast<'t> {
+    text: &'t CStr,
+    duration_ms: u32,
+    fade_ms: u32,
+    position: ToastPosition,
+    offset: i32,
+    style: Option<&'static Style>,
+}
+
+impl<'t> Toast<'t> {
+    /// Create a toast showing `text` for 2 seconds at the bottom of the screen
+    ///
+    /// The text is copied when the toast is shown.
+    pub fn new(text: &'t CStr) -> Self {
+        Self {
+            text,
+            duration_ms: 2000,
+            fade_ms: 200,
+            position: ToastPosition::Bottom,
+            offset: 24,
+            style: None,
+        }
+    }
+
+    /// Set how long the toast stays fully visible (milliseconds)
+    pub fn duration(mut self, ms: u32) -> Self {
+        self.duration_ms = ms;
+        self
+    }
+
+    /// Set the fade-in and fade-out time (milliseconds)
+    pub fn fade(mut self, ms: u32) -> Self {
+        self.fade_ms = ms;
+        self
+    }
+
+    /// Set the screen edge the toast is attached to
+    pub fn position(mut self, position: ToastPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the distance from the screen edge in pixels
+    pub fn offset(mut self, offset: i32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Replace the default look with `style`
+    ///
+    /// The style is added on top of a style-less label, so it should set
+    /// background, text color, radius and padding itself.
+    pub fn style(mut self, style: &'static Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Show the toast
+    ///
+    /// Requires a default display.
+    pub fn show(self) -> Result<(), crate::LvError> {
+        let main = neo_lvgl_sys::lv_part_t_LV_PART_MAIN;
+        unsafe {
+            let label = neo_lvgl_sys::lv_label_create(neo_lvgl_sys::lv_layer_top());
+            if label.is_null() {
+                return Err(crate::LvError::OutOfMemory);
+            }
+            neo_lvgl_sys::lv_label_set_text(label, self.text.as_ptr().cast());
+
+            neo_lvgl_sys::lv_obj_remove_style_all(label);
+            match self.style {
+                Some(style) => neo_lvgl_sys::lv_obj_add_style(label, style.raw(), main),
+                None => {
+                    let bg = Color::hex(0x323232).to_raw();
+                    neo_lvgl_sys::lv_obj_set_style_bg_color(label, bg, main);
+                    neo_lvgl_sys::lv_obj_set_style_bg_opa(label, Opacity::OPA_90.to_raw(), main);
+                    neo_lvgl_sys::lv_obj_set_style_text_color(label, Color::white().to_raw(), main);
+                    neo_lvgl_sys::lv_obj_set_style_radius(label, 8, main);
+                    neo_lvgl_sys::lv_obj_set_style_pad_left(label, 16, main);
+                    neo_lvgl_sys::lv_obj_set_style_pad_right(label, 16, main);
+                    neo_lvgl_sys::lv_obj_set_style_pad_top(label, 10, main);
+                    neo_lvgl_sys::lv_obj_set_style_pad_bottom(label, 10, main);
+                }
+            }
+
+            let (align, y) = match self.position {
+                ToastPosition::Top => (neo_lvgl_sys::lv_align_t_LV_ALIGN_TOP_MID, self.offset),
+                ToastPosition::Bottom => {
+                    (neo_lvgl_sys::lv_align_t_LV_ALIGN_BOTTOM_MID, -self.offset)
+                }
+            };
+            neo_lvgl_sys::lv_obj_align(label, align, 0, y);
+            neo_lvgl_sys::lv_obj_set_style_opa(label, Opacity::TRANSPARENT.to_raw(), main);
+
+            let mut fade_in = Anim::new();
+            fade_in
+                .set_var(label as *mut c_void)
+                .set_values(0, 255)
+                .set_duration(self.fade_ms)
+                .set_path(AnimPath::EaseOut)
+                .set_exec_cb(Some(fade_exec));
+            fade_in.start();
+
+            // Uses the custom exec callback: starting an animation replaces
+            // any running one with the same var and exec callback
+            let mut fade_out = Anim::new();
+            fade_out
+                .set_var(label as *mut c_void)
+                .set_values(255, 0)
+                .set_delay(self.fade_ms + self.duration_ms)
+                .set_duration(self.fade_ms)
+                .set_early_apply(false)
+                .set_path(AnimPath::EaseIn)
+                .set_custom_exec_cb(Some(opa_custom_exec))
+                .set_completed_cb(Some(neo_lvgl_sys::lv_obj_delete_anim_completed_cb));
+            fade_out.start();
+        }
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn opa_custom_exec(anim: *mut neo_lvgl_sys::lv_anim_t, value: i32) {
+    fade_exec((*anim).var, value);
+}
+
+/// Show `text` as a toast at the bottom of the screen for `duration_ms`
+///
+/// Shorthand for `Toast::new(text).duration(duration_ms).show()`.
+pub fn toast(text: &CStr, duration_ms: u32) -> Result<(), crate::LvError> {
+    Toast::new(text).duration(duration_ms).show()
+}
@@ -61,6 +61,11 @@ impl<'a> Arc<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the arc indicator angles (the "filled" part)
     ///
     /// Angles are in degrees (0-360).
@@ -126,6 +131,48 @@ impl<'a> Arc<'a> {
         }
     }
 
+    /// Animate the value from its current position to `value`
+    ///
+    /// Like [`set_value`](Self::set_value), the animation doesn't emit
+    /// `ValueChanged`. Starting a new one replaces the running one.
+    pub fn set_value_with_anim(
+        &self,
+        value: i32,
+        duration_ms: u32,
+    ) -> Option<crate::anim::AnimHandle> {
+        let mut anim = crate::anim::Anim::new();
+        anim.set_var(self.obj.raw().cast())
+            .set_values(self.value(), value)
+            .set_duration(duration_ms)
+            .set_path(crate::anim::AnimPath::EaseOut)
+            .set_exec_cb(Some(value_exec));
+        anim.start()
+    }
+
+    /// Snap values changed by the user to multiples of `step`
+    ///
+    /// LVGL arcs move in steps of 1, so this rounds the value to the nearest
+    /// `min_value + n * step` whenever the user drags or rotates the arc.
+    /// A `step` of 0 or 1 turns snapping off.
+    ///
+    /// Snapping happens in a `ValueChanged` handler, so call this before
+    /// adding your own handlers or [`bind_value`](Self::bind_value) for them
+    /// to see the snapped value.
+    pub fn set_step(&self, step: i32) {
+        let raw = self.obj.raw();
+        unsafe {
+            neo_lvgl_sys::lv_obj_remove_event_cb(raw, Some(step_cb));
+            if step > 1 {
+                neo_lvgl_sys::lv_obj_add_event_cb(
+                    raw,
+                    Some(step_cb),
+                    neo_lvgl_sys::lv_event_code_t_LV_EVENT_VALUE_CHANGED,
+                    step as isize as *mut core::ffi::c_void,
+                );
+            }
+        }
+    }
+
     /// Set the value range
     pub fn set_range(&self, min: i32, max: i32) {
         unsafe {
@@ -213,6 +260,33 @@ impl<'a> Arc<'a> {
     }
 }
 
+unsafe extern "C" fn value_exec(var: *mut core::ffi::c_void, value: i32) {
+    neo_lvgl_sys::lv_arc_set_value(var.cast(), value);
+}
+
+unsafe extern "C" fn step_cb(e: *mut neo_lvgl_sys::lv_event_t) {
+    let obj = neo_lvgl_sys::lv_event_get_current_target(e) as *mut neo_lvgl_sys::lv_obj_t;
+    let step = neo_lvgl_sys::lv_event_get_user_data(e) as isize as i32;
+    let min = neo_lvgl_sys::lv_arc_get_min_value(obj);
+    let max = neo_lvgl_sys::lv_arc_get_max_value(obj);
+    let value = neo_lvgl_sys::lv_arc_get_value(obj);
+    let snapped = snap(value, min, max, step);
+    if snapped != value {
+        neo_lvgl_sys::lv_arc_set_value(obj, snapped);
+    }
+}
+
+/// Round `value` to the nearest `min + n * step` within `min..=max`
+fn snap(value: i32, min: i32, max: i32, step: i32) -> i32 {
+    let n = (value - min + step / 2).div_euclid(step);
+    let snapped = min + n * step;
+    if snapped > max {
+        snapped - step
+    } else {
+        snapped
+    }
+}
+
 impl<'a> Widget<'a> for Arc<'a> {
     fn obj(&self) -> &Obj<'a> {
         &self.obj
@@ -225,6 +299,45 @@ impl EventHandler for Arc<'_> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Arc<'_> {
+    /// Call `handler` with the arc and its new value when the user changes it
+    ///
+    /// Values set from code ([`set_value`](Self::set_value),
+    /// [`set_value_with_anim`](Self::set_value_with_anim) or a subject bound
+    /// with [`bind_value`](Self::bind_value)) don't emit `ValueChanged`, so
+    /// calling `set_value` from the handler can't loop. When the arc is bound,
+    /// the subject is already updated by the binding; setting it again from
+    /// the handler would notify its observers twice.
+    ///
+    /// This hides [`ClosureEventHandler::on_value_changed`], which passes the
+    /// raw [`Event`] instead. To register that one on an arc, call it through
+    /// the trait: `ClosureEventHandler::on_value_changed(&arc, |e| ...)`.
+    ///
+    /// [`ClosureEventHandler::on_value_changed`]: crate::event::ClosureEventHandler::on_value_changed
+    /// [`Event`]: crate::event::Event
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// arc.set_step(5);
+    /// arc.on_value_changed(|_, value| haptics::tick(value));
+    /// ```
+    pub fn on_value_changed<F>(&self, handler: F)
+    where
+        F: Fn(&Arc<'_>, i32) + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        let raw = self.obj.raw();
+        self.on_event_closure(EventCode::ValueChanged, move |_| {
+            if let Some(obj) = unsafe { Obj::from_raw(raw) } {
+                let arc = Arc { obj };
+                handler(&arc, arc.value());
+            }
+        });
+    }
+}
 
 impl<'a> Arc<'a> {
     /// Bind this arc's value to an integer subject
@@ -242,3 +355,68 @@ impl<'a> Arc<'a> {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use core::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_snap() {
+        assert_eq!(snap(23, 0, 100, 5), 25);
+        assert_eq!(snap(22, 0, 100, 5), 20);
+        assert_eq!(snap(-7, -10, 10, 5), -5);
+        assert_eq!(snap(99, 0, 99, 5), 95);
+    }
+
+    #[test]
+    fn test_step_snaps_before_handler() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let arc = Arc::new(&screen).unwrap();
+        arc.set_range(0, 100);
+        arc.set_step(5);
+
+        let seen = Rc::new(Cell::new(0));
+        let sink = seen.clone();
+        arc.on_value_changed(move |_, value| sink.set(value));
+
+        arc.set_value(23);
+        unsafe {
+            neo_lvgl_sys::lv_obj_send_event(
+                arc.raw(),
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_VALUE_CHANGED,
+                core::ptr::null_mut(),
+            );
+        }
+        assert_eq!(arc.value(), 25);
+        assert_eq!(seen.get(), 25);
+    }
+
+    #[test]
+    fn test_trait_value_changed_still_reachable() {
+        use crate::event::ClosureEventHandler;
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let arc = Arc::new(&screen).unwrap();
+
+        let calls = Rc::new(Cell::new(0));
+        let sink = calls.clone();
+        ClosureEventHandler::on_value_changed(&arc, move |_| sink.set(sink.get() + 1));
+
+        unsafe {
+            neo_lvgl_sys::lv_obj_send_event(
+                arc.raw(),
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_VALUE_CHANGED,
+                core::ptr::null_mut(),
+            );
+        }
+        assert_eq!(calls.get(), 1);
+    }
+}
@@ -89,6 +89,11 @@ impl<'a> Bar<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the bar value
     ///
     /// # Arguments
@@ -129,6 +134,23 @@ impl<'a> Bar<'a> {
         }
     }
 
+    /// Fill the indicator with a gradient (local style)
+    ///
+    /// The gradient spans the whole bar, not just the indicator, so each
+    /// color marks a fixed value: a green-to-red gradient shows only green
+    /// at low values and reaches red as the bar fills up.
+    ///
+    /// The bar keeps its own copy of `grad`, so it can be a temporary.
+    ///
+    /// ```ignore
+    /// let stops = [(Color::hex(0x00C853), 0), (Color::hex(0xD50000), 255)];
+    /// let grad = Gradient::new(&stops, GradDir::Horizontal).unwrap();
+    /// bar.set_indicator_gradient(&grad);
+    /// ```
+    pub fn set_indicator_gradient(&self, grad: &crate::style::Gradient) {
+        super::set_indicator_gradient(self.obj.raw(), grad);
+    }
+
     /// Set the bar mode
     pub fn set_mode(&self, mode: BarMode) {
         unsafe {
@@ -209,3 +231,44 @@ impl<'a> Bar<'a> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+    use crate::style::{GradDir, Gradient};
+
+    #[test]
+    fn test_indicator_gradient() {
+        let _lvgl = crate::test_lvgl();
+
+        let stops = [(Color::rgb(255, 0, 0), 0), (Color::rgb(0, 0, 255), 255)];
+
+        let display = crate::TestFramebuffer::new(100, 10);
+
+        let screen = display.active_screen();
+        let bar = Bar::new(&screen).unwrap();
+        bar.set_size(100, 10);
+        bar.set_pos(0, 0);
+        // The bar keeps its own copy, so the gradient can be a temporary
+        bar.set_indicator_gradient(&Gradient::new(&stops, GradDir::Horizontal).unwrap());
+        bar.set_value(100, false);
+        display.refresh_now();
+
+        let pixel = |x: usize| {
+            let [r, g, b, _] = display.pixel(x, 5);
+            (r, g, b)
+        };
+        let (r, g, b) = pixel(8);
+        assert!(r > 200 && g < 50 && b < 50, "start was {:?}", (r, g, b));
+        let full_end = pixel(91);
+        let (r, g, b) = full_end;
+        assert!(b > 200 && r < 50 && g < 50, "end was {:?}", (r, g, b));
+
+        // A half-full bar keeps its red start and stops before the blue end
+        bar.set_value(50, false);
+        display.refresh_now();
+        let (r, g, b) = pixel(8);
+        assert!(r > 200 && b < 50, "start was {:?}", (r, g, b));
+        assert_ne!(pixel(91), full_end);
+    }
+}
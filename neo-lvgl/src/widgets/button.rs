@@ -31,12 +31,45 @@ impl<'a> Button<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Create a label inside this button.
     pub fn create_label(&'a self) -> Option<super::Label<'a>> {
         super::Label::new(self)
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Button<'_> {
+    /// Call `handler` repeatedly while the button is held down
+    ///
+    /// The first call comes after the input device's long press time, then
+    /// one every long press repeat time (see
+    /// [`Indev::set_long_press_time`](crate::indev::Indev::set_long_press_time)
+    /// and
+    /// [`Indev::set_long_press_repeat_time`](crate::indev::Indev::set_long_press_repeat_time)).
+    /// A short tap doesn't trigger it, so pair it with a `Clicked` handler.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let step = move || spinbox.increment();
+    /// plus.on_event_closure(EventCode::Clicked, move |_| step());
+    /// plus.on_long_press_repeat(step);
+    /// ```
+    pub fn on_long_press_repeat<F>(&self, mut handler: F)
+    where
+        F: FnMut() + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        self.on_event_closure_mut(EventCode::LongPressedRepeat, move |_| handler());
+    }
+}
+
 impl<'a> Widget<'a> for Button<'a> {
     fn obj(&self) -> &Obj<'a> {
         &self.obj
@@ -49,4 +82,56 @@ impl EventHandler for Button<'_> {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::indev::{Indev, IndevState, PointerData};
+    use crate::layout::LayoutExt;
+    use core::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_long_press_repeat_fires_while_held() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let btn = Button::new(&screen).unwrap();
+        btn.set_size(100, 50);
+        btn.center();
+        btn.update_layout();
 
+        let pressed = Rc::new(Cell::new(true));
+        let state = pressed.clone();
+        let indev = Indev::new_pointer(move || PointerData {
+            point: crate::widgets::Point::new(160, 120),
+            state: if state.get() { IndevState::Pressed } else { IndevState::Released },
+        })
+        .unwrap();
+        indev.set_long_press_time(400);
+        indev.set_long_press_repeat_time(100);
+
+        let fires = Rc::new(Cell::new(0));
+        let counter = fires.clone();
+        btn.on_long_press_repeat(move || counter.set(counter.get() + 1));
+
+        let step = |ms| {
+            crate::tick_inc(ms);
+            unsafe { neo_lvgl_sys::lv_indev_read(indev.raw()) };
+        };
+
+        // Held for 1 s: long press at 400 ms, then a repeat every 100 ms
+        for _ in 0..20 {
+            step(50);
+        }
+        let held = fires.get();
+        assert!((4..=7).contains(&held), "{held} repeats");
+
+        pressed.set(false);
+        for _ in 0..10 {
+            step(50);
+        }
+        assert_eq!(fires.get(), held);
+    }
+}
@@ -64,6 +64,11 @@ impl<'a> ButtonMatrix<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the button map
     ///
     /// The map must remain valid for the lifetime of the widget.
@@ -78,6 +78,11 @@ impl<'a> Canvas<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Calculate the required buffer size for a canvas
     ///
     /// # Arguments
@@ -112,6 +117,16 @@ impl<'a> Canvas<'a> {
         );
     }
 
+    /// Use a [`DrawBuf`](crate::draw_buf::DrawBuf) as the canvas buffer
+    ///
+    /// The canvas takes the buffer's size and color format. The buffer is
+    /// borrowed for the canvas' lifetime, so it outlives the canvas.
+    pub fn set_draw_buf(&self, buf: &'a crate::draw_buf::DrawBuf) {
+        unsafe {
+            neo_lvgl_sys::lv_canvas_set_draw_buf(self.obj.raw(), buf.raw());
+        }
+    }
+
     /// Set a pixel color
     pub fn set_px(&self, x: i32, y: i32, color: Color, opa: Opacity) {
         unsafe {
@@ -126,6 +141,32 @@ impl<'a> Canvas<'a> {
         }
     }
 
+    /// Fill the whole canvas with a gradient
+    ///
+    /// The gradient is copied, so it doesn't have to outlive the call. Does
+    /// nothing if the canvas has no buffer yet.
+    pub fn fill_gradient(&self, grad: &crate::style::Gradient) {
+        unsafe {
+            let buf = neo_lvgl_sys::lv_canvas_get_draw_buf(self.obj.raw());
+            if buf.is_null() {
+                return;
+            }
+            let area = neo_lvgl_sys::lv_area_t {
+                x1: 0,
+                y1: 0,
+                x2: (*buf).header.w() as i32 - 1,
+                y2: (*buf).header.h() as i32 - 1,
+            };
+            let mut layer: neo_lvgl_sys::lv_layer_t = core::mem::zeroed();
+            neo_lvgl_sys::lv_canvas_init_layer(self.obj.raw(), &mut layer);
+            let mut dsc: neo_lvgl_sys::lv_draw_rect_dsc_t = core::mem::zeroed();
+            neo_lvgl_sys::lv_draw_rect_dsc_init(&mut dsc);
+            dsc.bg_grad = grad.to_raw();
+            neo_lvgl_sys::lv_draw_rect(&mut layer, &dsc, &area);
+            neo_lvgl_sys::lv_canvas_finish_layer(self.obj.raw(), &mut layer);
+        }
+    }
+
     /// Set a palette color (for indexed color formats)
     pub fn set_palette(&self, index: u8, color: Color) {
         unsafe {
@@ -0,0 +1,153 @@
+//! Card widget
+
+use super::{pct, Label, Obj, Widget, SIZE_CONTENT};
+use crate::color::{Color, Opacity};
+use crate::event::EventHandler;
+use core::ffi::CStr;
+
+/// Card: a rounded, shadowed panel with an optional title
+///
+/// Built from the theme's panel style with a larger radius, a soft shadow
+/// and more padding. The title uses the theme's primary color. Children go
+/// into [`content`](Self::content), a column that grows with them; the card
+/// itself is as tall as its content.
+///
+/// # Example
+///
+/// ```ignore
+/// let card = Card::new(&screen, Some(c"Temperature")).unwrap();
+/// card.set_width(160);
+///
+/// let content = card.content();
+/// let value = Label::new(&content).unwrap();
+/// value.set_text(c"21.5 °C");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Card<'a> {
+    obj: Obj<'a>,
+    title: Option<Label<'a>>,
+    content: Obj<'a>,
+}
+
+impl<'a> Card<'a> {
+    /// Create a new card as a child of the given parent.
+    ///
+    /// With `title`, a label is added above the content.
+    pub fn new(parent: &'a impl Widget<'a>, title: Option<&CStr>) -> Option<Self> {
+        let main = neo_lvgl_sys::lv_part_t_LV_PART_MAIN;
+        unsafe {
+            let ptr = neo_lvgl_sys::lv_obj_create(parent.raw());
+            let obj = Obj::from_raw(ptr)?;
+            neo_lvgl_sys::lv_obj_set_height(ptr, SIZE_CONTENT);
+            neo_lvgl_sys::lv_obj_set_style_radius(ptr, 12, main);
+            neo_lvgl_sys::lv_obj_set_style_pad_all(ptr, 16, main);
+            neo_lvgl_sys::lv_obj_set_style_pad_row(ptr, 8, main);
+            neo_lvgl_sys::lv_obj_set_style_shadow_width(ptr, 16, main);
+            neo_lvgl_sys::lv_obj_set_style_shadow_offset_y(ptr, 4, main);
+            neo_lvgl_sys::lv_obj_set_style_shadow_opa(ptr, Opacity::OPA_20.raw(), main);
+            neo_lvgl_sys::lv_obj_set_style_shadow_color(ptr, Color::black().raw(), main);
+            neo_lvgl_sys::lv_obj_set_flex_flow(
+                ptr,
+                neo_lvgl_sys::lv_flex_flow_t_LV_FLEX_FLOW_COLUMN,
+            );
+            neo_lvgl_sys::lv_obj_remove_flag(
+                ptr,
+                neo_lvgl_sys::lv_obj_flag_t_LV_OBJ_FLAG_SCROLLABLE,
+            );
+
+            let title = match title {
+                Some(text) => {
+                    let label = neo_lvgl_sys::lv_label_create(ptr);
+                    let Some(label) = Obj::from_raw(label) else {
+                        neo_lvgl_sys::lv_obj_delete(ptr);
+                        return None;
+                    };
+                    neo_lvgl_sys::lv_label_set_text(label.raw(), text.as_ptr());
+                    let primary = neo_lvgl_sys::lv_theme_get_color_primary(ptr);
+                    neo_lvgl_sys::lv_obj_set_style_text_color(label.raw(), primary, main);
+                    Some(Label::from_obj(label))
+                }
+                None => None,
+            };
+
+            let content = neo_lvgl_sys::lv_obj_create(ptr);
+            let Some(content) = Obj::from_raw(content) else {
+                neo_lvgl_sys::lv_obj_delete(ptr);
+                return None;
+            };
+            neo_lvgl_sys::lv_obj_remove_style_all(content.raw());
+            neo_lvgl_sys::lv_obj_set_size(content.raw(), pct(100), SIZE_CONTENT);
+            neo_lvgl_sys::lv_obj_set_style_pad_row(content.raw(), 8, main);
+            neo_lvgl_sys::lv_obj_set_flex_flow(
+                content.raw(),
+                neo_lvgl_sys::lv_flex_flow_t_LV_FLEX_FLOW_COLUMN,
+            );
+
+            Some(Self { obj, title, content })
+        }
+    }
+
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(
+        parent: &'a impl Widget<'a>,
+        title: Option<&CStr>,
+    ) -> Result<Self, crate::LvError> {
+        Self::new(parent, title).ok_or(crate::LvError::OutOfMemory)
+    }
+
+    /// Get the title label, if the card was created with one
+    pub fn title(&self) -> Option<Label<'a>> {
+        self.title
+    }
+
+    /// Get the container for the card's children
+    pub fn content(&self) -> Obj<'a> {
+        self.content
+    }
+}
+
+impl<'a> Widget<'a> for Card<'a> {
+    fn obj(&self) -> &Obj<'a> {
+        &self.obj
+    }
+}
+
+impl EventHandler for Card<'_> {
+    fn obj_raw(&self) -> *mut neo_lvgl_sys::lv_obj_t {
+        self.obj.raw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::layout::LayoutExt;
+
+    #[test]
+    fn test_card_with_title_and_content() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let card = Card::new(&screen, Some(c"Battery")).unwrap();
+        card.set_width(160);
+
+        let title = card.title().unwrap();
+        assert_eq!(title.text(), Some("Battery"));
+        let content = card.content();
+        let value = Label::new(&content).unwrap();
+        value.set_text(c"87 %");
+        card.update_layout();
+
+        // Title first, then the content below it
+        assert_eq!(card.child_count(), 2);
+        assert!(content.y() > title.y());
+        assert!(value.height() > 0);
+        assert!(card.height() > content.y() + content.height());
+
+        let untitled = Card::new(&screen, None).unwrap();
+        assert!(untitled.title().is_none());
+        assert_eq!(untitled.child_count(), 1);
+    }
+}
@@ -0,0 +1,118 @@
+//! Container widget
+
+use super::{Obj, Widget, SIZE_CONTENT};
+use crate::event::EventHandler;
+use crate::layout::{FlexFlow, LayoutExt};
+
+/// Plain layout container
+///
+/// A thin wrapper over [`Obj`] without background, border, padding or
+/// scrollbar, sized to its content. The builder methods configure flex
+/// layout so the common "arrange these children in a row" case is one line.
+///
+/// # Example
+///
+/// ```ignore
+/// let row = Container::new(&screen).unwrap().row().fill();
+/// let ok = Button::new(&row).unwrap();
+/// let cancel = Button::new(&row).unwrap();
+/// ```
+#[derive(Clone, Copy)]
+pub struct Container<'a> {
+    obj: Obj<'a>,
+}
+
+impl<'a> Container<'a> {
+    /// Create a new container as a child of the given parent.
+    pub fn new(parent: &'a impl Widget<'a>) -> Option<Self> {
+        unsafe {
+            let ptr = neo_lvgl_sys::lv_obj_create(parent.raw());
+            let obj = Obj::from_raw(ptr)?;
+            neo_lvgl_sys::lv_obj_remove_style_all(ptr);
+            neo_lvgl_sys::lv_obj_remove_flag(
+                ptr,
+                neo_lvgl_sys::lv_obj_flag_t_LV_OBJ_FLAG_SCROLLABLE,
+            );
+            neo_lvgl_sys::lv_obj_set_size(ptr, SIZE_CONTENT, SIZE_CONTENT);
+            Some(Self { obj })
+        }
+    }
+
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
+    /// Arrange children horizontally
+    pub fn row(self) -> Self {
+        self.set_flex_flow(FlexFlow::Row);
+        self
+    }
+
+    /// Arrange children vertically
+    pub fn column(self) -> Self {
+        self.set_flex_flow(FlexFlow::Column);
+        self
+    }
+
+    /// Grow to fill the free space along the parent's flex main axis
+    ///
+    /// Only has an effect when the parent uses a flex layout.
+    pub fn fill(self) -> Self {
+        self.set_flex_grow(1);
+        self
+    }
+
+    /// Set the gap between children, in pixels
+    pub fn gap(self, gap: i32) -> Self {
+        unsafe {
+            let main = neo_lvgl_sys::lv_part_t_LV_PART_MAIN;
+            neo_lvgl_sys::lv_obj_set_style_pad_row(self.obj.raw(), gap, main);
+            neo_lvgl_sys::lv_obj_set_style_pad_column(self.obj.raw(), gap, main);
+        }
+        self
+    }
+}
+
+impl<'a> Widget<'a> for Container<'a> {
+    fn obj(&self) -> &Obj<'a> {
+        &self.obj
+    }
+}
+
+impl EventHandler for Container<'_> {
+    fn obj_raw(&self) -> *mut neo_lvgl_sys::lv_obj_t {
+        self.obj.raw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::widgets::Button;
+
+    #[test]
+    fn test_row_of_three_buttons() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let row = Container::new(&screen).unwrap().row().gap(10);
+        let buttons = [
+            Button::new(&row).unwrap(),
+            Button::new(&row).unwrap(),
+            Button::new(&row).unwrap(),
+        ];
+        for btn in &buttons {
+            btn.set_size(40, 20);
+        }
+        row.update_layout();
+
+        assert_eq!(buttons[0].x(), 0);
+        assert_eq!(buttons[1].x(), 50);
+        assert_eq!(buttons[2].x(), 100);
+        assert!(buttons.iter().all(|b| b.y() == 0));
+        assert_eq!(row.width(), 140);
+    }
+}
@@ -85,6 +85,11 @@ impl<'a> Dropdown<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the options (newline-separated string)
     pub fn set_options(&self, options: &CStr) {
         unsafe {
@@ -149,6 +154,14 @@ impl<'a> Dropdown<'a> {
         }
     }
 
+    /// Iterate over the options one by one
+    ///
+    /// Useful for finding an option's index by its text:
+    /// `options_iter().position(|o| o == "Apple")`.
+    pub fn options_iter(&self) -> impl Iterator<Item = &str> {
+        super::split_options(self.options())
+    }
+
     /// Set the dropdown direction
     pub fn set_dir(&self, dir: DropdownDir) {
         unsafe {
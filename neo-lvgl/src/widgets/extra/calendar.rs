@@ -69,6 +69,11 @@ impl<'a> Calendar<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set today's date (shown with a highlight)
     pub fn set_today(&self, date: CalendarDate) {
         let raw = date.to_raw();
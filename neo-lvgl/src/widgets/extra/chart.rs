@@ -53,6 +53,12 @@ impl ChartAxis {
     }
 }
 
+/// Point index or value meaning "none"
+///
+/// Returned by [`Chart::pressed_point`] while nothing is pressed, and used
+/// as the value of points that have no data.
+pub const CHART_POINT_NONE: u32 = i32::MAX as u32;
+
 /// Handle to a chart data series
 pub struct ChartSeries {
     raw: NonNull<neo_lvgl_sys::lv_chart_series_t>,
@@ -105,6 +111,11 @@ impl<'a> Chart<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the chart type
     pub fn set_type(&self, chart_type: ChartType) {
         unsafe {
@@ -199,10 +210,20 @@ impl<'a> Chart<'a> {
     }
 
     /// Get the index of the currently pressed point
+    ///
+    /// Returns [`CHART_POINT_NONE`] while no point is pressed.
     pub fn pressed_point(&self) -> u32 {
         unsafe { neo_lvgl_sys::lv_chart_get_pressed_point(self.obj.raw()) }
     }
 
+    /// Get the value of `series` at the currently pressed point
+    ///
+    /// Returns `None` while no point is pressed or if the series has no
+    /// value there.
+    pub fn value_at_pressed_point(&self, series: &ChartSeries) -> Option<i32> {
+        unsafe { value_at_pressed_point(self.obj.raw(), series.raw()) }
+    }
+
     /// Set the cursor position for a series
     pub fn set_cursor_point(&self, series: &ChartSeries, point_id: u32) {
         // Cursor API requires getting cursor first
@@ -220,6 +241,59 @@ impl<'a> Chart<'a> {
     }
 }
 
+/// Read the value of `series` at the pressed point
+///
+/// The pressed index counts from the left edge, while the Y array is a ring
+/// buffer starting at the series' start point.
+unsafe fn value_at_pressed_point(
+    chart: *mut neo_lvgl_sys::lv_obj_t,
+    series: *mut neo_lvgl_sys::lv_chart_series_t,
+) -> Option<i32> {
+    let pressed = neo_lvgl_sys::lv_chart_get_pressed_point(chart);
+    let count = neo_lvgl_sys::lv_chart_get_point_count(chart);
+    if pressed == CHART_POINT_NONE || pressed >= count {
+        return None;
+    }
+    let start = neo_lvgl_sys::lv_chart_get_x_start_point(chart, series);
+    let index = (start + pressed) % count;
+    let values = neo_lvgl_sys::lv_chart_get_series_y_array(chart, series);
+    if values.is_null() {
+        return None;
+    }
+    let value = *values.add(index as usize);
+    (value != CHART_POINT_NONE as i32).then_some(value)
+}
+
+#[cfg(feature = "alloc")]
+impl Chart<'_> {
+    /// Call `handler` when the user presses a different point
+    ///
+    /// `handler` gets the series index (its position in the chart's series
+    /// list), the point index counted from the left edge, and the value. It
+    /// is called once per series that has a value at that point, e.g. to
+    /// place a tooltip label.
+    pub fn on_point_pressed<F>(&self, handler: F)
+    where
+        F: Fn(usize, u32, i32) + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        let raw = self.obj.raw();
+        self.on_event_closure(EventCode::ValueChanged, move |_| unsafe {
+            let point = neo_lvgl_sys::lv_chart_get_pressed_point(raw);
+            let mut series = neo_lvgl_sys::lv_chart_get_series_next(raw, core::ptr::null());
+            let mut index = 0;
+            while !series.is_null() {
+                if let Some(value) = value_at_pressed_point(raw, series) {
+                    handler(index, point, value);
+                }
+                series = neo_lvgl_sys::lv_chart_get_series_next(raw, series);
+                index += 1;
+            }
+        });
+    }
+}
+
 impl<'a> Widget<'a> for Chart<'a> {
     fn obj(&self) -> &Obj<'a> {
         &self.obj
@@ -232,4 +306,61 @@ impl EventHandler for Chart<'_> {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::indev::{Indev, IndevState, PointerData};
+    use crate::layout::LayoutExt;
+    use core::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_pressed_point_value() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let chart = Chart::new(&screen).unwrap();
+        chart.set_size(200, 100);
+        chart.center();
+        chart.set_type(ChartType::Line);
+        chart.set_point_count(5);
+        let series = chart.add_series(Color::red(), ChartAxis::PrimaryY).unwrap();
+        for value in [10, 20, 30, 40, 50] {
+            chart.set_next(&series, value);
+        }
+        chart.update_layout();
+        assert_eq!(chart.value_at_pressed_point(&series), None);
+
+        let seen = Rc::new(Cell::new(None));
+        let sink = seen.clone();
+        chart.on_point_pressed(move |series, point, value| {
+            sink.set(Some((series, point, value)))
+        });
+
+        let pressed = Rc::new(Cell::new(true));
+        let state = pressed.clone();
+        let indev = Indev::new_pointer(move || PointerData {
+            point: crate::widgets::Point::new(160, 120),
+            state: if state.get() { IndevState::Pressed } else { IndevState::Released },
+        })
+        .unwrap();
+        let step = || {
+            crate::tick_inc(20);
+            unsafe { neo_lvgl_sys::lv_indev_read(indev.raw()) };
+        };
 
+        // The middle of the chart is the third of five points
+        step();
+        step();
+        assert_eq!(chart.pressed_point(), 2);
+        assert_eq!(chart.value_at_pressed_point(&series), Some(30));
+        assert_eq!(seen.get(), Some((0, 2, 30)));
+
+        pressed.set(false);
+        step();
+        assert_eq!(chart.pressed_point(), CHART_POINT_NONE);
+        assert_eq!(chart.value_at_pressed_point(&series), None);
+    }
+}
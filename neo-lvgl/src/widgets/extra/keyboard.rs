@@ -13,20 +13,34 @@ pub enum KeyboardMode {
     TextUpper,
     /// Special characters
     Special,
-    /// Numeric input
+    /// Numeric input (digits, sign, decimal point)
     Number,
-    /// User-defined map
-    UserDefined,
+    /// First user-defined map
+    User1,
+    /// Second user-defined map
+    User2,
+    /// Third user-defined map
+    User3,
+    /// Fourth user-defined map
+    User4,
 }
 
 impl KeyboardMode {
+    /// User-defined map (alias for `User1`)
+    #[deprecated(note = "use `KeyboardMode::User1`")]
+    #[allow(non_upper_case_globals)]
+    pub const UserDefined: Self = Self::User1;
+
     fn to_raw(self) -> neo_lvgl_sys::lv_keyboard_mode_t {
         match self {
             KeyboardMode::TextLower => neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_TEXT_LOWER,
             KeyboardMode::TextUpper => neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_TEXT_UPPER,
             KeyboardMode::Special => neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_SPECIAL,
             KeyboardMode::Number => neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_NUMBER,
-            KeyboardMode::UserDefined => neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_USER_1,
+            KeyboardMode::User1 => neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_USER_1,
+            KeyboardMode::User2 => neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_USER_2,
+            KeyboardMode::User3 => neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_USER_3,
+            KeyboardMode::User4 => neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_USER_4,
         }
     }
 
@@ -36,7 +50,10 @@ impl KeyboardMode {
             neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_TEXT_UPPER => KeyboardMode::TextUpper,
             neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_SPECIAL => KeyboardMode::Special,
             neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_NUMBER => KeyboardMode::Number,
-            _ => KeyboardMode::UserDefined,
+            neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_USER_2 => KeyboardMode::User2,
+            neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_USER_3 => KeyboardMode::User3,
+            neo_lvgl_sys::lv_keyboard_mode_t_LV_KEYBOARD_MODE_USER_4 => KeyboardMode::User4,
+            _ => KeyboardMode::User1,
         }
     }
 }
@@ -66,6 +83,18 @@ impl<'a> Keyboard<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
+    /// Create a keyboard in numeric mode, e.g. for a PIN pad.
+    pub fn numeric(parent: &'a impl Widget<'a>) -> Option<Self> {
+        let keyboard = Self::new(parent)?;
+        keyboard.set_mode(KeyboardMode::Number);
+        Some(keyboard)
+    }
+
     /// Set the associated text area
     ///
     /// Keys pressed on the keyboard will be sent to this text area.
@@ -132,4 +161,71 @@ impl EventHandler for Keyboard<'_> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Keyboard<'_> {
+    /// Call `handler` when the keyboard's OK key is pressed.
+    ///
+    /// The handler receives the text of the attached text area, or `None`
+    /// if no text area is set. The text area itself also receives
+    /// `EventCode::Ready`, see `TextArea::on_ready`.
+    pub fn on_ready<F>(&self, handler: F)
+    where
+        F: Fn(Option<&core::ffi::CStr>) + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        let raw = self.obj.raw();
+        self.on_event_closure(EventCode::Ready, move |_| unsafe {
+            let ta = neo_lvgl_sys::lv_keyboard_get_textarea(raw);
+            if ta.is_null() {
+                handler(None);
+            } else {
+                let text = neo_lvgl_sys::lv_textarea_get_text(ta);
+                handler(Some(core::ffi::CStr::from_ptr(text.cast())));
+            }
+        });
+    }
 
+    /// Call `handler` when the keyboard's close key is pressed.
+    ///
+    /// The attached text area also receives `EventCode::Cancel`.
+    pub fn on_cancel<F>(&self, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        self.on_event_closure(EventCode::Cancel, move |_| handler());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+
+    #[test]
+    fn test_numeric_keyboard_types_into_textarea() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let textarea = TextArea::new(&screen).unwrap();
+        let keyboard = Keyboard::numeric(&screen).unwrap();
+        keyboard.set_textarea(&textarea);
+        assert_eq!(keyboard.mode(), KeyboardMode::Number);
+
+        let one = (0..32)
+            .find(|&id| keyboard.button_text(id).map(|t| t.to_bytes()) == Some(b"1"))
+            .expect("numeric map has a \"1\" key");
+        unsafe {
+            neo_lvgl_sys::lv_buttonmatrix_set_selected_button(keyboard.raw(), one);
+            neo_lvgl_sys::lv_obj_send_event(
+                keyboard.raw(),
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_VALUE_CHANGED,
+                core::ptr::null_mut(),
+            );
+        }
+        assert_eq!(textarea.text().to_bytes(), b"1");
+    }
+}
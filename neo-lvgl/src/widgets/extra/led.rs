@@ -32,6 +32,11 @@ impl<'a> Led<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the LED color
     pub fn set_color(&self, color: Color) {
         unsafe {
@@ -86,6 +86,11 @@ impl<'a> List<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Add a text item (non-clickable header/separator)
     pub fn add_text(&self, text: &CStr) -> Option<ListText<'a>> {
         unsafe {
@@ -118,6 +118,11 @@ impl<'a> Menu<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Create a new page
     pub fn create_page(&self, title: &CStr) -> Option<MenuPage<'a>> {
         unsafe {
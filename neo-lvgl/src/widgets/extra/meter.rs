@@ -0,0 +1,286 @@
+//! Meter widget (analog gauge built on Scale)
+
+use super::{Scale, ScaleMode};
+use crate::anim::{Anim, AnimPath};
+use crate::color::Color;
+use crate::event::EventHandler;
+use crate::widgets::{Obj, Widget};
+use core::ffi::c_void;
+
+/// A needle on a [`Meter`]
+///
+/// Created with [`Meter::add_needle_line`]. The needle is a child of the
+/// meter's scale and is deleted together with it. The meter ignores a
+/// needle that has been deleted.
+#[derive(Clone, Copy)]
+pub struct MeterNeedle<'a> {
+    obj: Obj<'a>,
+}
+
+impl MeterNeedle<'_> {
+    /// Get the needle's state, or `None` if the needle has been deleted
+    fn state(&self) -> Option<*mut NeedleState> {
+        let obj = self.obj.raw();
+        if !unsafe { neo_lvgl_sys::lv_obj_is_valid(obj) } {
+            return None;
+        }
+        crate::widgets::event_user_data(obj, free_state_cb)
+            .next()
+            .map(|state| state.cast())
+    }
+}
+
+/// Per-needle state
+///
+/// Allocated with `lv_malloc` and kept as the user data of the needle's
+/// DELETE callback, which frees it. The object's own user data holds
+/// LVGL's needle points.
+struct NeedleState {
+    r_mod: i32,
+    /// Last value the needle was pointed at
+    value: i32,
+}
+
+impl<'a> Widget<'a> for MeterNeedle<'a> {
+    fn obj(&self) -> &Obj<'a> {
+        &self.obj
+    }
+}
+
+impl EventHandler for MeterNeedle<'_> {
+    fn obj_raw(&self) -> *mut neo_lvgl_sys::lv_obj_t {
+        self.obj.raw()
+    }
+}
+
+/// Meter widget
+///
+/// A round [`Scale`] with one or more needles, ready to use as a
+/// speedometer or any other analog gauge. Ticks, labels and sections are
+/// configured through [`scale`](Self::scale).
+///
+/// # Example
+///
+/// ```ignore
+/// let meter = Meter::new(&screen).unwrap();
+/// meter.set_size(200, 200);
+/// meter.scale().set_range(0, 240);
+/// meter.scale().set_total_tick_count(25);
+/// meter.scale().set_major_tick_every(5);
+///
+/// let needle = meter.add_needle_line(4, Color::hex(0xE53935), -10).unwrap();
+/// meter.set_value(&needle, 120, 500);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Meter<'a> {
+    scale: Scale<'a>,
+}
+
+impl<'a> Meter<'a> {
+    /// Create a new meter as a child of the given parent.
+    ///
+    /// The scale starts in round mode with a 0..=100 range, 270 degree
+    /// sweep and labelled major ticks.
+    pub fn new(parent: &'a impl Widget<'a>) -> Option<Self> {
+        let scale = Scale::new(parent)?;
+        scale.set_mode(ScaleMode::Round);
+        scale.set_range(0, 100);
+        scale.set_angle_range(270);
+        scale.set_rotation(135);
+        scale.set_total_tick_count(21);
+        scale.set_major_tick_every(5);
+        scale.set_label_show(true);
+        Some(Self { scale })
+    }
+
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
+    /// Get the underlying scale to configure range, ticks and sections
+    pub fn scale(&self) -> Scale<'a> {
+        self.scale
+    }
+
+    /// Add a line needle
+    ///
+    /// `r_mod` adjusts the needle length relative to the scale radius:
+    /// `0` reaches the ticks, negative values make it shorter.
+    pub fn add_needle_line(
+        &self,
+        width: i32,
+        color: Color,
+        r_mod: i32,
+    ) -> Option<MeterNeedle<'a>> {
+        let main = crate::style::StyleSelector::MAIN.bits();
+        unsafe {
+            let ptr = neo_lvgl_sys::lv_line_create(self.scale.raw());
+            let obj = Obj::from_raw(ptr)?;
+            let state = neo_lvgl_sys::lv_malloc(core::mem::size_of::<NeedleState>());
+            let state = state.cast::<NeedleState>();
+            if state.is_null() {
+                neo_lvgl_sys::lv_obj_delete(ptr);
+                return None;
+            }
+            let min = self.scale_range().0;
+            state.write(NeedleState { r_mod, value: min });
+            neo_lvgl_sys::lv_obj_add_event_cb(
+                ptr,
+                Some(free_state_cb),
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_DELETE,
+                state.cast(),
+            );
+            neo_lvgl_sys::lv_obj_set_style_line_width(ptr, width, main);
+            neo_lvgl_sys::lv_obj_set_style_line_color(ptr, color.raw(), main);
+            neo_lvgl_sys::lv_obj_set_style_line_rounded(ptr, true, main);
+            set_needle(ptr, state, min);
+            Some(MeterNeedle { obj })
+        }
+    }
+
+    /// Move a needle to `value` immediately
+    pub fn set_indicator_value(&self, needle: &MeterNeedle<'a>, value: i32) {
+        let Some(state) = needle.state() else {
+            return;
+        };
+        unsafe {
+            neo_lvgl_sys::lv_anim_delete(needle.obj.raw() as *mut c_void, None);
+            set_needle(needle.obj.raw(), state, value);
+        }
+    }
+
+    /// Animate a needle from its current value to `value`
+    ///
+    /// A `duration_ms` of 0 behaves like
+    /// [`set_indicator_value`](Self::set_indicator_value). Starting a new
+    /// animation cancels the one already running on that needle.
+    pub fn set_value(&self, needle: &MeterNeedle<'a>, value: i32, duration_ms: u32) {
+        if duration_ms == 0 {
+            self.set_indicator_value(needle, value);
+            return;
+        }
+
+        let Some(state) = needle.state() else {
+            return;
+        };
+        let ptr = needle.obj.raw();
+        unsafe {
+            neo_lvgl_sys::lv_anim_delete(ptr as *mut c_void, None);
+        }
+        let current = unsafe { (*state).value };
+
+        let mut anim = Anim::new();
+        anim.set_var(ptr as *mut c_void)
+            .set_values(current, value)
+            .set_duration(duration_ms)
+            .set_path(AnimPath::EaseOut)
+            .set_user_data(state.cast())
+            .set_custom_exec_cb(Some(needle_anim_exec));
+        anim.start();
+    }
+
+    /// Get the value a needle currently points at
+    ///
+    /// Returns the scale's minimum if the needle has been deleted.
+    pub fn indicator_value(&self, needle: &MeterNeedle<'a>) -> i32 {
+        match needle.state() {
+            Some(state) => unsafe { (*state).value },
+            None => self.scale_range().0,
+        }
+    }
+
+    fn scale_range(&self) -> (i32, i32) {
+        unsafe {
+            (
+                neo_lvgl_sys::lv_scale_get_range_min_value(self.scale.raw()),
+                neo_lvgl_sys::lv_scale_get_range_max_value(self.scale.raw()),
+            )
+        }
+    }
+}
+
+/// Point `needle` at `value`, sizing it from its parent scale's radius
+unsafe fn set_needle(
+    needle: *mut neo_lvgl_sys::lv_obj_t,
+    state: *mut NeedleState,
+    value: i32,
+) {
+    let scale = neo_lvgl_sys::lv_obj_get_parent(needle);
+    let radius = neo_lvgl_sys::lv_obj_get_content_width(scale)
+        .min(neo_lvgl_sys::lv_obj_get_content_height(scale))
+        / 2;
+    let length = (radius + (*state).r_mod).max(0);
+    neo_lvgl_sys::lv_scale_set_line_needle_value(scale, needle, length, value);
+    (*state).value = value;
+}
+
+unsafe extern "C" fn needle_anim_exec(anim: *mut neo_lvgl_sys::lv_anim_t, value: i32) {
+    let needle = (*anim).var as *mut neo_lvgl_sys::lv_obj_t;
+    let state = neo_lvgl_sys::lv_anim_get_user_data(anim).cast::<NeedleState>();
+    set_needle(needle, state, value);
+}
+
+/// Free the needle state when the needle is deleted
+unsafe extern "C" fn free_state_cb(e: *mut neo_lvgl_sys::lv_event_t) {
+    neo_lvgl_sys::lv_free(neo_lvgl_sys::lv_event_get_user_data(e));
+}
+
+impl<'a> Widget<'a> for Meter<'a> {
+    fn obj(&self) -> &Obj<'a> {
+        self.scale.obj()
+    }
+}
+
+impl EventHandler for Meter<'_> {
+    fn obj_raw(&self) -> *mut neo_lvgl_sys::lv_obj_t {
+        self.scale.raw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+
+    #[test]
+    fn test_needle_value_round_trip() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let meter = Meter::new(&screen).unwrap();
+        meter.set_size(200, 200);
+        let needle = meter.add_needle_line(4, Color::rgb(255, 0, 0), -10).unwrap();
+        assert_eq!(meter.indicator_value(&needle), 0);
+
+        meter.set_indicator_value(&needle, 42);
+        assert_eq!(meter.indicator_value(&needle), 42);
+
+        meter.set_value(&needle, 80, 100);
+        for _ in 0..5 {
+            crate::tick_inc(50);
+            crate::task_handler();
+        }
+        assert_eq!(meter.indicator_value(&needle), 80);
+    }
+
+    #[test]
+    fn test_deleted_needle_is_ignored() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let meter = Meter::new(&screen).unwrap();
+        meter.scale().set_range(20, 100);
+        let needle = meter.add_needle_line(4, Color::rgb(255, 0, 0), -10).unwrap();
+        meter.set_indicator_value(&needle, 42);
+
+        unsafe { needle.delete() };
+        meter.set_indicator_value(&needle, 60);
+        meter.set_value(&needle, 80, 100);
+        crate::tick_inc(200);
+        crate::task_handler();
+        assert_eq!(meter.indicator_value(&needle), 20);
+    }
+}
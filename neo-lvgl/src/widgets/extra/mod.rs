@@ -16,6 +16,8 @@ mod list;
 #[cfg(feature = "widgets-extra")]
 mod menu;
 #[cfg(feature = "widgets-extra")]
+mod meter;
+#[cfg(feature = "widgets-extra")]
 mod msgbox;
 #[cfg(feature = "widgets-extra")]
 mod scale;
@@ -37,7 +39,7 @@ mod window;
 #[cfg(feature = "widgets-extra")]
 pub use calendar::{Calendar, CalendarDate};
 #[cfg(feature = "widgets-extra")]
-pub use chart::{Chart, ChartAxis, ChartSeries, ChartType};
+pub use chart::{Chart, ChartAxis, ChartSeries, ChartType, CHART_POINT_NONE};
 #[cfg(feature = "widgets-extra")]
 pub use keyboard::{Keyboard, KeyboardMode};
 #[cfg(feature = "widgets-extra")]
@@ -47,6 +49,8 @@ pub use list::{List, ListButton, ListText};
 #[cfg(feature = "widgets-extra")]
 pub use menu::{Menu, MenuPage, MenuSection, MenuSeparator};
 #[cfg(feature = "widgets-extra")]
+pub use meter::{Meter, MeterNeedle};
+#[cfg(feature = "widgets-extra")]
 pub use msgbox::MsgBox;
 #[cfg(feature = "widgets-extra")]
 pub use scale::{Scale, ScaleMode, ScaleSectionDescr};
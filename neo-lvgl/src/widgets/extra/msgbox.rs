@@ -62,6 +62,16 @@ impl<'a> MsgBox<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(
+        parent: &'a impl Widget<'a>,
+        title: &CStr,
+        text: &CStr,
+        add_close_btn: bool,
+    ) -> Result<Self, crate::LvError> {
+        Self::new(parent, title, text, add_close_btn).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Add a button to the message box footer
     pub fn add_button(&self, text: &CStr) -> Option<Obj<'a>> {
         unsafe {
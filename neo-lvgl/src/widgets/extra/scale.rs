@@ -71,6 +71,11 @@ impl<'a> Scale<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the scale mode
     pub fn set_mode(&self, mode: ScaleMode) {
         unsafe {
@@ -154,6 +159,49 @@ impl<'a> Scale<'a> {
         }
     }
 
+    /// Keep a needle pointing at the value of `subject`
+    ///
+    /// The needle is set right away and on every change of the subject.
+    /// With `Some(length)` it is treated as a line needle of that length,
+    /// with `None` as an image needle. It must be a child of this scale.
+    ///
+    /// Values map to angles through the scale's range: `min` points at the
+    /// scale's rotation, `max` at rotation plus the angle range, with
+    /// linear steps in between. Values outside the range are clamped.
+    ///
+    /// The observer is removed automatically when the needle is deleted.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let needle = Line::new(&scale).unwrap();
+    /// let mut speed = IntSubject::new(0);
+    /// scale.bind_needle_value(needle.obj(), Some(80), &mut speed);
+    /// speed.set(42); // needle moves
+    /// ```
+    pub fn bind_needle_value(
+        &self,
+        needle: &Obj,
+        needle_length: Option<i32>,
+        subject: &mut crate::observer::IntSubject,
+    ) -> Option<crate::observer::Observer> {
+        use crate::observer::Subject;
+
+        let (cb, user_data): (neo_lvgl_sys::lv_observer_cb_t, _) = match needle_length {
+            Some(length) => (Some(line_needle_observer_cb), length as isize),
+            None => (Some(image_needle_observer_cb), 0),
+        };
+        unsafe {
+            let ptr = neo_lvgl_sys::lv_subject_add_observer_obj(
+                subject.raw(),
+                cb,
+                needle.raw(),
+                user_data as *mut core::ffi::c_void,
+            );
+            crate::observer::Observer::from_raw(ptr)
+        }
+    }
+
     /// Set custom text for a specific tick
     ///
     /// # Safety
@@ -171,6 +219,27 @@ impl<'a> Scale<'a> {
     }
 }
 
+unsafe extern "C" fn line_needle_observer_cb(
+    observer: *mut neo_lvgl_sys::lv_observer_t,
+    subject: *mut neo_lvgl_sys::lv_subject_t,
+) {
+    let needle = neo_lvgl_sys::lv_observer_get_target_obj(observer);
+    let length = neo_lvgl_sys::lv_observer_get_user_data(observer) as isize as i32;
+    let scale = neo_lvgl_sys::lv_obj_get_parent(needle);
+    let value = neo_lvgl_sys::lv_subject_get_int(subject);
+    neo_lvgl_sys::lv_scale_set_line_needle_value(scale, needle, length, value);
+}
+
+unsafe extern "C" fn image_needle_observer_cb(
+    observer: *mut neo_lvgl_sys::lv_observer_t,
+    subject: *mut neo_lvgl_sys::lv_subject_t,
+) {
+    let needle = neo_lvgl_sys::lv_observer_get_target_obj(observer);
+    let scale = neo_lvgl_sys::lv_obj_get_parent(needle);
+    let value = neo_lvgl_sys::lv_subject_get_int(subject);
+    neo_lvgl_sys::lv_scale_set_image_needle_value(scale, needle, value);
+}
+
 impl<'a> Widget<'a> for Scale<'a> {
     fn obj(&self) -> &Obj<'a> {
         &self.obj
@@ -113,6 +113,11 @@ impl<'a> SpanGroup<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Add a new span
     pub fn add_span(&self) -> Option<Span> {
         unsafe {
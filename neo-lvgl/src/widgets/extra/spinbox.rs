@@ -30,6 +30,11 @@ impl<'a> Spinbox<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the value
     pub fn set_value(&self, value: i32) {
         unsafe {
@@ -112,6 +117,38 @@ impl<'a> Spinbox<'a> {
         }
     }
 
+    /// Move the value by `steps` times the current step
+    ///
+    /// Intended for rotary encoders: pass the encoder diff, optionally
+    /// scaled with [`EncoderAccel`](crate::indev::EncoderAccel) for larger
+    /// jumps on fast rotation. The result is clamped to the range. With
+    /// rollover enabled, moving past an end the value already sits on wraps
+    /// to the other end, like [`increment`](Self::increment) and
+    /// [`decrement`](Self::decrement) do.
+    ///
+    /// The value is set once and a single `VALUE_CHANGED` event is sent if
+    /// it changed.
+    pub fn apply_encoder(&self, steps: i32) {
+        if steps == 0 {
+            return;
+        }
+        let old = self.value();
+        // lv_spinbox_set_value clamps to the range
+        self.set_value(old.saturating_add(steps.saturating_mul(self.step())));
+        if self.value() == old && self.rollover() {
+            self.set_value(if steps > 0 { i32::MIN } else { i32::MAX });
+        }
+        if self.value() != old {
+            unsafe {
+                neo_lvgl_sys::lv_obj_send_event(
+                    self.obj.raw(),
+                    neo_lvgl_sys::lv_event_code_t_LV_EVENT_VALUE_CHANGED,
+                    core::ptr::null_mut(),
+                );
+            }
+        }
+    }
+
     /// Enable/disable rollover (wrap from max to min and vice versa)
     pub fn set_rollover(&self, enable: bool) {
         unsafe {
@@ -149,4 +186,46 @@ impl EventHandler for Spinbox<'_> {
     }
 }
 
-
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::event::ClosureEventHandler;
+    use core::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_apply_encoder_sets_value_once() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let spinbox = Spinbox::new(&screen).unwrap();
+        spinbox.set_range(0, 100);
+        spinbox.set_step(10);
+        spinbox.set_value(50);
+
+        let changes = Rc::new(Cell::new(0));
+        let sink = changes.clone();
+        spinbox.on_value_changed(move |_| sink.set(sink.get() + 1));
+
+        spinbox.apply_encoder(3);
+        assert_eq!(spinbox.value(), 80);
+        assert_eq!(changes.get(), 1);
+
+        // Clamped at the end, then wrapped by the next step
+        spinbox.set_rollover(true);
+        spinbox.apply_encoder(5);
+        assert_eq!(spinbox.value(), 100);
+        spinbox.apply_encoder(1);
+        assert_eq!(spinbox.value(), 0);
+        spinbox.apply_encoder(-2);
+        assert_eq!(spinbox.value(), 100);
+        assert_eq!(changes.get(), 4);
+
+        // No event when nothing moves
+        spinbox.set_rollover(false);
+        spinbox.apply_encoder(1);
+        assert_eq!(changes.get(), 4);
+    }
+}
@@ -1,6 +1,9 @@
 //! Table widget
 
+use crate::color::{Color, Opacity};
 use crate::event::EventHandler;
+use crate::scroll::ScrollExt;
+use crate::style::StyleSelector;
 use crate::widgets::{Obj, Widget};
 use bitflags::bitflags;
 use core::ffi::CStr;
@@ -59,6 +62,11 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the cell text at the given row and column
     pub fn set_cell_value(&self, row: u32, col: u32, text: &CStr) {
         unsafe {
@@ -142,6 +150,189 @@ impl<'a> Table<'a> {
         }
         (row, col)
     }
+
+    /// Select a cell, as if it had been reached with the arrow keys
+    ///
+    /// The selected cell is drawn with the `ITEMS` part styles of the
+    /// table's focus states, see
+    /// [`set_selected_cell_colors`](Self::set_selected_cell_colors).
+    pub fn set_selected_cell(&self, row: u32, col: u32) {
+        unsafe {
+            neo_lvgl_sys::lv_table_set_selected_cell(self.obj.raw(), row, col);
+        }
+    }
+
+    /// Select a cell and scroll it into view
+    pub fn select_cell(&self, row: u32, col: u32, anim: bool) {
+        self.set_selected_cell(row, col);
+        self.scroll_to_cell(row, col, anim);
+    }
+
+    /// Scroll the table so that a cell is fully visible
+    ///
+    /// Scrolls as little as possible and never past the table's content.
+    /// Does nothing if the cell is out of range.
+    ///
+    /// LVGL doesn't expose the row heights, so they are measured again from
+    /// the cell texts and the `ITEMS` style. If a later LVGL lays rows out
+    /// differently, the scroll can be slightly off.
+    pub fn scroll_to_cell(&self, row: u32, col: u32, anim: bool) {
+        let Some((x1, y1, x2, y2)) = self.cell_rect(row, col) else {
+            return;
+        };
+        let obj = self.obj.raw();
+        let main = neo_lvgl_sys::lv_part_t_LV_PART_MAIN;
+        let pad = |prop| unsafe { style_num(obj, main, prop) };
+        let (width, height) = unsafe {
+            (neo_lvgl_sys::lv_obj_get_width(obj), neo_lvgl_sys::lv_obj_get_height(obj))
+        };
+
+        let dx = scroll_delta(
+            self.scroll_x(),
+            x1,
+            x2,
+            pad(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_LEFT),
+            width - pad(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_RIGHT),
+        );
+        let dy = scroll_delta(
+            self.scroll_y(),
+            y1,
+            y2,
+            pad(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_TOP),
+            height - pad(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_BOTTOM),
+        );
+        if dx != 0 || dy != 0 {
+            // Positive deltas move the content, i.e. scroll back
+            self.scroll_by_bounded(-dx, -dy, anim);
+        }
+    }
+
+    /// Set the background and text color of every cell
+    pub fn set_cell_colors(&self, bg: Color, text: Color) {
+        self.set_items_colors(StyleSelector::ITEMS, bg, text);
+    }
+
+    /// Set the background and text color of the selected cell
+    ///
+    /// LVGL only highlights the selected cell while the table is focused,
+    /// so the colors apply to the `FOCUSED` and `FOCUS_KEY` states of the
+    /// `ITEMS` part. Focus the table through its group, or add
+    /// `State::FOCUSED` to show the selection without one.
+    pub fn set_selected_cell_colors(&self, bg: Color, text: Color) {
+        for state in [StyleSelector::FOCUSED, StyleSelector::FOCUS_KEY] {
+            self.set_items_colors(StyleSelector::ITEMS | state, bg, text);
+        }
+    }
+
+    fn set_items_colors(&self, selector: StyleSelector, bg: Color, text: Color) {
+        let obj = self.obj.raw();
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_bg_color(obj, bg.raw(), selector.bits());
+            neo_lvgl_sys::lv_obj_set_style_bg_opa(obj, Opacity::COVER.raw(), selector.bits());
+            neo_lvgl_sys::lv_obj_set_style_text_color(obj, text.raw(), selector.bits());
+        }
+    }
+
+    /// Get a cell's area relative to the table's unscrolled content
+    ///
+    /// Returns `(x1, y1, x2, y2)`, inclusive, with the table's padding
+    /// included like LVGL's own cell layout.
+    fn cell_rect(&self, row: u32, col: u32) -> Option<(i32, i32, i32, i32)> {
+        if row >= self.row_count() || col >= self.column_count() {
+            return None;
+        }
+        let obj = self.obj.raw();
+        let main = neo_lvgl_sys::lv_part_t_LV_PART_MAIN;
+        let x1 = unsafe { style_num(obj, main, neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_LEFT) }
+            + (0..col).map(|c| self.column_width(c)).sum::<i32>();
+        let y1 = unsafe { style_num(obj, main, neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_TOP) }
+            + (0..row).map(|r| self.row_height(r)).sum::<i32>();
+        Some((
+            x1,
+            y1,
+            x1 + self.column_width(col) - 1,
+            y1 + self.row_height(row) - 1,
+        ))
+    }
+
+    /// Measure a row the way LVGL lays it out
+    ///
+    /// The row heights live in `lv_table_t`, which the bindings don't
+    /// expose, so they are computed again from the cell texts and the
+    /// `ITEMS` part style, following LVGL 9's `get_row_height`. Anything that
+    /// function doesn't account for (e.g. a change in a later LVGL version)
+    /// makes the rows drift from the real layout and `scroll_to_cell` scroll
+    /// slightly off; the tests compare the sum with the laid-out height.
+    fn row_height(&self, row: u32) -> i32 {
+        let obj = self.obj.raw();
+        let items = neo_lvgl_sys::lv_part_t_LV_PART_ITEMS;
+        unsafe {
+            let num = |prop| style_num(obj, items, prop);
+            let font = neo_lvgl_sys::lv_obj_get_style_prop(
+                obj,
+                items,
+                neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TEXT_FONT as u8,
+            )
+            .ptr as *const neo_lvgl_sys::lv_font_t;
+            let letter_space = num(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TEXT_LETTER_SPACE);
+            let line_space = num(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_TEXT_LINE_SPACE);
+            let pad_x = num(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_LEFT)
+                + num(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_RIGHT);
+            let pad_y = num(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_TOP)
+                + num(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_BOTTOM);
+            let line_height = neo_lvgl_sys::lv_font_get_line_height(font);
+
+            let mut height = line_height + pad_y;
+            let cols = self.column_count();
+            let mut col = 0;
+            while col < cols {
+                let start = col;
+                let mut text_width = self.column_width(col);
+                while col + 1 < cols && self.has_cell_ctrl(row, col, TableCellCtrl::MERGE_RIGHT) {
+                    col += 1;
+                    text_width += self.column_width(col);
+                }
+                let crop = self.has_cell_ctrl(row, start, TableCellCtrl::TEXT_CROP);
+                if let Some(text) = self.cell_value(row, start).filter(|_| !crop) {
+                    let mut size = neo_lvgl_sys::lv_point_t { x: 0, y: 0 };
+                    neo_lvgl_sys::lv_text_get_size(
+                        &mut size,
+                        text.as_ptr(),
+                        font,
+                        letter_space,
+                        line_space,
+                        text_width - pad_x,
+                        neo_lvgl_sys::lv_text_flag_t_LV_TEXT_FLAG_NONE,
+                    );
+                    height = height.max(size.y + pad_y);
+                }
+                col += 1;
+            }
+            height
+        }
+    }
+}
+
+/// Read a numeric style property of a part
+unsafe fn style_num(
+    obj: *mut neo_lvgl_sys::lv_obj_t,
+    part: neo_lvgl_sys::lv_part_t,
+    prop: neo_lvgl_sys::_lv_style_id_t,
+) -> i32 {
+    neo_lvgl_sys::lv_obj_get_style_prop(obj, part, prop as u8).num
+}
+
+/// How far to scroll along one axis to bring `start..=end` into the window
+/// between `lead` and `trail` (both relative to the widget's edge)
+fn scroll_delta(scroll: i32, start: i32, end: i32, lead: i32, trail: i32) -> i32 {
+    if start < scroll + lead {
+        start - (scroll + lead)
+    } else if end >= scroll + trail {
+        // Align the end, unless the cell is larger than the window
+        (end + 1 - (scroll + trail)).min(start - (scroll + lead))
+    } else {
+        0
+    }
 }
 
 impl<'a> Widget<'a> for Table<'a> {
@@ -156,4 +347,52 @@ impl EventHandler for Table<'_> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use crate::layout::LayoutExt;
+
+    #[test]
+    fn test_select_off_screen_cell_scrolls_it_into_view() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let table = Table::new(&screen).unwrap();
+        table.set_column_count(3);
+        table.set_row_count(40);
+        for col in 0..3 {
+            table.set_column_width(col, 120);
+        }
+        for row in 0..40 {
+            table.set_cell_value(row, 0, c"Row");
+            table.set_cell_value(row, 2, c"Value");
+        }
+        table.set_cell_value(3, 1, c"A cell with enough text to wrap onto more lines");
+        table.set_size(200, 150);
+        table.update_layout();
+
+        // The measured rows add up to the height LVGL laid out
+        let rows: i32 = (0..40).map(|r| table.row_height(r)).sum();
+        let laid_out = unsafe { neo_lvgl_sys::lv_obj_get_self_height(table.raw()) };
+        assert!((rows - laid_out).abs() <= 1, "{rows} vs {laid_out}");
+
+        table.select_cell(30, 2, false);
+        table.update_layout();
+        assert_eq!(table.selected_cell(), (30, 2));
+
+        let (x1, y1, x2, y2) = table.cell_rect(30, 2).unwrap();
+        let (sx, sy) = (table.scroll_x(), table.scroll_y());
+        assert!(sy > 0 && sx > 0);
+        assert!(x1 >= sx && x2 < sx + table.width(), "x {x1}..{x2} at {sx}");
+        assert!(y1 >= sy && y2 < sy + table.height(), "y {y1}..{y2} at {sy}");
 
+        // Already visible: nothing moves
+        table.scroll_to_cell(30, 2, false);
+        assert_eq!((table.scroll_x(), table.scroll_y()), (sx, sy));
+
+        table.select_cell(0, 0, false);
+        assert_eq!((table.scroll_x(), table.scroll_y()), (0, 0));
+    }
+}
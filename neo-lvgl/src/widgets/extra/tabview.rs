@@ -70,6 +70,15 @@ impl<'a> TabView<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(
+        parent: &'a impl Widget<'a>,
+        pos: TabViewPos,
+        tab_size: i32,
+    ) -> Result<Self, crate::LvError> {
+        Self::new(parent, pos, tab_size).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Add a new tab
     pub fn add_tab(&self, name: &CStr) -> Option<Obj<'a>> {
         unsafe {
@@ -15,7 +15,7 @@ use core::ffi::CStr;
 /// win.add_title(c"My Window");
 ///
 /// // Add buttons to title bar
-/// let close_btn = win.add_button(c"\xef\x80\x8d", 40).unwrap(); // LV_SYMBOL_CLOSE
+/// let close_btn = win.add_button(lvgl::symbols::CLOSE, 40).unwrap();
 ///
 /// // Get content area and add widgets
 /// let content = win.content().unwrap();
@@ -49,6 +49,14 @@ impl<'a> Window<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(
+        parent: &'a impl Widget<'a>,
+        header_height: i32,
+    ) -> Result<Self, crate::LvError> {
+        Self::new(parent, header_height).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Add a title to the window
     pub fn add_title(&self, title: &CStr) -> Option<Obj<'a>> {
         unsafe {
@@ -61,7 +69,7 @@ impl<'a> Window<'a> {
     ///
     /// # Arguments
     ///
-    /// * `icon` - Icon symbol (e.g., LV_SYMBOL_CLOSE)
+    /// * `icon` - Icon symbol (e.g. [`symbols::CLOSE`](crate::symbols::CLOSE))
     /// * `width` - Button width in pixels
     pub fn add_button(&self, icon: &CStr, width: i32) -> Option<Obj<'a>> {
         unsafe {
@@ -1,6 +1,8 @@
 //! Image widget
 
 use super::{Obj, Widget};
+use crate::color::{Color, Opacity};
+use crate::display::ColorFormat;
 use crate::event::EventHandler;
 
 /// Image alignment within the widget
@@ -138,6 +140,142 @@ impl Default for Rotation {
     }
 }
 
+/// Error type for image descriptor construction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageDscError {
+    /// Width or height is zero or larger than LVGL supports
+    InvalidSize,
+    /// The color format cannot be used without extra data (e.g. a palette)
+    UnsupportedFormat,
+    /// `data` is shorter than `stride * height`
+    DataTooSmall,
+}
+
+/// Image descriptor for pixel data that lives in memory
+///
+/// This is the Rust equivalent of a C `lv_image_dsc_t` as generated by LVGL's
+/// image converter, and can be used as the source of an [`Image`] or as a
+/// background image via `Style::set_bg_image_src`.
+///
+/// # Tinted icons from A8 masks
+///
+/// An `A8` image only stores coverage, one byte per pixel. LVGL draws it with
+/// the `image_recolor` color, so a single mask can be tinted per state:
+///
+/// ```ignore
+/// static ICON_DATA: [u8; 24 * 24] = *include_bytes!("icon.a8");
+/// let icon: &'static ImageDsc = Box::leak(Box::new(ImageDsc::a8_mask(24, 24, &ICON_DATA)?));
+///
+/// let img = Image::new(&screen).unwrap();
+/// img.set_src_dsc(icon);
+/// img.set_recolor(Color::hex(0xFF0000));
+/// img.set_recolor_opa(Opacity::COVER);
+/// ```
+pub struct ImageDsc {
+    raw: neo_lvgl_sys::lv_image_dsc_t,
+}
+
+// SAFETY: the descriptor only points at immutable 'static data.
+unsafe impl Send for ImageDsc {}
+unsafe impl Sync for ImageDsc {}
+
+impl ImageDsc {
+    /// Create a descriptor for uncompressed pixel data
+    ///
+    /// Rows are expected to be tightly packed. Indexed formats (`I1`..`I8`)
+    /// are rejected since they need a palette.
+    pub fn new(
+        width: u16,
+        height: u16,
+        format: ColorFormat,
+        data: &'static [u8],
+    ) -> Result<Self, ImageDscError> {
+        if width == 0 || height == 0 {
+            return Err(ImageDscError::InvalidSize);
+        }
+
+        let bits = match format {
+            ColorFormat::I1 | ColorFormat::I2 | ColorFormat::I4 | ColorFormat::I8 => {
+                return Err(ImageDscError::UnsupportedFormat)
+            }
+            other => other.bits_per_pixel() as usize,
+        };
+        let stride = (width as usize * bits).div_ceil(8);
+        if stride > u16::MAX as usize {
+            return Err(ImageDscError::InvalidSize);
+        }
+        if data.len() < stride * height as usize {
+            return Err(ImageDscError::DataTooSmall);
+        }
+
+        Ok(Self::from_parts(width, height, stride as u16, format, 0, data))
+    }
+
+    /// Create a descriptor for an 8-bit alpha mask
+    ///
+    /// See the type-level docs for how to tint it.
+    pub fn a8_mask(width: u16, height: u16, data: &'static [u8]) -> Result<Self, ImageDscError> {
+        Self::new(width, height, ColorFormat::A8, data)
+    }
+
+    /// Create a descriptor for compressed (e.g. RLE) pixel data
+    ///
+    /// `data` must be the output of LVGL's image converter with compression
+    /// enabled, i.e. start with LVGL's compression header. `width`, `height`
+    /// and `format` describe the decompressed image. The data is only checked
+    /// for being non-empty; LVGL validates it when decoding.
+    ///
+    /// Requires `LV_USE_RLE` (or `LV_USE_LZ4`) in `lv_conf.h`.
+    pub fn compressed(
+        width: u16,
+        height: u16,
+        format: ColorFormat,
+        data: &'static [u8],
+    ) -> Result<Self, ImageDscError> {
+        if width == 0 || height == 0 {
+            return Err(ImageDscError::InvalidSize);
+        }
+        if data.is_empty() {
+            return Err(ImageDscError::DataTooSmall);
+        }
+
+        Ok(Self::from_parts(
+            width,
+            height,
+            0,
+            format,
+            neo_lvgl_sys::lv_image_flags_t_LV_IMAGE_FLAGS_COMPRESSED,
+            data,
+        ))
+    }
+
+    fn from_parts(
+        width: u16,
+        height: u16,
+        stride: u16,
+        format: ColorFormat,
+        flags: u32,
+        data: &'static [u8],
+    ) -> Self {
+        let mut raw = neo_lvgl_sys::lv_image_dsc_t::default();
+        raw.header.set_magic(neo_lvgl_sys::LV_IMAGE_HEADER_MAGIC);
+        raw.header.set_cf(format.to_raw() as u32);
+        raw.header.set_flags(flags);
+        raw.header.set_w(width as u32);
+        raw.header.set_h(height as u32);
+        raw.header.set_stride(stride as u32);
+        raw.data_size = data.len() as u32;
+        raw.data = data.as_ptr();
+        Self { raw }
+    }
+
+    /// Get the raw descriptor pointer
+    #[inline]
+    pub fn raw(&self) -> *const neo_lvgl_sys::lv_image_dsc_t {
+        &self.raw
+    }
+}
+
 /// Image widget
 ///
 /// Displays an image from various sources.
@@ -146,7 +284,7 @@ impl Default for Rotation {
 ///
 /// ```ignore
 /// let img = Image::new(&screen).unwrap();
-/// img.set_src_symbol(lvgl::SYMBOL_OK);
+/// img.set_src_symbol(lvgl::symbols::OK);
 /// img.set_rotation(Rotation::degrees(45));
 /// img.set_scale(Scale::percent(150));
 /// ```
@@ -164,6 +302,18 @@ impl<'a> Image<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
+    /// Create an Image from an existing Obj
+    ///
+    /// This is used internally for images created on a system layer.
+    pub(crate) fn from_obj(obj: Obj<'a>) -> Self {
+        Self { obj }
+    }
+
     /// Set the image source (raw pointer to image descriptor or symbol)
     ///
     /// # Safety
@@ -173,6 +323,33 @@ impl<'a> Image<'a> {
         neo_lvgl_sys::lv_image_set_src(self.obj.raw(), src);
     }
 
+    /// Set the image source to an in-memory image descriptor
+    pub fn set_src_dsc(&self, dsc: &'static ImageDsc) {
+        unsafe {
+            neo_lvgl_sys::lv_image_set_src(self.obj.raw(), dsc.raw().cast());
+        }
+    }
+
+    /// Display a symbol glyph such as [`symbols::OK`](crate::symbols::OK)
+    ///
+    /// The glyph is drawn with the image's text font and color. LVGL copies
+    /// the string.
+    pub fn set_src_symbol(&self, symbol: &core::ffi::CStr) {
+        unsafe {
+            neo_lvgl_sys::lv_image_set_src(self.obj.raw(), symbol.as_ptr().cast());
+        }
+    }
+
+    /// Display the contents of a [`DrawBuf`](crate::draw_buf::DrawBuf)
+    ///
+    /// The buffer is borrowed for the image's lifetime, so fill in its
+    /// pixels before attaching it.
+    pub fn set_src_draw_buf(&self, buf: &'a crate::draw_buf::DrawBuf) {
+        unsafe {
+            neo_lvgl_sys::lv_image_set_src(self.obj.raw(), buf.raw() as *const _);
+        }
+    }
+
     /// Set the image offset
     pub fn set_offset(&self, x: i32, y: i32) {
         unsafe {
@@ -202,6 +379,9 @@ impl<'a> Image<'a> {
     }
 
     /// Set uniform scale
+    ///
+    /// Scales the picture inside the widget. This hides
+    /// [`Widget::set_scale`], which scales the whole widget with a style.
     pub fn set_scale(&self, scale: Scale) {
         unsafe {
             neo_lvgl_sys::lv_image_set_scale(self.obj.raw(), scale.to_raw());
@@ -276,14 +456,90 @@ impl<'a> Image<'a> {
     }
 
     /// Get the transformed image width
+    ///
+    /// Includes the image's rotation and scale. This hides
+    /// [`Widget::transformed_width`], which only applies the widget's
+    /// transform styles.
     pub fn transformed_width(&self) -> i32 {
         unsafe { neo_lvgl_sys::lv_image_get_transformed_width(self.obj.raw()) }
     }
 
     /// Get the transformed image height
+    ///
+    /// Hides [`Widget::transformed_height`]; see
+    /// [`transformed_width`](Self::transformed_width).
     pub fn transformed_height(&self) -> i32 {
         unsafe { neo_lvgl_sys::lv_image_get_transformed_height(self.obj.raw()) }
     }
+
+    /// Grow the widget to fit the rotated and scaled image
+    ///
+    /// By default the widget keeps the source image's size, so rotating it
+    /// cuts off the corners wherever the parent clips. With auto size on,
+    /// the widget is resized to [`transformed_width`](Self::transformed_width)
+    /// x [`transformed_height`](Self::transformed_height) whenever the
+    /// source, rotation or scale changes, and the image is centered in it.
+    /// This assumes the default pivot at the image center.
+    ///
+    /// Turning it off restores content sizing.
+    pub fn set_auto_size(&self, enable: bool) {
+        let raw = self.obj.raw();
+        unsafe {
+            neo_lvgl_sys::lv_obj_remove_event_cb(raw, Some(auto_size_cb));
+            if enable {
+                neo_lvgl_sys::lv_image_set_inner_align(raw, ImageAlign::Center.to_raw());
+                neo_lvgl_sys::lv_obj_add_event_cb(
+                    raw,
+                    Some(auto_size_cb),
+                    neo_lvgl_sys::lv_event_code_t_LV_EVENT_REFR_EXT_DRAW_SIZE,
+                    core::ptr::null_mut(),
+                );
+                fit_transformed_size(raw);
+            } else {
+                neo_lvgl_sys::lv_obj_set_size(raw, super::SIZE_CONTENT, super::SIZE_CONTENT);
+            }
+        }
+    }
+
+    /// Set the color used to tint the image
+    ///
+    /// This sets the `image_recolor` local style property on the MAIN part.
+    /// The tint only shows once a non-zero opacity is set with
+    /// [`set_recolor_opa`](Self::set_recolor_opa).
+    pub fn set_recolor(&self, color: Color) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_image_recolor(
+                self.obj.raw(),
+                color.to_raw(),
+                neo_lvgl_sys::lv_part_t_LV_PART_MAIN,
+            );
+        }
+    }
+
+    /// Set how strongly the recolor is mixed into the image
+    ///
+    /// `Opacity::COVER` replaces the image colors entirely, which suits
+    /// monochrome icons.
+    pub fn set_recolor_opa(&self, opa: Opacity) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_image_recolor_opa(
+                self.obj.raw(),
+                opa.to_raw(),
+                neo_lvgl_sys::lv_part_t_LV_PART_MAIN,
+            );
+        }
+    }
+
+    /// Set the opacity of the image itself
+    pub fn set_opa(&self, opa: Opacity) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_image_opa(
+                self.obj.raw(),
+                opa.to_raw(),
+                neo_lvgl_sys::lv_part_t_LV_PART_MAIN,
+            );
+        }
+    }
 }
 
 impl<'a> Widget<'a> for Image<'a> {
@@ -292,10 +548,85 @@ impl<'a> Widget<'a> for Image<'a> {
     }
 }
 
+/// Resize an image widget to its transformed size
+unsafe fn fit_transformed_size(obj: *mut neo_lvgl_sys::lv_obj_t) {
+    let w = neo_lvgl_sys::lv_image_get_transformed_width(obj);
+    let h = neo_lvgl_sys::lv_image_get_transformed_height(obj);
+    if w > 0 && h > 0 {
+        neo_lvgl_sys::lv_obj_set_size(obj, w, h);
+    }
+}
+
+/// Rotation, scale and source changes all refresh the extra draw size
+unsafe extern "C" fn auto_size_cb(e: *mut neo_lvgl_sys::lv_event_t) {
+    fit_transformed_size(neo_lvgl_sys::lv_event_get_current_target(e).cast());
+}
+
 impl EventHandler for Image<'_> {
     fn obj_raw(&self) -> *mut neo_lvgl_sys::lv_obj_t {
         self.obj.raw()
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use std::boxed::Box;
+
+    #[test]
+    fn test_auto_size_fits_rotated_image() {
+        static PIXELS: [u8; 100] = [0xFF; 100];
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(100, 100).unwrap();
+        let dsc: &'static ImageDsc =
+            Box::leak(Box::new(ImageDsc::a8_mask(10, 10, &PIXELS).unwrap()));
+
+        let screen = display.active_screen();
+        let image = Image::new(&screen).unwrap();
+        image.set_src_dsc(dsc);
+        image.set_auto_size(true);
+        image.set_rotation(Rotation::degrees(45));
+        crate::layout::LayoutExt::update_layout(&image);
 
+        // The diagonal of a 10x10 image is ~14.1 px
+        assert!(image.transformed_width() >= 14);
+        assert_eq!(image.width(), image.transformed_width());
+        assert_eq!(image.height(), image.transformed_height());
+
+        image.set_rotation(Rotation::degrees(0));
+        crate::layout::LayoutExt::update_layout(&image);
+        assert_eq!((image.width(), image.height()), (10, 10));
+
+        image.set_auto_size(false);
+        image.set_rotation(Rotation::degrees(45));
+        crate::layout::LayoutExt::update_layout(&image);
+        assert_eq!((image.width(), image.height()), (10, 10));
+    }
+
+    #[test]
+    fn test_a8_mask_tinted_red() {
+        static MASK: [u8; 16] = [0xFF; 16];
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::TestFramebuffer::new(8, 8);
+
+        assert_eq!(
+            ImageDsc::a8_mask(4, 4, &MASK[..8]).err(),
+            Some(ImageDscError::DataTooSmall)
+        );
+        let dsc: &'static ImageDsc = Box::leak(Box::new(ImageDsc::a8_mask(4, 4, &MASK).unwrap()));
+
+        let screen = display.active_screen();
+        let image = Image::new(&screen).unwrap();
+        image.set_src_dsc(dsc);
+        image.set_recolor(Color::rgb(255, 0, 0));
+        image.set_recolor_opa(Opacity::COVER);
+        display.refresh_now();
+
+        assert_eq!(display.pixel(0, 0), [255, 0, 0, 255]);
+    }
+}
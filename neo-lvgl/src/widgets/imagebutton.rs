@@ -53,6 +53,11 @@ impl<'a> ImageButton<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the image sources for a state
     ///
     /// Uses three-part images for scalable buttons (left edge, repeating middle, right edge).
@@ -78,6 +83,11 @@ impl<'a> ImageButton<'a> {
     }
 
     /// Set the state of the image button
+    ///
+    /// This picks which images are shown and hides [`Widget::set_state`],
+    /// which toggles a [`State`](crate::widgets::State) flag. To toggle a
+    /// flag, e.g. to start the transitions of a focus style, call the trait
+    /// method: `Widget::set_state(&button, State::FOCUSED, true)`.
     pub fn set_state(&self, state: ImageButtonState) {
         unsafe {
             neo_lvgl_sys::lv_imagebutton_set_state(self.obj.raw(), state.to_raw());
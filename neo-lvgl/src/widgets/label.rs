@@ -30,6 +30,11 @@ impl<'a> Label<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Create a Label from an existing Obj
     ///
     /// This is used internally when getting a label from another widget.
@@ -153,6 +158,107 @@ impl<'a> Label<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Label<'_> {
+    /// Bind this label's text to an integer subject with custom formatting
+    ///
+    /// `format` is called with the subject's value right away and on every
+    /// change, and its output becomes the label text. Use it when a printf
+    /// format isn't enough, e.g. to show seconds as `HH:MM`.
+    ///
+    /// The closure is freed when the label is deleted or when the returned
+    /// observer is removed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut seconds = IntSubject::new(3725);
+    /// label.bind_text_with(&mut seconds, |s| {
+    ///     CString::new(format!("{:02}:{:02}", s / 3600, s / 60 % 60)).unwrap()
+    /// });
+    /// ```
+    pub fn bind_text_with<F>(
+        &self,
+        subject: &mut crate::observer::IntSubject,
+        format: F,
+    ) -> Option<crate::observer::Observer>
+    where
+        F: FnMut(i32) -> alloc::ffi::CString + 'static,
+    {
+        use crate::observer::Subject;
+        use alloc::boxed::Box;
+
+        let closure: Box<TextFormatFn> = Box::new(Box::new(format));
+        let closure = Box::into_raw(closure);
+
+        let ptr = unsafe {
+            neo_lvgl_sys::lv_subject_add_observer_obj(
+                subject.raw(),
+                Some(text_format_trampoline),
+                self.obj.raw(),
+                closure.cast(),
+            )
+        };
+        if ptr.is_null() {
+            drop(unsafe { Box::from_raw(closure) });
+            return None;
+        }
+
+        unsafe {
+            neo_lvgl_sys::lv_obj_add_event_cb(
+                self.obj.raw(),
+                Some(free_text_format),
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_DELETE,
+                closure.cast(),
+            );
+            crate::observer::Observer::from_raw(ptr)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+type TextFormatFn = alloc::boxed::Box<dyn FnMut(i32) -> alloc::ffi::CString>;
+
+#[cfg(feature = "alloc")]
+unsafe extern "C" fn text_format_trampoline(
+    observer: *mut neo_lvgl_sys::lv_observer_t,
+    subject: *mut neo_lvgl_sys::lv_subject_t,
+) {
+    let closure = neo_lvgl_sys::lv_observer_get_user_data(observer) as *mut TextFormatFn;
+    let label = neo_lvgl_sys::lv_observer_get_target_obj(observer);
+    if closure.is_null() || label.is_null() {
+        return;
+    }
+    let text = (*closure)(neo_lvgl_sys::lv_subject_get_int(subject));
+    neo_lvgl_sys::lv_label_set_text(label, text.as_ptr().cast());
+}
+
+/// Free the closure of [`Label::bind_text_with`] when the label is deleted
+#[cfg(feature = "alloc")]
+unsafe extern "C" fn free_text_format(e: *mut neo_lvgl_sys::lv_event_t) {
+    let closure = neo_lvgl_sys::lv_event_get_user_data(e) as *mut TextFormatFn;
+    drop(alloc::boxed::Box::from_raw(closure));
+}
+
+/// Free the closure of a [`Label::bind_text_with`] observer that was removed
+///
+/// Does nothing unless `user_data` belongs to such a binding on `obj`, so
+/// `Observer::remove` can call it for any observer.
+#[cfg(feature = "alloc")]
+pub(crate) unsafe fn free_removed_text_format(
+    obj: *mut neo_lvgl_sys::lv_obj_t,
+    user_data: *mut core::ffi::c_void,
+) {
+    if obj.is_null() || user_data.is_null() {
+        return;
+    }
+    let removed =
+        neo_lvgl_sys::lv_obj_remove_event_cb_with_user_data(obj, Some(free_text_format), user_data);
+    if removed > 0 {
+        drop(alloc::boxed::Box::from_raw(user_data as *mut TextFormatFn));
+    }
+}
+
 /// Label long mode - how to handle text that doesn't fit
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LabelLongMode {
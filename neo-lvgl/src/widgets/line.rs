@@ -46,6 +46,11 @@ impl<'a> Line<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the points that define the line
     ///
     /// Note: The points array must remain valid for the lifetime of the line widget,
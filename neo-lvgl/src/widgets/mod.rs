@@ -12,11 +12,13 @@ mod bar;
 mod button;
 mod buttonmatrix;
 mod canvas;
+mod card;
 mod checkbox;
+mod container;
 mod dropdown;
 mod image;
 mod imagebutton;
-mod label;
+pub(crate) mod label;
 mod line;
 mod roller;
 mod slider;
@@ -31,25 +33,120 @@ pub use bar::{Bar, BarMode, BarOrientation};
 pub use button::Button;
 pub use buttonmatrix::{ButtonMatrix, ButtonMatrixCtrl, BUTTON_NONE};
 pub use canvas::{Canvas, ColorFormat};
+pub use card::Card;
 pub use checkbox::Checkbox;
+pub use container::Container;
 pub use dropdown::{Dropdown, DropdownDir, OptionPos};
-pub use image::{Image, ImageAlign, Rotation, Scale};
+pub use image::{Image, ImageAlign, ImageDsc, ImageDscError, Rotation, Scale};
 pub use imagebutton::{ImageButton, ImageButtonState};
 pub use label::Label;
 pub use line::{Line, Point};
 pub use roller::{Roller, RollerMode};
-pub use slider::{Slider, SliderMode, SliderOrientation};
+pub use slider::{Slider, SliderKnob, SliderMode, SliderOrientation};
 pub use switch::{Switch, SwitchOrientation};
 pub use textarea::{CursorPos, TextArea};
 
 use crate::event::EventHandler;
-use crate::style::{Style, StyleSelector};
+use crate::style::{BorderSide, Style, StyleSelector};
+use core::ffi::CStr;
 use core::marker::PhantomData;
 use core::ptr::NonNull;
 
+/// Split a newline-separated options string (as used by Roller and Dropdown)
+///
+/// An empty string yields no options and a single trailing newline is ignored.
+pub(crate) fn split_options(options: &CStr) -> impl Iterator<Item = &str> {
+    let text = options.to_str().unwrap_or("");
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    let mut options = text.split('\n');
+    if text.is_empty() {
+        // "".split() yields a single empty item
+        options.next();
+    }
+    options
+}
+
+/// User data of the event callbacks of `obj` that are `cb`
+///
+/// Lets wrappers keep per-object data in an event descriptor, usually the
+/// DELETE callback that frees it, and find it again from the object.
+pub(crate) fn event_user_data(
+    obj: *mut neo_lvgl_sys::lv_obj_t,
+    cb: unsafe extern "C" fn(*mut neo_lvgl_sys::lv_event_t),
+) -> impl Iterator<Item = *mut core::ffi::c_void> {
+    let count = unsafe { neo_lvgl_sys::lv_obj_get_event_count(obj) };
+    (0..count).filter_map(move |i| unsafe {
+        let dsc = neo_lvgl_sys::lv_obj_get_event_dsc(obj, i);
+        // Compare addresses: the callback only identifies the descriptor
+        let found = neo_lvgl_sys::lv_event_dsc_get_cb(dsc).map(|f| f as usize) == Some(cb as usize);
+        found.then(|| neo_lvgl_sys::lv_event_dsc_get_user_data(dsc))
+    })
+}
+
+/// Fill the indicator of `obj` with a copy of `grad` (local style)
+///
+/// The copy is allocated from LVGL's heap and freed when the widget is
+/// deleted or when a later call replaces it.
+pub(crate) fn set_indicator_gradient(
+    obj: *mut neo_lvgl_sys::lv_obj_t,
+    grad: &crate::style::Gradient,
+) {
+    let selector = StyleSelector::INDICATOR.bits();
+    unsafe {
+        let copy = neo_lvgl_sys::lv_malloc(core::mem::size_of::<neo_lvgl_sys::lv_grad_dsc_t>())
+            .cast::<neo_lvgl_sys::lv_grad_dsc_t>();
+        if copy.is_null() {
+            return;
+        }
+        copy.write(grad.to_raw());
+
+        let mut old = core::mem::zeroed::<neo_lvgl_sys::lv_style_value_t>();
+        let res = neo_lvgl_sys::lv_obj_get_local_style_prop(
+            obj,
+            neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BG_GRAD as u8,
+            &mut old,
+            selector,
+        );
+        neo_lvgl_sys::lv_obj_set_style_bg_grad(obj, copy, selector);
+        neo_lvgl_sys::lv_obj_add_event_cb(
+            obj,
+            Some(free_gradient_cb),
+            neo_lvgl_sys::lv_event_code_t_LV_EVENT_DELETE,
+            copy.cast(),
+        );
+
+        // Only free the previous gradient if an earlier call allocated it
+        if res == neo_lvgl_sys::lv_style_res_t_LV_STYLE_RES_FOUND {
+            let old = old.ptr as *mut core::ffi::c_void;
+            let removed = neo_lvgl_sys::lv_obj_remove_event_cb_with_user_data(
+                obj,
+                Some(free_gradient_cb),
+                old,
+            );
+            if removed > 0 {
+                neo_lvgl_sys::lv_free(old);
+            }
+        }
+    }
+}
+
+/// Free a gradient copied by `set_indicator_gradient` when its widget is deleted
+unsafe extern "C" fn free_gradient_cb(e: *mut neo_lvgl_sys::lv_event_t) {
+    neo_lvgl_sys::lv_free(neo_lvgl_sys::lv_event_get_user_data(e));
+}
+
 /// Special size value that makes the widget fit its content
 pub const SIZE_CONTENT: i32 = neo_lvgl_sys::LV_COORD_MAX as i32 | (1 << 29);
 
+/// Encode `percent` of the parent's content size as a size or position value
+///
+/// ```ignore
+/// child.set_size(pct(50), pct(100));
+/// ```
+pub fn pct(percent: i32) -> i32 {
+    unsafe { neo_lvgl_sys::lv_pct(percent) }
+}
+
 /// Base widget type
 ///
 /// `Obj` is a non-owning reference to an LVGL object.
@@ -81,6 +178,47 @@ impl<'a> Obj<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
+    /// Create a transparent container that arranges its children with flex
+    ///
+    /// Background and border are removed; the theme's padding and gaps are
+    /// kept.
+    pub fn new_flex(parent: &'a impl Widget<'a>, flow: crate::layout::FlexFlow) -> Option<Self> {
+        let obj = Self::new_layout_container(parent)?;
+        crate::layout::LayoutExt::set_flex_flow(&obj, flow);
+        Some(obj)
+    }
+
+    /// Create a transparent container that arranges its children in a grid
+    ///
+    /// `cols` and `rows` are grid templates ending with
+    /// [`GRID_TEMPLATE_LAST`](crate::layout::GRID_TEMPLATE_LAST). Background
+    /// and border are removed; the theme's padding and gaps are kept.
+    pub fn new_grid(
+        parent: &'a impl Widget<'a>,
+        cols: &'static [i32],
+        rows: &'static [i32],
+    ) -> Option<Self> {
+        let obj = Self::new_layout_container(parent)?;
+        crate::layout::LayoutExt::set_grid_dsc_array(&obj, cols, rows);
+        Some(obj)
+    }
+
+    fn new_layout_container(parent: &'a impl Widget<'a>) -> Option<Self> {
+        let obj = Self::new(parent)?;
+        let main = StyleSelector::MAIN.bits();
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_bg_opa(obj.raw(), 0, main);
+            neo_lvgl_sys::lv_obj_set_style_border_width(obj.raw(), 0, main);
+            neo_lvgl_sys::lv_obj_set_style_radius(obj.raw(), 0, main);
+        }
+        Some(obj)
+    }
+
     /// Create from raw pointer
     ///
     /// # Safety
@@ -132,6 +270,16 @@ pub trait Widget<'a>: EventHandler {
         self.obj().raw()
     }
 
+    /// Get the screen this widget belongs to
+    fn screen(&self) -> Screen<'a> {
+        unsafe { Screen::from_raw(neo_lvgl_sys::lv_obj_get_screen(self.raw())) }
+    }
+
+    /// Get this widget's index among its parent's children
+    fn index(&self) -> i32 {
+        unsafe { neo_lvgl_sys::lv_obj_get_index(self.raw()) }
+    }
+
     // Positioning
 
     /// Set widget position
@@ -165,6 +313,19 @@ pub trait Widget<'a>: EventHandler {
         unsafe { neo_lvgl_sys::lv_obj_get_y(self.raw()) }
     }
 
+    /// Shift all children by `dx`/`dy` pixels
+    ///
+    /// Moves the children's current coordinates without touching their
+    /// position styles, the way scrolling does, so it suits custom scrolling,
+    /// carousels and parallax effects. The shift is undone the next time the
+    /// children are repositioned, e.g. by a layout update. With
+    /// `ignore_floating`, children with `Flag::FLOATING` stay in place.
+    fn move_children_by(&self, dx: i32, dy: i32, ignore_floating: bool) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_move_children_by(self.raw(), dx, dy, ignore_floating);
+        }
+    }
+
     // Sizing
 
     /// Set widget size
@@ -188,6 +349,21 @@ pub trait Widget<'a>: EventHandler {
         }
     }
 
+    /// Set widget width as a percentage of the parent's content width
+    fn set_width_pct(&self, percent: u8) {
+        self.set_width(pct(percent as i32));
+    }
+
+    /// Set widget height as a percentage of the parent's content height
+    fn set_height_pct(&self, percent: u8) {
+        self.set_height(pct(percent as i32));
+    }
+
+    /// Set widget size as percentages of the parent's content size
+    fn set_size_pct(&self, width: u8, height: u8) {
+        self.set_size(pct(width as i32), pct(height as i32));
+    }
+
     /// Get widget width
     fn width(&self) -> i32 {
         unsafe { neo_lvgl_sys::lv_obj_get_width(self.raw()) }
@@ -198,6 +374,30 @@ pub trait Widget<'a>: EventHandler {
         unsafe { neo_lvgl_sys::lv_obj_get_height(self.raw()) }
     }
 
+    /// Get the drawn width, including the `transform_width` style on both sides
+    ///
+    /// The result is scaled by the `transform_scale_x` style. Image rotation
+    /// and the image's own scale are not taken into account; on an [`Image`]
+    /// the inherent [`Image::transformed_width`] wins method resolution, so
+    /// call `Widget::transformed_width(&image)` for this one.
+    fn transformed_width(&self) -> i32 {
+        use crate::style::StyleProp::{TransformScaleX, TransformWidth};
+        let extra = self.style_prop(TransformWidth, StyleSelector::MAIN).as_int().unwrap_or(0);
+        let scale = self.style_prop(TransformScaleX, StyleSelector::MAIN).as_int().unwrap_or(256);
+        (self.width() + 2 * extra) * scale / 256
+    }
+
+    /// Get the drawn height, including the `transform_height` style on both sides
+    ///
+    /// The result is scaled by the `transform_scale_y` style. See
+    /// [`transformed_width`](Self::transformed_width) for images.
+    fn transformed_height(&self) -> i32 {
+        use crate::style::StyleProp::{TransformHeight, TransformScaleY};
+        let extra = self.style_prop(TransformHeight, StyleSelector::MAIN).as_int().unwrap_or(0);
+        let scale = self.style_prop(TransformScaleY, StyleSelector::MAIN).as_int().unwrap_or(256);
+        (self.height() + 2 * extra) * scale / 256
+    }
+
     /// Set content width (fits content)
     fn set_content_width(&self) {
         unsafe {
@@ -228,6 +428,40 @@ pub trait Widget<'a>: EventHandler {
         }
     }
 
+    /// Center the widget horizontally in its parent, keeping its Y position
+    fn center_x(&self) {
+        unsafe { center_axis(self.raw(), true) };
+    }
+
+    /// Center the widget vertically in its parent, keeping its X position
+    fn center_y(&self) {
+        unsafe { center_axis(self.raw(), false) };
+    }
+
+    /// Center the widget at the top of its parent, `offset` pixels below the
+    /// top edge
+    fn top_center(&self, offset: i32) {
+        self.align(Align::TopMid, 0, offset);
+    }
+
+    /// Center the widget at the bottom of its parent, `offset` pixels above
+    /// the bottom edge
+    fn bottom_center(&self, offset: i32) {
+        self.align(Align::BottomMid, 0, -offset);
+    }
+
+    /// Center the widget at the left of its parent, `offset` pixels right of
+    /// the left edge
+    fn left_center(&self, offset: i32) {
+        self.align(Align::LeftMid, offset, 0);
+    }
+
+    /// Center the widget at the right of its parent, `offset` pixels left of
+    /// the right edge
+    fn right_center(&self, offset: i32) {
+        self.align(Align::RightMid, -offset, 0);
+    }
+
     /// Align widget relative to another widget
     fn align_to(&self, other: &impl Widget<'a>, align: Align, x_offset: i32, y_offset: i32) {
         unsafe {
@@ -244,6 +478,28 @@ pub trait Widget<'a>: EventHandler {
         }
     }
 
+    /// Add a shared style to this widget
+    ///
+    /// The widget keeps the style alive until it is deleted, so the
+    /// `SharedStyle` handle may be dropped right away. On deletion the
+    /// style is removed from the widget before the widget's handle is
+    /// released, so a last drop never resets a style still in its list.
+    #[cfg(feature = "alloc")]
+    fn add_shared_style(&self, style: &crate::style::SharedStyle, selector: StyleSelector) {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        let obj = self.raw();
+        unsafe {
+            neo_lvgl_sys::lv_obj_add_style(obj, style.raw(), selector.bits());
+        }
+        let keep_alive = style.clone();
+        let selector = selector.bits();
+        self.on_event_once(EventCode::Delete, move |_| {
+            unsafe { neo_lvgl_sys::lv_obj_remove_style(obj, keep_alive.raw(), selector) };
+            drop(keep_alive);
+        });
+    }
+
     /// Remove all styles from this widget
     fn remove_all_styles(&self) {
         unsafe {
@@ -251,6 +507,194 @@ pub trait Widget<'a>: EventHandler {
         }
     }
 
+    /// Set the duration of this widget's internal animation (local style)
+    ///
+    /// Animations read the property from the part they animate, so pass
+    /// the matching selector: e.g. `StyleSelector::MAIN` for a scrolling
+    /// Label, `StyleSelector::CURSOR` for the TextArea cursor blink,
+    /// `StyleSelector::INDICATOR` for a Spinner. Widgets that don't animate
+    /// internally ignore it; to blink a custom widget, run an [`Anim`] on
+    /// one of its style properties instead.
+    ///
+    /// [`Anim`]: crate::anim::Anim
+    fn set_style_anim_duration(&self, duration_ms: u32, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_anim_duration(self.raw(), duration_ms, selector.bits());
+        }
+    }
+
+    /// Set the animation template for this widget's internal animation (local style)
+    ///
+    /// See [`set_style_anim_duration`](Self::set_style_anim_duration) for
+    /// which widgets and parts use it.
+    fn set_style_anim(&self, anim: &'static crate::anim::Anim, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_anim(self.raw(), anim.raw(), selector.bits());
+        }
+    }
+
+    /// Set how this widget is blended onto what is behind it (local style)
+    ///
+    /// See [`BlendMode`](crate::style::BlendMode) for render support.
+    fn set_style_blend_mode(&self, mode: crate::style::BlendMode, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_blend_mode(self.raw(), mode.to_raw(), selector.bits());
+        }
+    }
+
+    /// Set a multi-stop background gradient (local style)
+    ///
+    /// The background must also be made visible with a non-zero bg opacity.
+    fn set_style_bg_grad(&self, grad: &'static crate::style::Gradient, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_bg_grad(self.raw(), grad.raw(), selector.bits());
+        }
+    }
+
+    /// Set the extra drawn width on both sides (local style)
+    ///
+    /// Animate it for a "pop" effect on press:
+    ///
+    /// ```ignore
+    /// let mut anim = Anim::new();
+    /// anim.set_var(button.raw().cast())
+    ///     .set_values(0, 6)
+    ///     .set_duration(80)
+    ///     .set_reverse_duration(80)
+    ///     .set_exec_cb(Some(grow_cb)); // calls lv_obj_set_style_transform_width
+    /// anim.start();
+    /// ```
+    fn set_style_transform_width(&self, width: i32, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_transform_width(self.raw(), width, selector.bits());
+        }
+    }
+
+    /// Set the extra drawn height on top and bottom (local style)
+    fn set_style_transform_height(&self, height: i32, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_transform_height(self.raw(), height, selector.bits());
+        }
+    }
+
+    /// Scale the widget uniformly when drawing (local style, 256 = 100%)
+    ///
+    /// Like `transform_width`, scaling doesn't affect the layout. Widgets
+    /// are scaled around their top-left corner unless a pivot is set with
+    /// `lv_obj_set_style_transform_pivot_x/y`. See
+    /// [`animate_scale`](crate::anim::animate_scale) for a zoom effect.
+    fn set_style_transform_scale(&self, scale: i32, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_transform_scale_x(self.raw(), scale, selector.bits());
+            neo_lvgl_sys::lv_obj_set_style_transform_scale_y(self.raw(), scale, selector.bits());
+        }
+    }
+
+    /// Scale the widget uniformly when drawing (256 = 100%)
+    ///
+    /// Shorthand for [`set_style_transform_scale`](Self::set_style_transform_scale)
+    /// on the main part, e.g. `set_scale(230)` for a pressed look.
+    ///
+    /// [`Image::set_scale`] takes precedence on images because inherent
+    /// methods win over trait methods; it scales the picture inside the
+    /// widget. Call `Widget::set_scale(&image, 512)` to scale the whole
+    /// widget instead.
+    fn set_scale(&self, scale: i32) {
+        self.set_style_transform_scale(scale, StyleSelector::MAIN);
+    }
+
+    /// Set the top margin (local style)
+    fn set_style_margin_top(&self, margin: i32, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_margin_top(self.raw(), margin, selector.bits());
+        }
+    }
+
+    /// Set the bottom margin (local style)
+    fn set_style_margin_bottom(&self, margin: i32, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_margin_bottom(self.raw(), margin, selector.bits());
+        }
+    }
+
+    /// Set the left margin (local style)
+    fn set_style_margin_left(&self, margin: i32, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_margin_left(self.raw(), margin, selector.bits());
+        }
+    }
+
+    /// Set the right margin (local style)
+    fn set_style_margin_right(&self, margin: i32, selector: StyleSelector) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_style_margin_right(self.raw(), margin, selector.bits());
+        }
+    }
+
+    /// Set all four margins (local style)
+    fn set_style_margin_all(&self, margin: i32, selector: StyleSelector) {
+        self.set_style_margin_top(margin, selector);
+        self.set_style_margin_bottom(margin, selector);
+        self.set_style_margin_left(margin, selector);
+        self.set_style_margin_right(margin, selector);
+    }
+
+    /// Read the resolved value of a style property
+    ///
+    /// Returns the value LVGL actually uses for `part` in the widget's
+    /// current state, taking local styles, added styles and inheritance into
+    /// account. Properties that are not set anywhere return LVGL's default
+    /// (e.g. 0 for paddings, `Opacity::COVER` for `Opa`), so an unset value
+    /// can't be told apart from one explicitly set to the default.
+    ///
+    /// `part` should be a single part such as `StyleSelector::MAIN`; state
+    /// bits are ignored.
+    fn style_prop(
+        &self,
+        prop: crate::style::StyleProp,
+        part: StyleSelector,
+    ) -> crate::style::StyleValue {
+        let raw = unsafe {
+            neo_lvgl_sys::lv_obj_get_style_prop(
+                self.raw(),
+                part.bits() & neo_lvgl_sys::lv_part_t_LV_PART_ANY,
+                prop.raw(),
+            )
+        };
+        prop.value_from_raw(raw)
+    }
+
+    /// Get the space between the left edge and the content
+    ///
+    /// This is the resolved left padding plus the border width, if the
+    /// border is drawn on the left side. Use it for layout math inside a
+    /// container, where the padding alone is off by the border.
+    fn left_pad(&self) -> i32 {
+        style_space(self.raw(), neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_LEFT, BorderSide::LEFT)
+    }
+
+    /// Get the space between the right edge and the content
+    ///
+    /// See [`left_pad`](Self::left_pad).
+    fn right_pad(&self) -> i32 {
+        style_space(self.raw(), neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_RIGHT, BorderSide::RIGHT)
+    }
+
+    /// Get the space between the top edge and the content
+    ///
+    /// See [`left_pad`](Self::left_pad).
+    fn top_pad(&self) -> i32 {
+        style_space(self.raw(), neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_TOP, BorderSide::TOP)
+    }
+
+    /// Get the space between the bottom edge and the content
+    ///
+    /// See [`left_pad`](Self::left_pad).
+    fn bottom_pad(&self) -> i32 {
+        let pad = neo_lvgl_sys::_lv_style_id_t_LV_STYLE_PAD_BOTTOM;
+        style_space(self.raw(), pad, BorderSide::BOTTOM)
+    }
+
     /// Refresh the style (call after modifying a shared style)
     fn refresh_style(&self) {
         unsafe {
@@ -262,9 +706,76 @@ pub trait Widget<'a>: EventHandler {
         }
     }
 
+    /// Copy local style properties and flags from another widget
+    ///
+    /// Useful for building repeated items from a template widget without
+    /// re-specifying every property.
+    ///
+    /// What is copied:
+    /// - Local style properties (set with `lv_obj_set_style_*`) of every part,
+    ///   for the default state and each single state (`PRESSED`, `CHECKED`, ...)
+    /// - All [`Flag`]s
+    ///
+    /// What is not copied:
+    /// - Local properties set for combined states (e.g. `CHECKED | PRESSED`)
+    /// - Shared styles added with [`add_style`](Self::add_style), which LVGL
+    ///   does not expose for enumeration; add them again on the new widget
+    /// - Widget-specific data such as text, values, or image sources
+    /// - Children
+    ///
+    /// Pointer properties (fonts, image sources, grid templates) are copied
+    /// by reference and must outlive both widgets.
+    fn copy_styles_from(&self, other: &impl Widget<'a>) {
+        const PARTS: [neo_lvgl_sys::lv_part_t; 7] = [
+            neo_lvgl_sys::lv_part_t_LV_PART_MAIN,
+            neo_lvgl_sys::lv_part_t_LV_PART_SCROLLBAR,
+            neo_lvgl_sys::lv_part_t_LV_PART_INDICATOR,
+            neo_lvgl_sys::lv_part_t_LV_PART_KNOB,
+            neo_lvgl_sys::lv_part_t_LV_PART_SELECTED,
+            neo_lvgl_sys::lv_part_t_LV_PART_ITEMS,
+            neo_lvgl_sys::lv_part_t_LV_PART_CURSOR,
+        ];
+
+        let src = other.raw();
+        let dst = self.raw();
+
+        for part in PARTS {
+            let states = core::iter::once(State::DEFAULT).chain(State::all().iter());
+            for state in states {
+                let selector = part as u32 | state.bits();
+                for prop in 1..neo_lvgl_sys::_lv_style_id_t_LV_STYLE_LAST_BUILT_IN_PROP {
+                    unsafe {
+                        let mut value = core::mem::zeroed::<neo_lvgl_sys::lv_style_value_t>();
+                        let res = neo_lvgl_sys::lv_obj_get_local_style_prop(
+                            src,
+                            prop as u8,
+                            &mut value,
+                            selector,
+                        );
+                        if res == neo_lvgl_sys::lv_style_res_t_LV_STYLE_RES_FOUND {
+                            neo_lvgl_sys::lv_obj_set_local_style_prop(dst, prop as u8, value, selector);
+                        }
+                    }
+                }
+            }
+        }
+
+        for flag in Flag::all().iter() {
+            if other.has_flag(flag) {
+                self.add_flag(flag);
+            } else {
+                self.remove_flag(flag);
+            }
+        }
+    }
+
     // Visibility and state
 
     /// Add a state flag
+    ///
+    /// The widget's styles are refreshed right away, starting any
+    /// [`Transition`](crate::style::Transition) set on the styles of the new
+    /// state.
     fn add_state(&self, state: State) {
         unsafe {
             neo_lvgl_sys::lv_obj_add_state(self.raw(), state.bits());
@@ -272,36 +783,96 @@ pub trait Widget<'a>: EventHandler {
     }
 
     /// Remove a state flag
+    ///
+    /// Like [`add_state`](Self::add_state), this starts the transitions of
+    /// the styles that become active.
     fn remove_state(&self, state: State) {
         unsafe {
             neo_lvgl_sys::lv_obj_remove_state(self.raw(), state.bits());
         }
     }
 
+    /// Add or remove a state flag
+    ///
+    /// [`ImageButton::set_state`] has the same name but selects the images
+    /// to show; call this one as `Widget::set_state(&image_button, ...)`.
+    fn set_state(&self, state: State, on: bool) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_state(self.raw(), state.bits(), on);
+        }
+    }
+
     /// Check if a state is active
     fn has_state(&self, state: State) -> bool {
         unsafe { neo_lvgl_sys::lv_obj_has_state(self.raw(), state.bits()) }
     }
 
-    /// Add a flag
+    /// Add one or more flags
+    ///
+    /// Flags can be combined: `add_flag(Flag::CLICKABLE | Flag::SCROLLABLE)`.
     fn add_flag(&self, flag: Flag) {
         unsafe {
             neo_lvgl_sys::lv_obj_add_flag(self.raw(), flag.bits());
         }
     }
 
-    /// Remove a flag
+    /// Remove one or more flags
     fn remove_flag(&self, flag: Flag) {
         unsafe {
             neo_lvgl_sys::lv_obj_remove_flag(self.raw(), flag.bits());
         }
     }
 
-    /// Check if a flag is set
+    /// Check if all of the given flags are set
     fn has_flag(&self, flag: Flag) -> bool {
         unsafe { neo_lvgl_sys::lv_obj_has_flag(self.raw(), flag.bits()) }
     }
 
+    /// Check if at least one of the given flags is set
+    fn has_any_flag(&self, flag: Flag) -> bool {
+        unsafe { neo_lvgl_sys::lv_obj_has_flag_any(self.raw(), flag.bits()) }
+    }
+
+    /// Check if the widget has an edit mode for keypad and encoder input
+    ///
+    /// Editable widgets (sliders, rollers, text areas, ...) take the
+    /// encoder's rotation as input after being clicked; others (buttons,
+    /// checkboxes) only react to the click.
+    fn is_editable(&self) -> bool {
+        unsafe { neo_lvgl_sys::lv_obj_is_editable(self.raw()) }
+    }
+
+    /// Check if the widget type is added to the default group on creation
+    fn is_group_def(&self) -> bool {
+        unsafe { neo_lvgl_sys::lv_obj_is_group_def(self.raw()) }
+    }
+
+    /// Check if the widget can currently receive focus from a group
+    ///
+    /// True when the widget is a member of a group and is neither hidden
+    /// nor disabled.
+    fn is_focusable(&self) -> bool {
+        unsafe {
+            !neo_lvgl_sys::lv_obj_get_group(self.raw()).is_null()
+                && !self.has_flag(Flag::HIDDEN)
+                && !self.has_state(State::DISABLED)
+        }
+    }
+
+    /// Forward this widget's events to its parent as well
+    ///
+    /// Sets `Flag::EVENT_BUBBLE`. A parent can then handle the events of all
+    /// its children in one place, see `ClosureEventHandler::on_child_event`.
+    /// Inside a handler, `Event::target` is the widget that triggered the
+    /// event and `Event::current_target` is the widget the handler is on.
+    fn enable_event_bubbling(&self, enable: bool) {
+        if enable {
+            self.add_flag(Flag::EVENT_BUBBLE);
+        } else {
+            self.remove_flag(Flag::EVENT_BUBBLE);
+        }
+    }
+
     /// Set widget visibility
     fn set_hidden(&self, hidden: bool) {
         if hidden {
@@ -346,6 +917,233 @@ pub trait Widget<'a>: EventHandler {
         }
     }
 
+    /// Invalidate only part of the widget (trigger a partial redraw)
+    ///
+    /// `area` is in absolute screen coordinates and is clipped to the widget.
+    fn invalidate_area(&self, area: &crate::display::Area) {
+        let raw = area.to_raw();
+        unsafe {
+            neo_lvgl_sys::lv_obj_invalidate_area(self.raw(), &raw);
+        }
+    }
+
+    /// Check if any part of `area` (absolute coordinates) is visible on this widget
+    fn is_area_visible(&self, area: &crate::display::Area) -> bool {
+        let mut raw = area.to_raw();
+        unsafe { neo_lvgl_sys::lv_obj_area_is_visible(self.raw(), &mut raw) }
+    }
+
+    /// Animate the widget from its current position to (`x`, `y`)
+    ///
+    /// Both coordinates move together over `duration_ms`, following `path`,
+    /// e.g. to slide a panel in. Replaces a running `move_to` on the same
+    /// widget. The coordinates are relative to the parent, like
+    /// [`set_pos`](Self::set_pos), so a layout on the parent overrides them.
+    ///
+    /// ```ignore
+    /// let slide = panel.move_to(0, 40, 300, AnimPath::EaseOut).unwrap();
+    /// ```
+    fn move_to(
+        &self,
+        x: i32,
+        y: i32,
+        duration_ms: u32,
+        path: crate::anim::AnimPath,
+    ) -> Option<crate::anim::MoveHandle> {
+        crate::anim::MoveHandle::start(self.raw(), x, y, duration_ms, path)
+    }
+
+    /// Fade the widget in from transparent to opaque
+    ///
+    /// Replaces a running `fade_in`/`fade_out` on the same widget.
+    fn fade_in(&self, duration_ms: u32) -> Option<crate::anim::AnimHandle> {
+        let mut anim = crate::anim::Anim::new();
+        anim.set_var(self.raw().cast())
+            .set_values(0, 255)
+            .set_duration(duration_ms)
+            .set_exec_cb(Some(fade_exec));
+        anim.start()
+    }
+
+    /// Fade the widget out to transparent
+    ///
+    /// With `delete_when_done` the widget is deleted once the animation
+    /// completes; handles to it (and its children) must not be used after
+    /// that. Replaces a running `fade_in`/`fade_out` on the same widget.
+    fn fade_out(
+        &self,
+        duration_ms: u32,
+        delete_when_done: bool,
+    ) -> Option<crate::anim::AnimHandle> {
+        let current = unsafe {
+            neo_lvgl_sys::lv_obj_get_style_prop(
+                self.raw(),
+                neo_lvgl_sys::lv_part_t_LV_PART_MAIN,
+                neo_lvgl_sys::_lv_style_id_t_LV_STYLE_OPA as neo_lvgl_sys::lv_style_prop_t,
+            )
+            .num
+        };
+        let mut anim = crate::anim::Anim::new();
+        anim.set_var(self.raw().cast())
+            .set_values(current, 0)
+            .set_duration(duration_ms)
+            .set_exec_cb(Some(fade_exec));
+        if delete_when_done {
+            anim.set_completed_cb(Some(neo_lvgl_sys::lv_obj_delete_anim_completed_cb));
+        }
+        anim.start()
+    }
+
+    /// Make the widget visible, optionally fading it in
+    ///
+    /// Cancels a running [`hide`](Self::hide) fade, so the widget can't be
+    /// hidden again by it. The fade starts from the current opacity, or from
+    /// transparent if the widget was hidden. Without animation the opacity
+    /// is reset to opaque. Returns the fade animation, if one was started.
+    fn show(&self, animated: bool, duration_ms: u32) -> Option<crate::anim::AnimHandle> {
+        let from = if self.is_hidden() { 0 } else { main_opa(self.raw()) };
+        unsafe {
+            neo_lvgl_sys::lv_anim_delete(self.raw().cast(), Some(fade_exec));
+            fade_exec(self.raw().cast(), from);
+        }
+        self.remove_flag(Flag::HIDDEN);
+        if !animated || duration_ms == 0 {
+            unsafe { fade_exec(self.raw().cast(), 255) };
+            return None;
+        }
+        let mut anim = crate::anim::Anim::new();
+        anim.set_var(self.raw().cast())
+            .set_values(from, 255)
+            .set_duration(duration_ms)
+            .set_exec_cb(Some(fade_exec));
+        anim.start()
+    }
+
+    /// Hide the widget, optionally fading it out first
+    ///
+    /// With animation, `Flag::HIDDEN` is only set once the fade completes
+    /// and the opacity is then reset to opaque, so a later
+    /// `set_hidden(false)` shows the widget fully. A [`show`](Self::show)
+    /// during the fade cancels it. Returns the fade animation, if one was
+    /// started.
+    fn hide(&self, animated: bool, duration_ms: u32) -> Option<crate::anim::AnimHandle> {
+        unsafe {
+            neo_lvgl_sys::lv_anim_delete(self.raw().cast(), Some(fade_exec));
+        }
+        if !animated || duration_ms == 0 || self.is_hidden() {
+            self.add_flag(Flag::HIDDEN);
+            return None;
+        }
+        let mut anim = crate::anim::Anim::new();
+        anim.set_var(self.raw().cast())
+            .set_values(main_opa(self.raw()), 0)
+            .set_duration(duration_ms)
+            .set_exec_cb(Some(fade_exec))
+            .set_completed_cb(Some(hide_completed_cb));
+        anim.start()
+    }
+
+    /// Ask the widget how far it draws outside its area
+    ///
+    /// Sends `EventCode::RefreshExtDrawSize` and invalidates the new area.
+    /// Call this when something a handler for that event depends on changes,
+    /// e.g. the radius of a custom glow painted by a draw event handler.
+    fn refresh_ext_draw_size(&self) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_refresh_ext_draw_size(self.raw());
+        }
+    }
+
+    /// Get how many pixels the widget currently draws outside its area
+    fn ext_draw_size(&self) -> i32 {
+        unsafe { neo_lvgl_sys::lv_obj_get_ext_draw_size(self.raw()) }
+    }
+
+    /// Get the parent widget
+    ///
+    /// Returns `None` for screens.
+    fn parent(&self) -> Option<Obj<'a>> {
+        unsafe { Obj::from_raw(neo_lvgl_sys::lv_obj_get_parent(self.raw())) }
+    }
+
+    /// Move the widget (with its children, styles and event handlers) to a new parent
+    ///
+    /// The widget keeps its position relative to the parent's content area
+    /// and is appended as the new parent's last child.
+    ///
+    /// From now on the widget is deleted together with `parent`, not the old
+    /// parent. Handles to it keep the lifetime they were created with, so if
+    /// `parent` can be deleted earlier, stop using them at that point.
+    fn set_parent(&self, parent: &impl Widget<'a>) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_set_parent(self.raw(), parent.raw());
+        }
+    }
+
+    /// Get the number of children
+    fn child_count(&self) -> u32 {
+        unsafe { neo_lvgl_sys::lv_obj_get_child_count(self.raw()) }
+    }
+
+    /// Find the first descendant whose class is exactly `class`
+    ///
+    /// Children are searched depth-first, in child order, so a direct child
+    /// is found before the children of an earlier sibling's children. Useful
+    /// to reach a part of a widget built by XML or a theme:
+    ///
+    /// ```ignore
+    /// let class = unsafe { core::ptr::addr_of!(neo_lvgl_sys::lv_label_class) };
+    /// let label = button.find_child_of_class(class);
+    /// ```
+    fn find_child_of_class(&self, class: *const neo_lvgl_sys::lv_obj_class_t) -> Option<Obj<'a>> {
+        unsafe { Obj::from_raw(find_of_class(self.raw(), class)) }
+    }
+
+    /// Get the name of this widget's type, e.g. `"Button"`
+    ///
+    /// Known LVGL classes map to the name of their wrapper type; a base
+    /// object without a parent is a `"Screen"`. Classes defined elsewhere
+    /// (custom widgets, or parts without a wrapper) give `"unknown"`. Handy
+    /// to check what a lookup such as `NameExt::find_by_name` returned.
+    fn class_name(&self) -> &'static str {
+        unsafe {
+            let class = neo_lvgl_sys::lv_obj_get_class(self.raw());
+            if class == core::ptr::addr_of!(neo_lvgl_sys::lv_obj_class)
+                && neo_lvgl_sys::lv_obj_get_parent(self.raw()).is_null()
+            {
+                return "Screen";
+            }
+            class_name_of(class)
+        }
+    }
+
+    /// Delete all children, keeping the widget itself
+    ///
+    /// Handles to the deleted children must not be used afterwards.
+    fn clean(&self) {
+        unsafe {
+            neo_lvgl_sys::lv_obj_clean(self.raw());
+        }
+    }
+
+    /// Describe this widget and all its descendants, one per line
+    ///
+    /// Each line shows the class, the name (if set), the position relative
+    /// to the parent and the size, indented by depth. Hidden widgets are
+    /// marked, which helps when tracking down a widget that doesn't show up.
+    ///
+    /// ```text
+    /// lv_obj (0, 0) 320x240
+    ///   lv_button "ok" (110, 95) 100x50
+    ///     lv_label (32, 16) 36x17
+    /// ```
+    #[cfg(feature = "debug")]
+    fn dump_tree(&self) -> alloc::string::String {
+        let mut out = alloc::string::String::new();
+        unsafe { dump_node(&mut out, self.raw(), 0) };
+        out
+    }
+
     /// Delete the widget
     ///
     /// # Safety
@@ -359,6 +1157,185 @@ pub trait Widget<'a>: EventHandler {
     }
 }
 
+/// Map a known LVGL class to the name of its wrapper type
+unsafe fn class_name_of(class: *const neo_lvgl_sys::lv_obj_class_t) -> &'static str {
+    use core::ptr::addr_of;
+    use neo_lvgl_sys::*;
+
+    let known = [
+        (addr_of!(lv_obj_class), "Obj"),
+        (addr_of!(lv_arc_class), "Arc"),
+        (addr_of!(lv_bar_class), "Bar"),
+        (addr_of!(lv_button_class), "Button"),
+        (addr_of!(lv_buttonmatrix_class), "ButtonMatrix"),
+        (addr_of!(lv_canvas_class), "Canvas"),
+        (addr_of!(lv_checkbox_class), "Checkbox"),
+        (addr_of!(lv_dropdown_class), "Dropdown"),
+        (addr_of!(lv_image_class), "Image"),
+        (addr_of!(lv_imagebutton_class), "ImageButton"),
+        (addr_of!(lv_label_class), "Label"),
+        (addr_of!(lv_line_class), "Line"),
+        (addr_of!(lv_roller_class), "Roller"),
+        (addr_of!(lv_slider_class), "Slider"),
+        (addr_of!(lv_switch_class), "Switch"),
+        (addr_of!(lv_textarea_class), "TextArea"),
+        (addr_of!(lv_calendar_class), "Calendar"),
+        (addr_of!(lv_chart_class), "Chart"),
+        (addr_of!(lv_keyboard_class), "Keyboard"),
+        (addr_of!(lv_led_class), "Led"),
+        (addr_of!(lv_list_class), "List"),
+        (addr_of!(lv_menu_class), "Menu"),
+        (addr_of!(lv_msgbox_class), "MsgBox"),
+        (addr_of!(lv_scale_class), "Scale"),
+        (addr_of!(lv_spangroup_class), "SpanGroup"),
+        (addr_of!(lv_spinbox_class), "Spinbox"),
+        (addr_of!(lv_spinner_class), "Spinner"),
+        (addr_of!(lv_table_class), "Table"),
+        (addr_of!(lv_tabview_class), "TabView"),
+        (addr_of!(lv_tileview_class), "TileView"),
+        (addr_of!(lv_win_class), "Window"),
+    ];
+    known
+        .iter()
+        .find(|(known, _)| *known == class)
+        .map_or("unknown", |(_, name)| name)
+}
+
+/// Depth-first search below `obj`, returning null if nothing matches
+unsafe fn find_of_class(
+    obj: *mut neo_lvgl_sys::lv_obj_t,
+    class: *const neo_lvgl_sys::lv_obj_class_t,
+) -> *mut neo_lvgl_sys::lv_obj_t {
+    for i in 0..neo_lvgl_sys::lv_obj_get_child_count(obj) {
+        let child = neo_lvgl_sys::lv_obj_get_child(obj, i as i32);
+        if neo_lvgl_sys::lv_obj_get_class(child) == class {
+            return child;
+        }
+        let found = find_of_class(child, class);
+        if !found.is_null() {
+            return found;
+        }
+    }
+    core::ptr::null_mut()
+}
+
+/// Append one line for `obj` and recurse into its children
+#[cfg(feature = "debug")]
+unsafe fn dump_node(
+    out: &mut alloc::string::String,
+    obj: *mut neo_lvgl_sys::lv_obj_t,
+    depth: usize,
+) {
+    use core::fmt::Write;
+
+    let class = neo_lvgl_sys::lv_obj_get_class(obj);
+    let class_name = if class.is_null() || (*class).name.is_null() {
+        "?"
+    } else {
+        CStr::from_ptr((*class).name).to_str().unwrap_or("?")
+    };
+    let _ = write!(out, "{:indent$}{}", "", class_name, indent = depth * 2);
+
+    let name = neo_lvgl_sys::lv_obj_get_name(obj);
+    if !name.is_null() {
+        let _ = write!(out, " {:?}", CStr::from_ptr(name.cast()).to_str().unwrap_or("?"));
+    }
+    let _ = write!(
+        out,
+        " ({}, {}) {}x{}",
+        neo_lvgl_sys::lv_obj_get_x(obj),
+        neo_lvgl_sys::lv_obj_get_y(obj),
+        neo_lvgl_sys::lv_obj_get_width(obj),
+        neo_lvgl_sys::lv_obj_get_height(obj),
+    );
+    if neo_lvgl_sys::lv_obj_has_flag(obj, neo_lvgl_sys::lv_obj_flag_t_LV_OBJ_FLAG_HIDDEN) {
+        out.push_str(" hidden");
+    }
+    out.push('\n');
+
+    for i in 0..neo_lvgl_sys::lv_obj_get_child_count(obj) {
+        dump_node(out, neo_lvgl_sys::lv_obj_get_child(obj, i as i32), depth + 1);
+    }
+}
+
+/// Center `obj` on one axis and keep the alignment and offset of the other
+unsafe fn center_axis(obj: *mut neo_lvgl_sys::lv_obj_t, horizontal: bool) {
+    use neo_lvgl_sys::*;
+
+    let main = lv_part_t_LV_PART_MAIN;
+    let prop = |id: u32| lv_obj_get_style_prop(obj, main, id as lv_style_prop_t).num;
+    let align = prop(_lv_style_id_t_LV_STYLE_ALIGN) as lv_align_t;
+    let (x, y) = (prop(_lv_style_id_t_LV_STYLE_X), prop(_lv_style_id_t_LV_STYLE_Y));
+
+    if horizontal {
+        let align = match align {
+            lv_align_t_LV_ALIGN_LEFT_MID
+            | lv_align_t_LV_ALIGN_CENTER
+            | lv_align_t_LV_ALIGN_RIGHT_MID => lv_align_t_LV_ALIGN_CENTER,
+            lv_align_t_LV_ALIGN_BOTTOM_LEFT
+            | lv_align_t_LV_ALIGN_BOTTOM_MID
+            | lv_align_t_LV_ALIGN_BOTTOM_RIGHT => lv_align_t_LV_ALIGN_BOTTOM_MID,
+            _ => lv_align_t_LV_ALIGN_TOP_MID,
+        };
+        lv_obj_align(obj, align, 0, y);
+    } else {
+        let align = match align {
+            lv_align_t_LV_ALIGN_TOP_MID
+            | lv_align_t_LV_ALIGN_CENTER
+            | lv_align_t_LV_ALIGN_BOTTOM_MID => lv_align_t_LV_ALIGN_CENTER,
+            lv_align_t_LV_ALIGN_TOP_RIGHT
+            | lv_align_t_LV_ALIGN_RIGHT_MID
+            | lv_align_t_LV_ALIGN_BOTTOM_RIGHT => lv_align_t_LV_ALIGN_RIGHT_MID,
+            _ => lv_align_t_LV_ALIGN_LEFT_MID,
+        };
+        lv_obj_align(obj, align, x, 0);
+    }
+}
+
+/// Anim exec callback setting the main part's opacity
+pub(crate) unsafe extern "C" fn fade_exec(var: *mut core::ffi::c_void, value: i32) {
+    let main = neo_lvgl_sys::lv_part_t_LV_PART_MAIN;
+    neo_lvgl_sys::lv_obj_set_style_opa(var.cast(), value as u8, main);
+}
+
+/// Padding on one side of the main part, plus the border if drawn there
+///
+/// Mirrors LVGL's inline `lv_obj_get_style_space_*` getters.
+fn style_space(obj: *mut neo_lvgl_sys::lv_obj_t, pad: u32, side: BorderSide) -> i32 {
+    let main = neo_lvgl_sys::lv_part_t_LV_PART_MAIN;
+    let prop = |id: u32| unsafe {
+        neo_lvgl_sys::lv_obj_get_style_prop(obj, main, id as neo_lvgl_sys::lv_style_prop_t).num
+    };
+    let padding = prop(pad);
+    let sides = BorderSide::from_bits_truncate(
+        prop(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BORDER_SIDE) as u8,
+    );
+    if sides.contains(side) {
+        padding + prop(neo_lvgl_sys::_lv_style_id_t_LV_STYLE_BORDER_WIDTH)
+    } else {
+        padding
+    }
+}
+
+/// Get the opacity of the main part
+fn main_opa(obj: *mut neo_lvgl_sys::lv_obj_t) -> i32 {
+    unsafe {
+        neo_lvgl_sys::lv_obj_get_style_prop(
+            obj,
+            neo_lvgl_sys::lv_part_t_LV_PART_MAIN,
+            neo_lvgl_sys::_lv_style_id_t_LV_STYLE_OPA as neo_lvgl_sys::lv_style_prop_t,
+        )
+        .num
+    }
+}
+
+/// Anim completed callback of `Widget::hide`
+unsafe extern "C" fn hide_completed_cb(anim: *mut neo_lvgl_sys::lv_anim_t) {
+    let obj = (*anim).var as *mut neo_lvgl_sys::lv_obj_t;
+    neo_lvgl_sys::lv_obj_add_flag(obj, neo_lvgl_sys::lv_obj_flag_t_LV_OBJ_FLAG_HIDDEN);
+    fade_exec(obj.cast(), 255);
+}
+
 /// Screen widget (root of the widget tree)
 #[derive(Clone, Copy)]
 pub struct Screen<'a> {
@@ -404,6 +1381,64 @@ impl<'a> Screen<'a> {
     }
 }
 
+impl Screen<'static> {
+    /// Create a new screen on the default display
+    ///
+    /// The screen isn't shown until it is loaded. It lives until it is
+    /// deleted, either explicitly or by loading another screen with
+    /// `auto_delete`.
+    pub fn new() -> Option<Self> {
+        unsafe {
+            let ptr = neo_lvgl_sys::lv_obj_create(core::ptr::null_mut());
+            Obj::from_raw(ptr).map(|obj| Self { obj })
+        }
+    }
+
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new() -> Result<Self, crate::LvError> {
+        Self::new().ok_or(crate::LvError::OutOfMemory)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Screen<'_> {
+    /// Call `handler` every time this screen finishes loading.
+    ///
+    /// `EventCode::ScreenLoaded` is sent once the screen is fully visible,
+    /// i.e. after any load animation has completed.
+    pub fn on_loaded<F>(&self, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        self.on_event_closure(EventCode::ScreenLoaded, move |_| handler());
+    }
+
+    /// Load this screen with animation and call `on_done` once it is fully visible.
+    ///
+    /// `on_done` runs once, for this load only.
+    ///
+    /// If `auto_delete` is set, the previous screen is deleted by LVGL right
+    /// after `on_done` returns. Any `Screen` or widget handles referring to
+    /// the previous screen must not be used after that point.
+    pub fn load_anim_then<F>(
+        &self,
+        anim: ScreenLoadAnim,
+        time_ms: u32,
+        delay_ms: u32,
+        auto_delete: bool,
+        on_done: F,
+    ) where
+        F: FnOnce() + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        self.on_event_once(EventCode::ScreenLoaded, move |_| on_done());
+        self.load_anim(anim, time_ms, delay_ms, auto_delete);
+    }
+}
+
 impl<'a> Widget<'a> for Screen<'a> {
     fn obj(&self) -> &Obj<'a> {
         &self.obj
@@ -578,3 +1613,403 @@ impl ScreenLoadAnim {
 }
 
 // Implement FromObj for Screen
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+
+    unsafe extern "C" fn grow_cb(var: *mut core::ffi::c_void, value: i32) {
+        neo_lvgl_sys::lv_obj_set_style_transform_width(var.cast(), value, 0);
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    fn test_dump_tree() {
+        use crate::xml::NameExt;
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let button = Button::new(&screen).unwrap();
+        button.set_name(c"ok");
+        button.set_pos(10, 20);
+        button.set_size(100, 50);
+        let label = Label::new(&button).unwrap();
+        label.add_flag(Flag::HIDDEN);
+        crate::layout::LayoutExt::update_layout(&screen);
+
+        let dump = button.dump_tree();
+        let mut lines = dump.lines();
+        assert_eq!(lines.next(), Some("lv_button \"ok\" (10, 20) 100x50"));
+        let child = lines.next().unwrap();
+        assert!(child.starts_with("  lv_label ("), "{child}");
+        assert!(child.ends_with(" hidden"), "{child}");
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_new_flex() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let row = Obj::new_flex(&screen, crate::layout::FlexFlow::Row).unwrap();
+        row.set_size(300, 100);
+        let first = Button::new(&row).unwrap();
+        let second = Button::new(&row).unwrap();
+        first.set_size(40, 20);
+        second.set_size(40, 20);
+        crate::layout::LayoutExt::update_layout(&row);
+
+        assert_eq!(first.y(), second.y());
+        assert!(second.x() >= first.x() + 40);
+        let bg = row.style_prop(crate::style::StyleProp::BgOpa, StyleSelector::MAIN);
+        assert_eq!(bg.as_opa(), Some(crate::color::Opacity::TRANSPARENT));
+        let border = row.style_prop(crate::style::StyleProp::BorderWidth, StyleSelector::MAIN);
+        assert_eq!(border.as_int(), Some(0));
+    }
+
+    #[test]
+    fn test_new_grid() {
+        use crate::layout::{GridAlign, LayoutExt, GRID_TEMPLATE_LAST};
+
+        static COLS: [i32; 3] = [100, 100, GRID_TEMPLATE_LAST];
+        static ROWS: [i32; 3] = [50, 50, GRID_TEMPLATE_LAST];
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let grid = Obj::new_grid(&screen, &COLS, &ROWS).unwrap();
+        grid.set_size(300, 200);
+        let top_right = Button::new(&grid).unwrap();
+        let bottom_left = Button::new(&grid).unwrap();
+        top_right.set_grid_cell(GridAlign::Stretch, 1, 1, GridAlign::Stretch, 0, 1);
+        bottom_left.set_grid_cell(GridAlign::Stretch, 0, 1, GridAlign::Stretch, 1, 1);
+        grid.update_layout();
+
+        assert!(top_right.x() >= bottom_left.x() + 100);
+        assert!(bottom_left.y() >= top_right.y() + 50);
+        assert_eq!((top_right.width(), top_right.height()), (100, 50));
+    }
+
+    #[test]
+    fn test_axis_and_edge_alignment() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let parent = Container::new(&screen).unwrap();
+        parent.set_size(200, 100);
+        let child = Container::new(&parent).unwrap();
+        child.set_size(20, 10);
+        child.set_pos(4, 6);
+
+        let pos = || {
+            crate::layout::LayoutExt::update_layout(&parent);
+            (child.x(), child.y())
+        };
+        child.center_x();
+        assert_eq!(pos(), (90, 6));
+        child.center_y();
+        assert_eq!(pos(), (90, 45));
+
+        child.top_center(8);
+        assert_eq!(pos(), (90, 8));
+        child.bottom_center(8);
+        assert_eq!(pos(), (90, 82));
+        child.left_center(5);
+        assert_eq!(pos(), (5, 45));
+        child.right_center(5);
+        assert_eq!(pos(), (175, 45));
+    }
+
+    #[test]
+    fn test_animated_hide_sets_flag_after_fade() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        let step = |ms| {
+            crate::tick_inc(ms);
+            crate::task_handler();
+        };
+
+        assert!(panel.hide(true, 100).is_some());
+        step(50);
+        assert!(!panel.is_hidden());
+        step(100);
+        assert!(panel.is_hidden());
+        assert_eq!(main_opa(panel.raw()), 255);
+
+        // Showing during the fade cancels the pending hide
+        panel.show(true, 100);
+        assert!(!panel.is_hidden());
+        panel.hide(true, 100);
+        step(50);
+        assert!(panel.show(false, 0).is_none());
+        step(100);
+        assert!(!panel.is_hidden());
+        assert_eq!(main_opa(panel.raw()), 255);
+    }
+
+    #[test]
+    fn test_find_child_of_class() {
+        use core::ptr::addr_of;
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let button = Button::new(&screen).unwrap();
+        let label = Label::new(&button).unwrap();
+
+        let label_class = unsafe { addr_of!(neo_lvgl_sys::lv_label_class) };
+        let slider_class = unsafe { addr_of!(neo_lvgl_sys::lv_slider_class) };
+
+        let found = button.find_child_of_class(label_class);
+        assert_eq!(found.map(|obj| obj.raw()), Some(label.raw()));
+        let found = screen.find_child_of_class(label_class);
+        assert_eq!(found.map(|obj| obj.raw()), Some(label.raw()));
+        assert!(button.find_child_of_class(slider_class).is_none());
+    }
+
+    #[test]
+    fn test_style_space_includes_border() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Obj::new(&screen).unwrap();
+        let mut style = Style::new();
+        style.set_pad_all(10);
+        style.set_border_width(3);
+        style.set_border_side(BorderSide::LEFT | BorderSide::TOP);
+        panel.add_style(&style, StyleSelector::MAIN);
+
+        assert_eq!(panel.left_pad(), 13);
+        assert_eq!(panel.top_pad(), 13);
+        assert_eq!(panel.right_pad(), 10);
+        assert_eq!(panel.bottom_pad(), 10);
+
+        // Values for the current state win
+        let mut checked = Style::new();
+        checked.set_pad_left(20);
+        panel.add_style(&checked, StyleSelector::MAIN | StyleSelector::CHECKED);
+        panel.add_state(State::CHECKED);
+        assert_eq!(panel.left_pad(), 23);
+    }
+
+    #[test]
+    fn test_class_name() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let button = Button::new(&screen).unwrap();
+        let label = Label::new(&button).unwrap();
+        let slider = Slider::new(&screen).unwrap();
+        let plain = Obj::new(&screen).unwrap();
+
+        assert_eq!(screen.class_name(), "Screen");
+        assert_eq!(button.class_name(), "Button");
+        assert_eq!(label.class_name(), "Label");
+        assert_eq!(slider.class_name(), "Slider");
+        assert_eq!(plain.class_name(), "Obj");
+
+        // The dropdown's list is a class without a wrapper
+        let dropdown = Dropdown::new(&screen).unwrap();
+        assert_eq!(dropdown.class_name(), "Dropdown");
+        let list = unsafe { Obj::from_raw(neo_lvgl_sys::lv_dropdown_get_list(dropdown.raw())) };
+        assert_eq!(list.unwrap().class_name(), "unknown");
+    }
+
+    #[test]
+    fn test_combined_flags() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        panel.remove_flag(Flag::CLICKABLE | Flag::SCROLLABLE);
+        assert!(!panel.has_any_flag(Flag::CLICKABLE | Flag::SCROLLABLE));
+
+        panel.add_flag(Flag::CLICKABLE | Flag::SCROLLABLE);
+        assert!(panel.has_flag(Flag::CLICKABLE));
+        assert!(panel.has_flag(Flag::SCROLLABLE));
+        assert!(panel.has_flag(Flag::CLICKABLE | Flag::SCROLLABLE));
+
+        // has_flag with a mask means "all set"
+        panel.remove_flag(Flag::SCROLLABLE);
+        assert!(!panel.has_flag(Flag::CLICKABLE | Flag::SCROLLABLE));
+        assert!(panel.has_any_flag(Flag::CLICKABLE | Flag::SCROLLABLE));
+    }
+
+    #[test]
+    fn test_editable_vs_focusable() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let group = crate::group::Group::new().unwrap();
+        group.set_default();
+
+        let screen = display.active_screen();
+        let slider = Slider::new(&screen).unwrap();
+        let button = Button::new(&screen).unwrap();
+        let label = Label::new(&screen).unwrap();
+
+        assert!(slider.is_editable());
+        assert!(!button.is_editable());
+        assert!(slider.is_group_def() && button.is_group_def());
+        assert!(slider.is_focusable() && button.is_focusable());
+        assert!(!label.is_focusable());
+
+        button.add_state(State::DISABLED);
+        assert!(!button.is_focusable());
+    }
+
+    #[test]
+    fn test_set_parent_moves_label() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let left = Container::new(&screen).unwrap();
+        let right = Container::new(&screen).unwrap();
+        let label = Label::new(&left).unwrap();
+        assert_eq!(label.parent().map(|p| p.raw()), Some(left.raw()));
+
+        label.set_parent(&right);
+        assert_eq!(label.parent().map(|p| p.raw()), Some(right.raw()));
+        assert_eq!((left.child_count(), right.child_count()), (0, 1));
+        assert!(screen.parent().is_none());
+    }
+
+    #[test]
+    fn test_width_pct_is_half_of_parent() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let parent = Container::new(&screen).unwrap();
+        unsafe { neo_lvgl_sys::lv_obj_remove_style_all(parent.raw()) };
+        parent.set_size(200, 100);
+        let child = Obj::new(&parent).unwrap();
+        child.set_width_pct(50);
+        crate::layout::LayoutExt::update_layout(&parent);
+        assert_eq!(child.width(), 100);
+
+        child.set_size_pct(25, 50);
+        crate::layout::LayoutExt::update_layout(&parent);
+        assert_eq!((child.width(), child.height()), (50, 50));
+    }
+
+    #[test]
+    fn test_animated_transform_width() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let button = Button::new(&screen).unwrap();
+        button.set_size(60, 30);
+        crate::layout::LayoutExt::update_layout(&button);
+        assert_eq!(button.transformed_width(), 60);
+
+        let mut anim = crate::anim::Anim::new();
+        anim.set_var(button.raw().cast())
+            .set_values(0, 10)
+            .set_duration(100)
+            .set_exec_cb(Some(grow_cb));
+        anim.start().unwrap();
+
+        crate::tick_inc(200);
+        unsafe { neo_lvgl_sys::lv_anim_refr_now() };
+
+        assert_eq!(button.transformed_width(), 80);
+        assert_eq!(button.width(), 60);
+        assert_eq!(button.transformed_height(), 30);
+    }
+
+    #[test]
+    fn test_set_scale() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let button = Button::new(&screen).unwrap();
+        button.set_size(60, 30);
+        button.set_scale(512);
+        assert_eq!((button.transformed_width(), button.transformed_height()), (120, 60));
+
+        // On an image the inherent method scales the picture, not the widget
+        let image = Image::new(&screen).unwrap();
+        image.set_size(40, 20);
+        Widget::set_scale(&image, 128);
+        let image_size = (Widget::transformed_width(&image), Widget::transformed_height(&image));
+        assert_eq!(image_size, (20, 10));
+    }
+
+    #[test]
+    fn test_image_button_state_flags() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let button = ImageButton::new(&screen).unwrap();
+
+        // The inherent method picks the images, the trait one a flag
+        button.set_state(ImageButtonState::CheckedReleased);
+        assert!(button.has_state(State::CHECKED));
+        Widget::set_state(&button, State::FOCUSED, true);
+        assert!(button.has_state(State::FOCUSED));
+        assert!(button.has_state(State::CHECKED));
+    }
+
+    #[test]
+    fn test_move_children_by() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        panel.set_size(200, 200);
+        let a = Button::new(&panel).unwrap();
+        let b = Button::new(&panel).unwrap();
+        let floating = Button::new(&panel).unwrap();
+        a.set_pos(0, 0);
+        b.set_pos(50, 20);
+        floating.set_pos(100, 100);
+        floating.add_flag(Flag::FLOATING);
+        crate::layout::LayoutExt::update_layout(&panel);
+
+        panel.move_children_by(10, -5, true);
+        assert_eq!((a.x(), a.y()), (10, -5));
+        assert_eq!((b.x(), b.y()), (60, 15));
+        assert_eq!((floating.x(), floating.y()), (100, 100));
+
+        panel.move_children_by(-10, 5, false);
+        assert_eq!((a.x(), a.y()), (0, 0));
+        assert_eq!((floating.x(), floating.y()), (90, 105));
+    }
+
+    #[test]
+    fn test_clean_removes_all_children() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let panel = Container::new(&screen).unwrap();
+        for _ in 0..3 {
+            Button::new(&panel).unwrap();
+        }
+        Label::new(&panel).unwrap();
+        assert_eq!(panel.child_count(), 4);
+
+        panel.clean();
+        assert_eq!(panel.child_count(), 0);
+        assert_eq!(screen.child_count(), 1);
+    }
+}
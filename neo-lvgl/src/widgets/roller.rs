@@ -49,6 +49,11 @@ impl<'a> Roller<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the options (newline-separated string) and mode
     pub fn set_options(&self, options: &CStr, mode: RollerMode) {
         unsafe {
@@ -99,6 +104,16 @@ impl<'a> Roller<'a> {
         }
     }
 
+    /// Iterate over the options one by one
+    ///
+    /// Useful for finding an option's index by its text:
+    /// `options_iter().position(|o| o == "Apple")`.
+    ///
+    /// In infinite mode each option is yielded once, not once per repetition.
+    pub fn options_iter(&self) -> impl Iterator<Item = &str> {
+        super::split_options(self.options()).take(self.option_count() as usize)
+    }
+
     /// Get the number of options
     pub fn option_count(&self) -> u32 {
         unsafe { neo_lvgl_sys::lv_roller_get_option_count(self.obj.raw()) }
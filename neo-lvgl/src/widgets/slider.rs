@@ -11,6 +11,15 @@ pub type SliderMode = BarMode;
 /// Slider orientation (same as Bar orientation)
 pub type SliderOrientation = BarOrientation;
 
+/// Knob of a range-mode slider
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SliderKnob {
+    /// The start knob (see [`Slider::left_value`])
+    Left,
+    /// The end knob (see [`Slider::value`])
+    Right,
+}
+
 /// Slider widget
 ///
 /// An interactive slider for selecting a value within a range.
@@ -22,8 +31,8 @@ pub type SliderOrientation = BarOrientation;
 /// slider.set_range(0, 100);
 /// slider.set_value(50, false);
 ///
-/// slider.on_value_changed(|_| {
-///     println!("Slider value changed!");
+/// slider.on_value_changed(|e| {
+///     let value = e.target_value().unwrap();
 /// });
 /// ```
 #[derive(Clone, Copy)]
@@ -40,6 +49,11 @@ impl<'a> Slider<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Set the slider value
     ///
     /// # Arguments
@@ -52,6 +66,31 @@ impl<'a> Slider<'a> {
         }
     }
 
+    /// Fill the indicator with a gradient (local style)
+    ///
+    /// Like [`Bar::set_indicator_gradient`](super::Bar::set_indicator_gradient),
+    /// the gradient is laid out over the slider's full track, so dragging the
+    /// knob reveals more of it instead of squeezing it. The knob is a
+    /// separate part and keeps its own background.
+    ///
+    /// A copy of `grad` is stored with the slider.
+    ///
+    /// ```ignore
+    /// let stops = [(Color::hex(0x2962FF), 0), (Color::hex(0xFFD600), 255)];
+    /// let grad = Gradient::new(&stops, GradDir::Horizontal).unwrap();
+    /// slider.set_indicator_gradient(&grad);
+    /// ```
+    pub fn set_indicator_gradient(&self, grad: &crate::style::Gradient) {
+        super::set_indicator_gradient(self.obj.raw(), grad);
+    }
+
+    /// Set the left (start) value for range mode
+    pub fn set_left_value(&self, value: i32, anim: bool) {
+        unsafe {
+            neo_lvgl_sys::lv_slider_set_start_value(self.obj.raw(), value, anim);
+        }
+    }
+
     /// Set the value range
     pub fn set_range(&self, min: i32, max: i32) {
         unsafe {
@@ -159,3 +198,130 @@ impl<'a> Slider<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Slider<'_> {
+    /// Call `handler` with the knob that moved and its new value
+    ///
+    /// Meant for `SliderMode::Range`, where a plain `ValueChanged` event
+    /// doesn't say which end changed. The knob is found by comparing both
+    /// values against the ones seen at the previous event.
+    ///
+    /// When both ends change in the same update (e.g. the dragged knob pushes
+    /// the other one), the handler runs twice: first for
+    /// [`SliderKnob::Left`], then for [`SliderKnob::Right`]. Values set from
+    /// code don't emit `ValueChanged` themselves; they are reported along
+    /// with the next user change.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// slider.set_mode(SliderMode::Range);
+    /// slider.on_range_changed(move |knob, value| match knob {
+    ///     SliderKnob::Left => filter.set_min(value),
+    ///     SliderKnob::Right => filter.set_max(value),
+    /// });
+    /// ```
+    pub fn on_range_changed<F>(&self, handler: F)
+    where
+        F: Fn(SliderKnob, i32) + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        let raw = self.obj.raw();
+        let mut prev = (self.left_value(), self.value());
+        self.on_event_closure_mut(EventCode::ValueChanged, move |_| {
+            let (left, right) = unsafe {
+                (
+                    neo_lvgl_sys::lv_slider_get_left_value(raw),
+                    neo_lvgl_sys::lv_slider_get_value(raw),
+                )
+            };
+            if left != prev.0 {
+                handler(SliderKnob::Left, left);
+            }
+            if right != prev.1 {
+                handler(SliderKnob::Right, right);
+            }
+            prev = (left, right);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+    use crate::style::{GradDir, Gradient};
+
+    #[test]
+    fn test_indicator_gradient_replaced() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = crate::TestFramebuffer::new(100, 10);
+
+        let screen = display.active_screen();
+        let slider = Slider::new(&screen).unwrap();
+        slider.set_size(100, 10);
+        slider.set_pos(0, 0);
+        slider.set_value(100, false);
+
+        let red_to_blue = [(Color::rgb(255, 0, 0), 0), (Color::rgb(0, 0, 255), 255)];
+        slider.set_indicator_gradient(&Gradient::new(&red_to_blue, GradDir::Horizontal).unwrap());
+        display.refresh_now();
+        let [r, g, b, _] = display.pixel(8, 5);
+        assert!(r > 200 && g < 50 && b < 50, "start was {:?}", (r, g, b));
+
+        // A second gradient replaces the first copy
+        let green_to_blue = [(Color::rgb(0, 255, 0), 0), (Color::rgb(0, 0, 255), 255)];
+        slider.set_indicator_gradient(&Gradient::new(&green_to_blue, GradDir::Horizontal).unwrap());
+        display.refresh_now();
+        let [r, g, b, _] = display.pixel(8, 5);
+        assert!(g > 200 && r < 50 && b < 50, "start was {:?}", (r, g, b));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_range_changed_reports_knobs_in_order() {
+        use crate::display::Display;
+        use core::cell::RefCell;
+        use std::rc::Rc;
+        use std::vec::Vec;
+
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let slider = Slider::new(&screen).unwrap();
+        slider.set_mode(SliderMode::Range);
+        slider.set_range(0, 100);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = seen.clone();
+        slider.on_range_changed(move |knob, value| sink.borrow_mut().push((knob, value)));
+        let changed = || unsafe {
+            neo_lvgl_sys::lv_obj_send_event(
+                slider.raw(),
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_VALUE_CHANGED,
+                core::ptr::null_mut(),
+            );
+        };
+
+        // Changes from code, not from dragging a knob
+        slider.set_value(50, false);
+        changed();
+        assert!(!slider.is_dragged());
+        assert_eq!(*seen.borrow(), [(SliderKnob::Right, 50)]);
+
+        // Both ends moved: left first, then right
+        seen.borrow_mut().clear();
+        slider.set_left_value(10, false);
+        slider.set_value(60, false);
+        changed();
+        assert_eq!(*seen.borrow(), [(SliderKnob::Left, 10), (SliderKnob::Right, 60)]);
+
+        // Nothing moved: nothing reported
+        seen.borrow_mut().clear();
+        changed();
+        assert!(seen.borrow().is_empty());
+    }
+}
@@ -61,6 +61,11 @@ impl<'a> Switch<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Check if the switch is on
     pub fn is_checked(&self) -> bool {
         self.obj.has_state(State::CHECKED)
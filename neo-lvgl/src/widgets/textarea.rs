@@ -1,6 +1,6 @@
 //! TextArea widget
 
-use super::{Obj, Widget};
+use super::{event_user_data, Obj, Widget};
 use crate::event::EventHandler;
 use core::ffi::CStr;
 
@@ -51,18 +51,34 @@ impl<'a> TextArea<'a> {
         }
     }
 
+    /// Like [`new`](Self::new), but reports why creation failed.
+    pub fn try_new(parent: &'a impl Widget<'a>) -> Result<Self, crate::LvError> {
+        Self::new(parent).ok_or(crate::LvError::OutOfMemory)
+    }
+
     /// Add a character at the cursor position
     pub fn add_char(&self, c: char) {
+        #[cfg(feature = "alloc")]
+        let dropped = self.dropped_input(c.encode_utf8(&mut [0; 4]));
         unsafe {
             neo_lvgl_sys::lv_textarea_add_char(self.obj.raw(), c as u32);
         }
+        #[cfg(feature = "alloc")]
+        self.report_dropped(dropped);
     }
 
     /// Add text at the cursor position
     pub fn add_text(&self, text: &CStr) {
+        #[cfg(feature = "alloc")]
+        let dropped = text
+            .to_str()
+            .map(|t| self.dropped_input(t))
+            .unwrap_or_default();
         unsafe {
             neo_lvgl_sys::lv_textarea_add_text(self.obj.raw(), text.as_ptr().cast());
         }
+        #[cfg(feature = "alloc")]
+        self.report_dropped(dropped);
     }
 
     /// Delete the character before the cursor
@@ -136,15 +152,77 @@ impl<'a> TextArea<'a> {
     }
 
     /// Enable/disable password mode (shows bullets instead of characters)
+    ///
+    /// Enabling it while the password is revealed keeps the text visible
+    /// until [`reveal_password(false)`](Self::reveal_password). Disabling it
+    /// also ends the reveal.
     pub fn set_password_mode(&self, en: bool) {
+        let revealed = self.is_password_revealed();
+        if !en {
+            self.set_revealed(false);
+        }
         unsafe {
-            neo_lvgl_sys::lv_textarea_set_password_mode(self.obj.raw(), en);
+            neo_lvgl_sys::lv_textarea_set_password_mode(self.obj.raw(), en && !revealed);
         }
     }
 
     /// Check if password mode is enabled
+    ///
+    /// Stays `true` while the password is revealed.
     pub fn password_mode(&self) -> bool {
-        unsafe { neo_lvgl_sys::lv_textarea_get_password_mode(self.obj.raw()) }
+        let hidden = unsafe { neo_lvgl_sys::lv_textarea_get_password_mode(self.obj.raw()) };
+        hidden || self.is_password_revealed()
+    }
+
+    /// Temporarily show the text of a password field in clear
+    ///
+    /// Meant for a show/hide toggle next to the field. The text area stays
+    /// a password field: [`password_mode`](Self::password_mode) keeps
+    /// returning `true`, and `reveal_password(false)` hides the text again.
+    /// Has no effect if password mode is off.
+    ///
+    /// While revealed, [`password_show_time`](Self::password_show_time) has
+    /// no effect since every character is visible. Hiding replaces the whole
+    /// text with bullets at once, including a character that was typed
+    /// within the show time.
+    pub fn reveal_password(&self, reveal: bool) {
+        if !self.password_mode() {
+            return;
+        }
+        self.set_revealed(reveal);
+        unsafe {
+            neo_lvgl_sys::lv_textarea_set_password_mode(self.obj.raw(), !reveal);
+        }
+    }
+
+    /// Check if the password is currently revealed
+    pub fn is_password_revealed(&self) -> bool {
+        event_user_data(self.obj.raw(), password_revealed_cb)
+            .next()
+            .is_some()
+    }
+
+    /// Mark the text area as revealed with an event callback that does nothing
+    ///
+    /// Keeping the mark on the object shares it between all copies of the
+    /// wrapper without claiming one of the application's user flags.
+    fn set_revealed(&self, revealed: bool) {
+        if revealed == self.is_password_revealed() {
+            return;
+        }
+        let obj = self.obj.raw();
+        unsafe {
+            if revealed {
+                neo_lvgl_sys::lv_obj_add_event_cb(
+                    obj,
+                    Some(password_revealed_cb),
+                    neo_lvgl_sys::lv_event_code_t_LV_EVENT_DELETE,
+                    core::ptr::null_mut(),
+                );
+            } else {
+                neo_lvgl_sys::lv_obj_remove_event_cb(obj, Some(password_revealed_cb));
+            }
+        }
     }
 
     /// Set password bullet character
@@ -273,6 +351,9 @@ impl<'a> TextArea<'a> {
     }
 }
 
+/// Marks a text area whose password is revealed (see `TextArea::set_revealed`)
+unsafe extern "C" fn password_revealed_cb(_e: *mut neo_lvgl_sys::lv_event_t) {}
+
 impl<'a> Widget<'a> for TextArea<'a> {
     fn obj(&self) -> &Obj<'a> {
         &self.obj
@@ -285,4 +366,251 @@ impl EventHandler for TextArea<'_> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl TextArea<'_> {
+    /// Call `handler` with the final text when the user confirms the input.
+    ///
+    /// LVGL emits `EventCode::Ready` on a text area when Enter is pressed in
+    /// one-line mode, or when an attached `Keyboard`'s OK key is pressed.
+    pub fn on_ready<F>(&self, handler: F)
+    where
+        F: Fn(&CStr) + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        let raw = self.obj.raw();
+        self.on_event_closure(EventCode::Ready, move |_| {
+            let text = unsafe { CStr::from_ptr(neo_lvgl_sys::lv_textarea_get_text(raw).cast()) };
+            handler(text);
+        });
+    }
+
+    /// Filter characters before they are inserted
+    ///
+    /// `filter` is called for every character that is about to be inserted,
+    /// whether typed on a keyboard or added with `add_char`/`add_text`.
+    /// Characters for which it returns `false` are dropped.
+    ///
+    /// Unlike [`set_accepted_chars`](Self::set_accepted_chars) the filter can
+    /// keep state or look at the current text, e.g. to allow at most one
+    /// decimal point.
+    ///
+    /// It hooks `EventCode::Insert` and rejects the insertion with
+    /// `lv_textarea_set_insert_replace(c"")`. When only some characters of a
+    /// longer text are dropped the rest is passed as the replacement, which
+    /// LVGL inserts by sending `Insert` again; that event is not filtered a
+    /// second time.
+    pub fn set_text_filter<F>(&self, mut filter: F)
+    where
+        F: FnMut(char) -> bool + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        let raw = self.obj.raw();
+        let mut kept = alloc::string::String::new();
+        self.on_event_closure_mut(EventCode::Insert, move |e| unsafe {
+            let param = e.param_raw().cast_const().cast::<u8>();
+            if param.is_null() || param == kept.as_ptr() {
+                return;
+            }
+            let Ok(text) = CStr::from_ptr(param.cast()).to_str() else {
+                return;
+            };
+            let filtered: alloc::string::String = text.chars().filter(|&c| filter(c)).collect();
+            if filtered.len() == text.len() {
+                return;
+            }
+            kept = filtered;
+            kept.push('\0');
+            neo_lvgl_sys::lv_textarea_set_insert_replace(raw, kept.as_ptr().cast());
+        });
+    }
+
+    /// Call `handler` when input is dropped because of the maximum length
+    ///
+    /// `handler` gets the text that didn't fit, e.g. to shake the field or
+    /// show a hint, once per [`add_char`](Self::add_char) or
+    /// [`add_text`](Self::add_text) call. Nothing is reported while no
+    /// maximum length is set (see [`set_max_length`](Self::set_max_length)).
+    ///
+    /// LVGL drops characters over the limit before any event is sent, so
+    /// the check is done by `add_char` and `add_text` before inserting.
+    /// Text that LVGL inserts on its own, such as the keys of an attached
+    /// `Keyboard`, is not reported.
+    pub fn on_max_length_reached<F>(&self, handler: F)
+    where
+        F: Fn(&CStr) + 'static,
+    {
+        use alloc::boxed::Box;
+
+        let handler: Box<MaxLengthHandler> = Box::new(Box::new(handler));
+        unsafe {
+            neo_lvgl_sys::lv_obj_add_event_cb(
+                self.obj.raw(),
+                Some(free_max_length_handler),
+                neo_lvgl_sys::lv_event_code_t_LV_EVENT_DELETE,
+                Box::into_raw(handler).cast(),
+            );
+        }
+    }
+
+    /// Characters of `text` that won't fit because of the maximum length
+    ///
+    /// Mirrors LVGL's checks: characters outside the accepted list are
+    /// skipped without using up room.
+    fn dropped_input(&self, text: &str) -> alloc::string::String {
+        let mut dropped = alloc::string::String::new();
+        let Some(max) = self.max_length() else {
+            return dropped;
+        };
+        if event_user_data(self.obj.raw(), free_max_length_handler)
+            .next()
+            .is_none()
+        {
+            return dropped;
+        }
+        let current = self.text();
+        let len = current
+            .to_str()
+            .map_or(current.to_bytes().len(), |t| t.chars().count());
+        let mut room = (max as usize).saturating_sub(len);
+        let accepted = self.accepted_chars().and_then(|a| a.to_str().ok());
+        for c in text.chars() {
+            if accepted.is_some_and(|a| !a.contains(c)) {
+                continue;
+            }
+            if room == 0 {
+                dropped.push(c);
+            } else {
+                room -= 1;
+            }
+        }
+        dropped
+    }
+
+    /// Pass the dropped characters of one insertion to the handlers
+    fn report_dropped(&self, dropped: alloc::string::String) {
+        if dropped.is_empty() {
+            return;
+        }
+        let Ok(dropped) = alloc::ffi::CString::new(dropped) else {
+            return;
+        };
+        for handler in event_user_data(self.obj.raw(), free_max_length_handler) {
+            let handler = unsafe { &*handler.cast::<MaxLengthHandler>() };
+            handler(&dropped);
+        }
+    }
+
+    /// Call `handler` when the user cancels the input.
+    ///
+    /// LVGL emits `EventCode::Cancel` on a text area when an attached
+    /// `Keyboard`'s close key is pressed.
+    pub fn on_cancel<F>(&self, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        use crate::event::{ClosureEventHandler, EventCode};
+
+        self.on_event_closure(EventCode::Cancel, move |_| handler());
+    }
+}
+
+/// Handler registered with [`TextArea::on_max_length_reached`]
+#[cfg(feature = "alloc")]
+type MaxLengthHandler = alloc::boxed::Box<dyn Fn(&CStr)>;
+
+/// Free a max length handler when its text area is deleted
+///
+/// The event descriptor also keeps the handler with the text area, where
+/// `TextArea::report_dropped` looks it up.
+#[cfg(feature = "alloc")]
+unsafe extern "C" fn free_max_length_handler(e: *mut neo_lvgl_sys::lv_event_t) {
+    let handler = neo_lvgl_sys::lv_event_get_user_data(e).cast::<MaxLengthHandler>();
+    drop(alloc::boxed::Box::from_raw(handler));
+}
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::display::Display;
+    use core::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_text_filter_one_decimal_point() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let ta = TextArea::new(&screen).unwrap();
+        let raw = ta.raw();
+        ta.set_text_filter(move |c| {
+            let text = unsafe { CStr::from_ptr(neo_lvgl_sys::lv_textarea_get_text(raw).cast()) };
+            c.is_ascii_digit() || (c == '.' && !text.to_bytes().contains(&b'.'))
+        });
+
+        ta.add_text(c"3.1a4");
+        ta.add_char('.');
+        ta.add_char('5');
+        assert_eq!(ta.text(), c"3.145");
+
+        // With a maximum length LVGL inserts character by character
+        ta.set_max_length(Some(8));
+        ta.add_text(c"6.b7");
+        assert_eq!(ta.text(), c"3.14567");
+    }
+
+    #[test]
+    fn test_reveal_password() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let ta = TextArea::new(&screen).unwrap();
+        let copy = ta;
+
+        ta.reveal_password(true);
+        assert!(!ta.is_password_revealed());
+
+        ta.set_password_mode(true);
+        ta.reveal_password(true);
+        assert!(copy.is_password_revealed());
+        assert!(ta.password_mode());
+        assert!(!unsafe { neo_lvgl_sys::lv_textarea_get_password_mode(ta.raw()) });
+
+        copy.reveal_password(false);
+        assert!(!ta.is_password_revealed());
+        assert!(unsafe { neo_lvgl_sys::lv_textarea_get_password_mode(ta.raw()) });
+
+        ta.reveal_password(true);
+        ta.set_password_mode(false);
+        assert!(!ta.is_password_revealed());
+        assert!(!ta.password_mode());
+    }
+
+    #[test]
+    fn test_max_length_reported_once_per_paste() {
+        let _lvgl = crate::test_lvgl();
+
+        let display = Display::new(320, 240).unwrap();
+        let screen = display.active_screen();
+        let ta = TextArea::new(&screen).unwrap();
+        ta.set_max_length(Some(5));
+
+        let reports = Rc::new(core::cell::RefCell::new(std::vec::Vec::new()));
+        let sink = reports.clone();
+        ta.on_max_length_reached(move |text| sink.borrow_mut().push(text.to_owned()));
+
+        ta.add_text(c"abc");
+        assert!(reports.borrow().is_empty());
+
+        ta.add_text(c"defgh");
+        assert_eq!(ta.text(), c"abcde");
+        assert_eq!(*reports.borrow(), [c"fgh".to_owned()]);
+
+        ta.add_char('x');
+        assert_eq!(reports.borrow().len(), 2);
+        assert_eq!(reports.borrow()[1].as_c_str(), c"x");
+    }
+}